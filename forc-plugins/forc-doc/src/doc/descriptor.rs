@@ -268,7 +268,7 @@ impl Descriptor {
             // }
             ty::TyDecl::FunctionDecl(ty::FunctionDecl { decl_id, .. }) => {
                 let fn_decl = decl_engine.get_function(decl_id);
-                if !document_private_items && fn_decl.visibility.is_private() {
+                if !document_private_items && !fn_decl.visibility.is_public() {
                     Ok(Descriptor::NonDocumentable)
                 } else {
                     let item_name = fn_decl.name;
@@ -12,6 +12,7 @@ pub struct Annotated<T> {
 //    #[attribute()]
 //    #[attribute(value)]
 //    #[attribute(value0, value1, value2)]
+//    #[attribute(value0, nested(value1))]
 
 #[derive(Clone, Debug, Serialize)]
 pub struct AttributeDecl {
@@ -56,12 +57,17 @@ pub enum AttributeHashKind {
 pub struct AttributeArg {
     pub name: Ident,
     pub value: Option<Literal>,
+    /// The arguments of an argument which is itself an attribute, like the `inline(always)` of
+    /// `#[cfg_attr(target = "fuel", inline(always))]`.
+    pub args: Option<Parens<Punctuated<AttributeArg, CommaToken>>>,
 }
 
 impl Spanned for AttributeArg {
     fn span(&self) -> Span {
         if let Some(value) = &self.value {
             Span::join(self.name.span(), value.span())
+        } else if let Some(args) = &self.args {
+            Span::join(self.name.span(), args.span())
         } else {
             self.name.span()
         }
@@ -39,10 +39,22 @@ pub enum Expr {
         condition: Box<Expr>,
         block: Braces<CodeBlockContents>,
     },
+    /// A block in which the `?` operator short-circuits to the end of the block rather than
+    /// returning from the function, e.g. `try { foo()? + bar()? }`.
+    TryBlock {
+        try_token: TryToken,
+        block: Braces<CodeBlockContents>,
+    },
     FuncApp {
         func: Box<Expr>,
         args: Parens<Punctuated<Expr, CommaToken>>,
     },
+    /// An argument of a function call labeled with the name of its parameter, e.g. `amount: 100`.
+    NamedArg {
+        name: Ident,
+        colon_token: ColonToken,
+        value: Box<Expr>,
+    },
     Index {
         target: Box<Expr>,
         arg: SquareBrackets<Box<Expr>>,
@@ -65,6 +77,12 @@ pub enum Expr {
         field: BigUint,
         field_span: Span,
     },
+    /// The `?` operator, which returns early with the error of a `Result` or the `None` of an
+    /// `Option`, e.g. `foo()?`.
+    Try {
+        expr: Box<Expr>,
+        question_mark_token: QuestionMarkToken,
+    },
     Ref {
         ref_token: RefToken,
         expr: Box<Expr>,
@@ -218,13 +236,19 @@ impl Spanned for Expr {
             Expr::While {
                 while_token, block, ..
             } => Span::join(while_token.span(), block.span()),
+            Expr::TryBlock { try_token, block } => Span::join(try_token.span(), block.span()),
             Expr::FuncApp { func, args } => Span::join(func.span(), args.span()),
+            Expr::NamedArg { name, value, .. } => Span::join(name.span(), value.span()),
             Expr::Index { target, arg } => Span::join(target.span(), arg.span()),
             Expr::MethodCall { target, args, .. } => Span::join(target.span(), args.span()),
             Expr::FieldProjection { target, name, .. } => Span::join(target.span(), name.span()),
             Expr::TupleFieldProjection {
                 target, field_span, ..
             } => Span::join(target.span(), field_span.clone()),
+            Expr::Try {
+                expr,
+                question_mark_token,
+            } => Span::join(expr.span(), question_mark_token.span()),
             Expr::Ref { ref_token, expr } => Span::join(ref_token.span(), expr.span()),
             Expr::Deref { deref_token, expr } => Span::join(deref_token.span(), expr.span()),
             Expr::Not { bang_token, expr } => Span::join(bang_token.span(), expr.span()),
@@ -484,7 +508,8 @@ impl Expr {
                 | Expr::Asm(..)
                 | Expr::If(..)
                 | Expr::Match { .. }
-                | Expr::While { .. },
+                | Expr::While { .. }
+                | Expr::TryBlock { .. },
         )
     }
 }
@@ -2,7 +2,14 @@ use crate::priv_prelude::*;
 
 #[derive(Clone, Debug, Serialize)]
 pub struct GenericParams {
-    pub parameters: AngleBrackets<Punctuated<Ident, CommaToken>>,
+    pub parameters: AngleBrackets<Punctuated<GenericParam, CommaToken>>,
+}
+
+/// A single type parameter, with optional inline trait bounds, eg. `T` or `T: Eq + Ord`.
+#[derive(Clone, Debug, Serialize)]
+pub struct GenericParam {
+    pub name: Ident,
+    pub bounds_opt: Option<(ColonToken, Traits)>,
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -15,3 +22,12 @@ impl Spanned for GenericArgs {
         self.parameters.span()
     }
 }
+
+impl Spanned for GenericParam {
+    fn span(&self) -> Span {
+        match &self.bounds_opt {
+            Some((_colon_token, bounds)) => Span::join(self.name.span(), bounds.span()),
+            None => self.name.span(),
+        }
+    }
+}
@@ -85,20 +85,34 @@ pub enum FnArgs {
 
 #[derive(Clone, Debug, Serialize)]
 pub struct FnArg {
+    /// The doc comments and attributes of the argument.
+    pub attribute_list: Vec<AttributeDecl>,
     pub pattern: Pattern,
     pub colon_token: ColonToken,
     pub ty: Ty,
+    pub default_opt: Option<(EqToken, Expr)>,
 }
 
 impl Spanned for FnArg {
     fn span(&self) -> Span {
-        Span::join(self.pattern.span(), self.ty.span())
+        let end = match &self.default_opt {
+            Some((_eq_token, expr)) => expr.span(),
+            None => self.ty.span(),
+        };
+        let start = match self.attribute_list.first() {
+            Some(attr0) => attr0.span(),
+            None => self.pattern.span(),
+        };
+        Span::join(start, end)
     }
 }
 
 #[derive(Clone, Debug, Serialize)]
 pub struct FnSignature {
     pub visibility: Option<PubToken>,
+    /// The `(package)` of `pub(package)`, which restricts the visibility to the package.
+    pub package_scope_opt: Option<Parens<PackageToken>>,
+    pub const_token: Option<ConstToken>,
     pub fn_token: FnToken,
     pub name: Ident,
     pub generics: Option<GenericParams>,
@@ -109,9 +123,10 @@ pub struct FnSignature {
 
 impl Spanned for FnSignature {
     fn span(&self) -> Span {
-        let start = match &self.visibility {
-            Some(pub_token) => pub_token.span(),
-            None => self.fn_token.span(),
+        let start = match (&self.visibility, &self.const_token) {
+            (Some(pub_token), _) => pub_token.span(),
+            (None, Some(const_token)) => const_token.span(),
+            (None, None) => self.fn_token.span(),
         };
         let end = match &self.where_clause_opt {
             Some(where_clause) => where_clause.span(),
@@ -84,6 +84,8 @@ define_keyword!(ConfigurableToken, "configurable");
 define_keyword!(TypeToken, "type");
 define_keyword!(PtrToken, "__ptr");
 define_keyword!(SliceToken, "__slice");
+define_keyword!(PackageToken, "package");
+define_keyword!(TryToken, "try");
 
 /// The type is a keyword.
 pub trait Token: Spanned + Sized {
@@ -232,3 +234,4 @@ define_token!(DoublePipeToken, "`||`", [Pipe, Pipe], [Pipe]);
 define_token!(UnderscoreToken, "`_`", [Underscore], [Underscore]);
 define_token!(HashToken, "`#`", [Sharp], []);
 define_token!(HashBangToken, "`#!`", [Sharp, Bang], []);
+define_token!(QuestionMarkToken, "`?`", [QuestionMark], []);
@@ -28,7 +28,7 @@ pub use crate::{
         AbiCastArgs, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
         ExprTupleDescriptor, IfCondition, IfExpr, MatchBranch, MatchBranchKind,
     },
-    generics::{GenericArgs, GenericParams},
+    generics::{GenericArgs, GenericParam, GenericParams},
     intrinsics::*,
     item::{
         item_abi::ItemAbi,
@@ -44,7 +44,7 @@ pub use crate::{
         item_use::{ItemUse, UseTree},
         FnArg, FnArgs, FnSignature, Item, ItemKind, TypeField,
     },
-    keywords::{CommaToken, DoubleColonToken, PubToken},
+    keywords::{CommaToken, DoubleColonToken, ImplToken, PubToken},
     literal::{LitInt, LitIntType, Literal},
     module::{Module, ModuleKind},
     path::{PathExpr, PathExprSegment, PathType, PathTypeSegment, QualifiedPathRoot},
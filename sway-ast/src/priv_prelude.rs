@@ -8,7 +8,7 @@ pub use {
             op_code::Instruction,
             CodeBlockContents, Expr,
         },
-        generics::{GenericArgs, GenericParams},
+        generics::{GenericArgs, GenericParam, GenericParams},
         intrinsics::*,
         item::{
             item_abi::ItemAbi,
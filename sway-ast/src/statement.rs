@@ -18,6 +18,8 @@ pub struct StatementLet {
     pub ty_opt: Option<(ColonToken, Ty)>,
     pub eq_token: EqToken,
     pub expr: Expr,
+    /// The diverging block of a `let ... else` statement, run when `pattern` doesn't match.
+    pub else_opt: Option<(ElseToken, Braces<CodeBlockContents>)>,
     pub semicolon_token: SemicolonToken,
 }
 
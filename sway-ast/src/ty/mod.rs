@@ -21,6 +21,23 @@ pub enum Ty {
         slice_token: SliceToken,
         ty: SquareBrackets<Box<Ty>>,
     },
+    /// The type of expressions which never evaluate to a value, written `!`.
+    Never {
+        bang_token: BangToken,
+    },
+    /// An opaque type implementing the given traits, e.g. `impl Iterator`. Only allowed as the
+    /// return type of a function.
+    ImplTrait {
+        impl_token: ImplToken,
+        trait_bounds: Box<Traits>,
+    },
+    /// A comma separated list of types without parentheses, e.g. `u64, bool`. Only allowed as
+    /// the return type of a function, where it stands for the tuple of the types.
+    UnparenthesizedTuple {
+        head: Box<Ty>,
+        comma_token: CommaToken,
+        tail: Punctuated<Ty, CommaToken>,
+    },
 }
 
 impl Spanned for Ty {
@@ -33,6 +50,23 @@ impl Spanned for Ty {
             Ty::Infer { underscore_token } => underscore_token.span(),
             Ty::Ptr { ptr_token, ty } => Span::join(ptr_token.span(), ty.span()),
             Ty::Slice { slice_token, ty } => Span::join(slice_token.span(), ty.span()),
+            Ty::Never { bang_token } => bang_token.span(),
+            Ty::ImplTrait {
+                impl_token,
+                trait_bounds,
+            } => Span::join(impl_token.span(), trait_bounds.span()),
+            Ty::UnparenthesizedTuple {
+                head,
+                comma_token,
+                tail,
+            } => {
+                let last_span = match (&tail.final_value_opt, tail.value_separator_pairs.last()) {
+                    (Some(last), _) => last.span(),
+                    (None, Some((_, last_comma_token))) => last_comma_token.span(),
+                    (None, None) => comma_token.span(),
+                };
+                Span::join(head.span(), last_span)
+            }
         }
     }
 }
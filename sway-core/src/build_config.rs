@@ -2,6 +2,7 @@ use std::{path::PathBuf, sync::Arc};
 
 use serde::{Deserialize, Serialize};
 use strum::EnumString;
+use sway_types::Ident;
 
 #[derive(
     Clone,
@@ -46,6 +47,8 @@ pub struct BuildConfig {
     pub(crate) print_finalized_asm: bool,
     pub(crate) print_ir: bool,
     pub(crate) include_tests: bool,
+    pub(crate) lint_config: LintConfig,
+    pub(crate) fn_name_rewriter: Option<fn(&Ident) -> Ident>,
     pub time_phases: bool,
     pub metrics_outfile: Option<String>,
 }
@@ -90,6 +93,8 @@ impl BuildConfig {
             print_finalized_asm: false,
             print_ir: false,
             include_tests: false,
+            lint_config: LintConfig::default(),
+            fn_name_rewriter: None,
             time_phases: false,
             metrics_outfile: None,
         }
@@ -156,7 +161,71 @@ impl BuildConfig {
         }
     }
 
+    /// The lints reported while type checking.
+    ///
+    /// Default: `LintConfig::default()`
+    pub fn lint_config(self, lint_config: LintConfig) -> Self {
+        Self {
+            lint_config,
+            ..self
+        }
+    }
+
+    /// Rewrites the name each declared function has in its selector, e.g. to give the functions
+    /// expanded from a macro a deterministic suffix. The functions are still declared and called
+    /// by their source names. The `fn_name_rewriter` has to be pure.
+    ///
+    /// Default: `None`, meaning the names are kept
+    pub fn fn_name_rewriter(self, fn_name_rewriter: fn(&Ident) -> Ident) -> Self {
+        Self {
+            fn_name_rewriter: Some(fn_name_rewriter),
+            ..self
+        }
+    }
+
     pub fn canonical_root_module(&self) -> Arc<PathBuf> {
         self.canonical_root_module.clone()
     }
 }
+
+/// Configuration for the lints reported while type checking.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LintConfig {
+    /// Whether or not to report the style lints, like non-snake-case function names, as errors
+    /// instead of warnings.
+    ///
+    /// Default: `false`
+    pub style_as_error: bool,
+    /// The number of parameters, not counting the `self` receiver, above which a function is
+    /// warned about.
+    ///
+    /// Default: `None`, meaning unlimited
+    pub max_function_parameters: Option<usize>,
+    /// The number of type parameters above which a function is warned about.
+    ///
+    /// Default: `None`, meaning unlimited
+    pub max_type_parameters: Option<usize>,
+    /// The cyclomatic complexity above which a function is warned about.
+    ///
+    /// Default: `None`, meaning unlimited
+    pub max_complexity: Option<u32>,
+    /// The depth of nested generic types, e.g. 2 for `Vec<Option<u64>>`, above which the
+    /// parameter and return types of a function are warned about.
+    ///
+    /// Default: `None`, meaning unlimited
+    pub max_generic_depth: Option<usize>,
+    /// The number of `bool` parameters above which a function is warned about.
+    ///
+    /// Default: `None`, meaning unlimited
+    pub max_bool_parameters: Option<usize>,
+    /// Whether or not to warn about a `return` at the end of a function body, whose value can
+    /// be returned implicitly.
+    ///
+    /// Default: `false`
+    pub redundant_tail_return: bool,
+    /// Whether or not to warn about the methods which never use their `self` receiver, and
+    /// could be associated functions instead.
+    ///
+    /// Default: `false`
+    pub unused_self: bool,
+}
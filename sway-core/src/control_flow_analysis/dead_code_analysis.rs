@@ -17,7 +17,11 @@ use sway_error::{
     handler::Handler,
     warning::{CompileWarning, Warning},
 };
-use sway_types::{constants::ALLOW_DEAD_CODE_NAME, span::Span, Ident, Named, Spanned};
+use sway_types::{
+    constants::{ALLOW_DEAD_CODE_NAME, ALLOW_UNUSED_VARIABLES_NAME},
+    span::Span,
+    Ident, Named, Spanned,
+};
 
 impl<'cfg> ControlFlowGraph<'cfg> {
     pub(crate) fn find_dead_code(&self, decl_engine: &DeclEngine) -> Vec<CompileWarning> {
@@ -98,9 +102,9 @@ impl<'cfg> ControlFlowGraph<'cfg> {
                 ControlFlowGraphNode::FunctionParameter {
                     param_name,
                     is_self,
-                    ..
+                    allow_unused,
                 } => {
-                    if *is_self || param_name.as_str().starts_with('_') {
+                    if *is_self || *allow_unused || param_name.as_str().starts_with('_') {
                         // self type parameter is always alive
                         true
                     } else {
@@ -228,7 +232,9 @@ impl<'cfg> ControlFlowGraph<'cfg> {
                         ControlFlowGraphNode::FunctionParameter { param_name, .. } => {
                             Some(CompileWarning {
                                 span: param_name.span(),
-                                warning_content: Warning::DeadDeclaration,
+                                warning_content: Warning::UnusedFunctionParameter {
+                                    name: param_name.clone(),
+                                },
                             })
                         }
                     }
@@ -914,6 +920,7 @@ fn connect_typed_fn_decl<'eng: 'cfg, 'cfg>(
 ) -> Result<(), CompileError> {
     let type_engine = engines.te();
 
+    let allow_unused = allow_unused_variables(fn_decl.attributes.clone());
     graph.namespace.push_code_block();
     for fn_param in fn_decl.parameters.iter() {
         let fn_param_node = graph.add_node(ControlFlowGraphNode::FunctionParameter {
@@ -922,6 +929,7 @@ fn connect_typed_fn_decl<'eng: 'cfg, 'cfg>(
                 type_engine.get(fn_param.type_argument.initial_type_id),
                 TypeInfo::SelfType
             ),
+            allow_unused,
         });
         graph.add_edge(entry_node, fn_param_node, "".into());
 
@@ -1417,7 +1425,7 @@ fn connect_expression<'eng: 'cfg, 'cfg>(
 
             Ok([then_expr, else_expr].concat())
         }
-        CodeBlock(a @ ty::TyCodeBlock { .. }) => {
+        CodeBlock(a @ ty::TyCodeBlock { .. }) | TryBlock(a @ ty::TyCodeBlock { .. }) => {
             connect_code_block(engines, a, graph, leaves, exit_node, tree_type, options)
         }
         StructExpression {
@@ -1836,6 +1844,19 @@ fn connect_expression<'eng: 'cfg, 'cfg>(
             }
             Ok(vec![])
         }
+        // The graph doesn't track the end of the enclosing `try` block, so the flow is taken to
+        // go on after the break. This only hides the dead code following it.
+        TryBreak(exp) => connect_expression(
+            engines,
+            &exp.expression,
+            graph,
+            leaves,
+            exit_node,
+            "try break",
+            tree_type,
+            exp.span.clone(),
+            options,
+        ),
     }
 }
 
@@ -2199,20 +2220,23 @@ fn connect_call_path_decl<'eng: 'cfg, 'cfg>(
 /// Checks [AttributesMap] for `#[allow(dead_code)]` usage, if so returns true
 /// otherwise returns false.
 fn allow_dead_code(attributes: AttributesMap) -> bool {
-    fn allow_dead_code_helper(attributes: AttributesMap) -> Option<bool> {
-        Some(
-            attributes
-                .get(&transform::AttributeKind::Allow)?
-                .last()?
-                .args
-                .first()?
-                .name
-                .as_str()
-                == ALLOW_DEAD_CODE_NAME,
-        )
-    }
+    attributes
+        .get(&transform::AttributeKind::Allow)
+        .into_iter()
+        .flatten()
+        .flat_map(|attr| &attr.args)
+        .any(|arg| arg.name.as_str() == ALLOW_DEAD_CODE_NAME)
+}
 
-    allow_dead_code_helper(attributes).unwrap_or_default()
+/// Checks [AttributesMap] for `#[allow(unused_variables)]` usage, if so returns true
+/// otherwise returns false.
+fn allow_unused_variables(attributes: AttributesMap) -> bool {
+    attributes
+        .get(&transform::AttributeKind::Allow)
+        .into_iter()
+        .flatten()
+        .flat_map(|attr| &attr.args)
+        .any(|arg| arg.name.as_str() == ALLOW_UNUSED_VARIABLES_NAME)
 }
 
 /// Returns true when the given `node` contains the attribute `#[allow(dead_code)]`
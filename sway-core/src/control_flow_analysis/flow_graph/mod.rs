@@ -84,6 +84,8 @@ pub enum ControlFlowGraphNode<'cfg> {
     FunctionParameter {
         param_name: Ident,
         is_self: bool,
+        /// Whether the function is annotated with `#[allow(unused_variables)]`.
+        allow_unused: bool,
     },
 }
 
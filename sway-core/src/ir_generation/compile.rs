@@ -402,7 +402,7 @@ fn compile_fn(
     let type_engine = engines.te();
     let decl_engine = engines.de();
 
-    let inline_opt = ast_fn_decl.inline();
+    let inline_opt = ast_fn_decl.inline;
     let ty::TyFunctionDecl {
         name,
         body,
@@ -24,7 +24,7 @@ use sway_ir::{
     constant::{Constant, ConstantValue},
     context::Context,
     metadata::combine as md_combine,
-    module::Module,
+    module::{Kind, Module},
     value::Value,
     Instruction, Type,
 };
@@ -253,6 +253,29 @@ pub(crate) fn compile_constant_expression_to_constant(
     }
 }
 
+/// Evaluates `expr` to a constant value, following the same rules as the value of a constant
+/// declaration. The evaluation happens in a scratch IR module, so that it can be done while
+/// type checking.
+pub(crate) fn evaluate_constant_expression(
+    engines: &Engines,
+    module_ns: &namespace::Module,
+    expr: &ty::TyExpression,
+) -> Result<ConstantValue, CompileError> {
+    let mut context = Context::new(engines.se());
+    let mut md_mgr = MetadataManager::default();
+    let module = Module::new(&mut context, Kind::Library);
+    compile_constant_expression_to_constant(
+        engines,
+        &mut context,
+        &mut md_mgr,
+        module,
+        Some(module_ns),
+        None,
+        expr,
+    )
+    .map(|constant| constant.value)
+}
+
 /// Given an environment mapping names to constants,
 /// attempt to evaluate a typed expression to a constant.
 fn const_eval_typed_expr(
@@ -291,7 +314,11 @@ fn const_eval_typed_expr(
             }
 
             let function_decl = lookup.engines.de().get_function(fn_ref);
-            let res = const_eval_codeblock(lookup, known_consts, &function_decl.body);
+            let res = const_eval_codeblock(
+                lookup,
+                known_consts,
+                &function_decl.body.without_tail_returns(),
+            );
 
             for (name, _) in arguments {
                 known_consts.pop(name);
@@ -530,10 +557,11 @@ fn const_eval_typed_expr(
                 })
             }
         },
-        // we could allow non-local control flow in pure functions, but it would
+        // the returns at the end of function bodies are removed before evaluating them; we
+        // could allow the other non-local control flow in pure functions, but it would
         // require some more work and at this point it's not clear if it is too useful
         // for constant initializers -- the user can always refactor their pure functions
-        // to not use the return statement
+        // to not return early
         ty::TyExpressionVariant::Return(exp) => {
             return Err(ConstEvalError::CannotBeEvaluatedToConst {
                 span: exp.span.clone(),
@@ -621,7 +649,9 @@ fn const_eval_typed_expr(
         | ty::TyExpressionVariant::UnsafeDowncast { .. }
         | ty::TyExpressionVariant::Break
         | ty::TyExpressionVariant::Continue
-        | ty::TyExpressionVariant::WhileLoop { .. } => {
+        | ty::TyExpressionVariant::WhileLoop { .. }
+        | ty::TyExpressionVariant::TryBlock(_)
+        | ty::TyExpressionVariant::TryBreak(_) => {
             return Err(ConstEvalError::CannotBeEvaluatedToConst {
                 span: expr.span.clone(),
             })
@@ -927,7 +957,6 @@ fn const_eval_intrinsic(
 mod tests {
     use super::*;
     use sway_error::handler::Handler;
-    use sway_ir::Kind;
 
     /// This function validates if an expression can be converted to [Constant].
     ///
@@ -1021,6 +1050,12 @@ mod tests {
         assert_is_constant(true, "", "true");
         assert_is_constant(true, "fn one() -> u64 { 1 }", "one()");
         assert_is_constant(true, "fn id(x: u64) -> u64 { x }", "id(1)");
+        assert_is_constant(true, "fn id(x: u64) -> u64 { return x; }", "id(1)");
+        assert_is_constant(
+            true,
+            "fn pick(b: bool) -> u64 { if b { return 1; } else { return 2; } }",
+            "pick(true)",
+        );
         assert_is_constant(true, "enum Color { Blue: () }", "Color::Blue");
         assert_is_constant(true, "enum Color { Blue: u64 }", "Color::Blue(1)");
         assert_is_constant(true, "struct Person { age: u64 }", "Person { age: 1 }");
@@ -1047,8 +1082,12 @@ mod tests {
             "struct Person { age: u64 }",
             "Person { age: { let mut x = 0; x = 1; 1} }",
         );
-        // At the moment this is not constant because of the "return"
-        assert_is_constant(false, "fn id(x: u64) -> u64 { return x; }", "id(1)");
+        // Only the returns at the end of a function body are supported
+        assert_is_constant(
+            false,
+            "fn pick(b: bool) -> u64 { if b { return 1; } 2 }",
+            "pick(true)",
+        );
         assert_is_constant(false, "", "[0,1][2]");
         assert_is_constant(
             false,
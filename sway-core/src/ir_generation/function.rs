@@ -63,6 +63,8 @@ pub(crate) struct FnCompiler<'eng> {
     pub(super) current_block: Block,
     block_to_break_to: Option<Block>,
     block_to_continue_to: Option<Block>,
+    // The block which the `?` operators of the innermost `try` block break to.
+    block_to_try_break_to: Option<Block>,
     current_fn_param: Option<ty::TyFunctionParameter>,
     lexical_map: LexicalMap,
     recreated_fns: HashMap<(Span, Vec<TypeId>, Vec<TypeId>), Function>,
@@ -94,6 +96,7 @@ impl<'eng> FnCompiler<'eng> {
             current_block: function.get_entry_block(context),
             block_to_break_to: None,
             block_to_continue_to: None,
+            block_to_try_break_to: None,
             lexical_map,
             recreated_fns: HashMap::new(),
             current_fn_param: None,
@@ -181,7 +184,11 @@ impl<'eng> FnCompiler<'eng> {
                     // compile and then call these properly.
                     Ok(None)
                 }
-                ty::TyDecl::FunctionDecl { .. } => unexpected_decl("function"),
+                ty::TyDecl::FunctionDecl { .. } => {
+                    // Nested functions are compiled lazily as they are called, the same way as
+                    // the trait implementations above.
+                    Ok(None)
+                }
                 ty::TyDecl::TraitDecl { .. } => unexpected_decl("trait"),
                 ty::TyDecl::StructDecl { .. } => unexpected_decl("struct"),
                 ty::TyDecl::AbiDecl { .. } => unexpected_decl("abi"),
@@ -442,6 +449,25 @@ impl<'eng> FnCompiler<'eng> {
             ty::TyExpressionVariant::Return(exp) => {
                 self.compile_return_statement(context, md_mgr, exp)
             }
+            ty::TyExpressionVariant::TryBlock(body) => {
+                self.compile_try_block(context, md_mgr, body, ast_expr.return_type, span_md_idx)
+            }
+            ty::TyExpressionVariant::TryBreak(exp) => match self.block_to_try_break_to {
+                Some(block_to_try_break_to) => {
+                    let value = self.compile_expression_to_value(context, md_mgr, exp)?;
+                    if value.is_diverging(context) {
+                        return Ok(value);
+                    }
+                    Ok(self
+                        .current_block
+                        .ins(context)
+                        .branch(block_to_try_break_to, vec![value]))
+                }
+                None => Err(CompileError::Internal(
+                    "Unexpected `?` outside of a `try` block.",
+                    ast_expr.span.clone(),
+                )),
+            },
         }
     }
 
@@ -1631,6 +1657,54 @@ impl<'eng> FnCompiler<'eng> {
         Ok(Constant::get_unit(context).add_metadatum(context, span_md_idx))
     }
 
+    fn compile_try_block(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        body: &ty::TyCodeBlock,
+        return_type: TypeId,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        // As for the while loops, the `?` operators break to an intermediate block which is
+        // created before the body, and which jumps to the final block created after it.
+        let return_type = convert_resolved_typeid_no_span(
+            self.engines.te(),
+            self.engines.de(),
+            context,
+            &return_type,
+        )
+        .unwrap_or_else(|_| Type::get_unit(context));
+        let break_block = self
+            .function
+            .create_block(context, Some("try_break".into()));
+        let break_val_arg_idx = break_block.new_arg(context, return_type);
+
+        let prev_block_to_try_break_to = self.block_to_try_break_to;
+        self.block_to_try_break_to = Some(break_block);
+        let body_value = self.compile_code_block(context, md_mgr, body);
+        self.block_to_try_break_to = prev_block_to_try_break_to;
+        let body_value = body_value?;
+        let body_block_end = self.current_block;
+
+        let final_block = self.function.create_block(context, Some("end_try".into()));
+        let final_val_arg_idx = final_block.new_arg(context, return_type);
+        let break_value = break_block.get_arg(context, break_val_arg_idx).unwrap();
+        break_block
+            .ins(context)
+            .branch(final_block, vec![break_value]);
+        if !body_block_end.is_terminated(context) {
+            body_block_end
+                .ins(context)
+                .branch(final_block, vec![body_value]);
+        }
+
+        self.current_block = final_block;
+        Ok(final_block
+            .get_arg(context, final_val_arg_idx)
+            .unwrap()
+            .add_metadatum(context, span_md_idx))
+    }
+
     pub(crate) fn get_function_var(&self, context: &mut Context, name: &str) -> Option<LocalVar> {
         self.lexical_map
             .get(name)
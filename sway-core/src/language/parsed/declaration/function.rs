@@ -18,6 +18,8 @@ pub struct FunctionDeclaration {
     pub return_type: TypeArgument,
     pub type_parameters: Vec<TypeParameter>,
     pub where_clause: Vec<(Ident, Vec<TraitConstraint>)>,
+    /// Whether the function is declared as `const fn`, and so must be evaluable at compile time.
+    pub is_const: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -27,6 +29,9 @@ pub struct FunctionParameter {
     pub is_mutable: bool,
     pub mutability_span: Span,
     pub type_argument: TypeArgument,
+    pub default_value: Option<Expression>,
+    /// The doc attributes of the parameter, the only ones allowed on parameters.
+    pub attributes: transform::AttributesMap,
 }
 
 impl EqWithEngines for FunctionParameter {}
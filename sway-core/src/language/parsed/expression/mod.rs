@@ -28,6 +28,13 @@ pub struct FunctionApplicationExpression {
     pub arguments: Vec<Expression>,
 }
 
+/// An argument of a function call labeled with the name of the parameter it is passed to.
+#[derive(Debug, Clone)]
+pub struct NamedArgumentExpression {
+    pub name: Ident,
+    pub value: Box<Expression>,
+}
+
 #[derive(Debug, Clone)]
 pub struct LazyOperatorExpression {
     pub op: LazyOp,
@@ -177,6 +184,8 @@ pub enum ExpressionKind {
     /// is a free function call, an enum variant or a UFCS (Rust term) style associated function call.
     AmbiguousPathExpression(Box<AmbiguousPathExpression>),
     FunctionApplication(Box<FunctionApplicationExpression>),
+    /// A labeled argument of a function call, e.g. `amount: 100` in `transfer(amount: 100)`.
+    NamedArgument(NamedArgumentExpression),
     LazyOperator(LazyOperatorExpression),
     /// And ambiguous single ident which could either be a variable or an enum variant
     AmbiguousVariableExpression(Ident),
@@ -231,6 +240,14 @@ pub enum ExpressionKind {
     Continue,
     Reassignment(ReassignmentExpression),
     Return(Box<Expression>),
+    /// The `?` operator applied to a `Result` or an `Option`. The operand is always a variable,
+    /// which the value is bound to beforehand.
+    Try(Box<Expression>),
+    /// A `try` block, in which the `?` operator short-circuits to the end of the block. Its value
+    /// is wrapped in the `Ok` or `Some` variant of the block's type.
+    TryBlock(CodeBlock),
+    /// The `else` block of a `let PAT = EXPR else { BLOCK };` statement, which has to diverge.
+    LetElseBlock(CodeBlock),
 }
 
 #[derive(Debug, Clone)]
@@ -163,6 +163,18 @@ impl TyAstNode {
         }
     }
 
+    /// Returns the top-level expression of `self`, if any. For variable declarations this is
+    /// the initializer.
+    pub(crate) fn expression(&self) -> Option<&TyExpression> {
+        match &self.content {
+            TyAstNodeContent::Expression(exp) | TyAstNodeContent::ImplicitReturnExpression(exp) => {
+                Some(exp)
+            }
+            TyAstNodeContent::Declaration(TyDecl::VariableDecl(decl)) => Some(&decl.body),
+            TyAstNodeContent::SideEffect(_) | TyAstNodeContent::Declaration(_) => None,
+        }
+    }
+
     /// Returns `true` if this AST node will be exported in a library, i.e. it is a public declaration.
     pub(crate) fn is_public(&self, decl_engine: &DeclEngine) -> bool {
         match &self.content {
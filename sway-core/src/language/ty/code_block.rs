@@ -63,3 +63,126 @@ impl DeterministicallyAborts for TyCodeBlock {
             .any(|x| x.deterministically_aborts(decl_engine, check_call_body))
     }
 }
+
+impl TyCodeBlock {
+    /// Returns the top-level expressions of the nodes in this block.
+    pub(crate) fn expressions(&self) -> Vec<&TyExpression> {
+        self.contents
+            .iter()
+            .filter_map(|node| node.expression())
+            .collect()
+    }
+
+    /// Calls `f` on every expression in this block, recursing into nested expressions.
+    pub(crate) fn visit_expressions<'a>(&'a self, f: &mut impl FnMut(&'a TyExpression)) {
+        for expr in self.expressions() {
+            expr.visit(f);
+        }
+    }
+
+    /// Returns the `return` statements of this block, taken as a function body, which are not in
+    /// a tail position.
+    ///
+    /// A `return` is in a tail position if it ends the body, or ends a branch of an `if` or
+    /// `match` which itself is in a tail position. Those are exactly the ones removed by
+    /// [TyCodeBlock::without_tail_returns].
+    pub(crate) fn early_return_statements(&self) -> Vec<&TyExpression> {
+        let last = self.contents.len().saturating_sub(1);
+        self.contents
+            .iter()
+            .enumerate()
+            .flat_map(|(i, node)| match &node.content {
+                TyAstNodeContent::Expression(exp)
+                    if i == last && matches!(exp.expression, TyExpressionVariant::Return(_)) =>
+                {
+                    tail_early_return_statements(exp)
+                }
+                TyAstNodeContent::ImplicitReturnExpression(exp) if i == last => {
+                    tail_early_return_statements(exp)
+                }
+                _ => node.gather_return_statements(),
+            })
+            .collect()
+    }
+
+    /// Returns a copy of this block, taken as a function body, where every `return` in a tail
+    /// position is replaced by the returned expression, so that the block evaluates to it.
+    pub(crate) fn without_tail_returns(&self) -> TyCodeBlock {
+        let mut contents = self.contents.clone();
+        if let Some(last) = contents.pop() {
+            let content = match last.content {
+                TyAstNodeContent::Expression(exp)
+                    if matches!(exp.expression, TyExpressionVariant::Return(_)) =>
+                {
+                    TyAstNodeContent::ImplicitReturnExpression(strip_tail_returns(exp))
+                }
+                TyAstNodeContent::ImplicitReturnExpression(exp) => {
+                    TyAstNodeContent::ImplicitReturnExpression(strip_tail_returns(exp))
+                }
+                content => content,
+            };
+            contents.push(TyAstNode {
+                content,
+                span: last.span,
+            });
+        }
+        TyCodeBlock { contents }
+    }
+}
+
+fn tail_early_return_statements(exp: &TyExpression) -> Vec<&TyExpression> {
+    match &exp.expression {
+        TyExpressionVariant::Return(exp) => tail_early_return_statements(exp),
+        TyExpressionVariant::CodeBlock(code_block) => code_block.early_return_statements(),
+        TyExpressionVariant::MatchExp { desugared, .. } => tail_early_return_statements(desugared),
+        TyExpressionVariant::IfExp {
+            condition,
+            then,
+            r#else,
+        } => {
+            let mut returns = condition.gather_return_statements();
+            returns.extend(tail_early_return_statements(then));
+            if let Some(r#else) = r#else {
+                returns.extend(tail_early_return_statements(r#else));
+            }
+            returns
+        }
+        _ => exp.gather_return_statements(),
+    }
+}
+
+fn strip_tail_returns(exp: TyExpression) -> TyExpression {
+    let TyExpression {
+        expression,
+        return_type,
+        span,
+    } = exp;
+    let expression = match expression {
+        TyExpressionVariant::Return(exp) => return strip_tail_returns(*exp),
+        TyExpressionVariant::CodeBlock(code_block) => {
+            TyExpressionVariant::CodeBlock(code_block.without_tail_returns())
+        }
+        TyExpressionVariant::MatchExp {
+            desugared,
+            scrutinees,
+        } => TyExpressionVariant::MatchExp {
+            desugared: Box::new(strip_tail_returns(*desugared)),
+            scrutinees,
+        },
+        TyExpressionVariant::IfExp {
+            condition,
+            then,
+            r#else,
+        } => TyExpressionVariant::IfExp {
+            condition,
+            then: Box::new(strip_tail_returns(*then)),
+            r#else: r#else.map(|r#else| Box::new(strip_tail_returns(*r#else))),
+        },
+        expression => expression,
+    };
+    TyExpression {
+        expression,
+        return_type,
+        span,
+    }
+}
@@ -4,25 +4,28 @@ use std::{
 };
 
 use sha2::{Digest, Sha256};
-use sway_error::handler::{ErrorEmitted, Handler};
+use sway_error::{
+    error::CompileError,
+    handler::{ErrorEmitted, Handler},
+};
 
 use crate::{
     decl_engine::*,
     engine_threading::*,
-    language::{parsed, ty::*, Inline, Purity, Visibility},
+    language::{parsed, ty::*, CallPath, Inline, Purity, Visibility},
     transform,
     type_system::*,
     types::*,
 };
 
-use sway_types::{
-    constants::{INLINE_ALWAYS_NAME, INLINE_NEVER_NAME},
-    Ident, Named, Span, Spanned,
-};
+use sway_types::{Ident, Named, Span, Spanned};
 
 #[derive(Clone, Debug)]
 pub struct TyFunctionDecl {
     pub name: Ident,
+    /// The name given by the `fn_name_rewriter` of the build config, if any. It replaces `name`
+    /// in the selector, while the function is still declared and called by `name`.
+    pub symbol_name: Option<Ident>,
     pub body: TyCodeBlock,
     pub parameters: Vec<TyFunctionParameter>,
     pub implementing_type: Option<TyDecl>,
@@ -34,7 +37,34 @@ pub struct TyFunctionDecl {
     /// whether this function exists in another contract and requires a call to it or not
     pub is_contract_call: bool,
     pub purity: Purity,
+    /// The storage fields accessed in the body, or `None` if the function has no storage
+    /// attributes.
+    pub storage_access: Option<StorageAccessSet>,
+    /// The inlining requested with the `#[inline(..)]` attribute, if any.
+    pub inline: Option<Inline>,
+    /// Whether every path through the body reverts or calls a diverging function, so that a
+    /// call of this function never returns.
+    pub diverges: bool,
+    /// Whether this is a method of an inherent impl which takes no receiver and returns `Self`,
+    /// like `fn new() -> Self`. It only serves diagnostics and tooling.
+    pub is_constructor: bool,
+    /// Whether the `#[reentrancy(guard)]` attribute asks for this contract method to reject
+    /// calls made while it is already executing.
+    pub reentrancy_guard: bool,
+    /// Whether the `#[fallback]` attribute makes this the contract method called when no other
+    /// method matches the selector of a call.
+    pub is_fallback: bool,
+    /// The bounds of the `where` clause, type checked and grouped per constrained type.
     pub where_clause: Vec<(Ident, Vec<TraitConstraint>)>,
+    /// The cyclomatic complexity of the body, i.e. one plus the number of its branches. It only
+    /// serves diagnostics and tooling.
+    pub complexity: u32,
+    /// The gas cost expected of a call, given with the `#[gas_hint(..)]` attribute. It only
+    /// serves tooling, which can compare it against measured costs.
+    pub gas_hint: Option<u64>,
+    /// Whether the function is declared as `const fn`, in which case its body is checked to be
+    /// evaluable at compile time, e.g. for the default value of a configurable.
+    pub is_const: bool,
 }
 
 impl Named for TyFunctionDecl {
@@ -64,6 +94,7 @@ impl PartialEqWithEngines for TyFunctionDecl {
             && self.visibility == other.visibility
             && self.is_contract_call == other.is_contract_call
             && self.purity == other.purity
+            && self.inline == other.inline
     }
 }
 
@@ -78,12 +109,22 @@ impl HashWithEngines for TyFunctionDecl {
             visibility,
             is_contract_call,
             purity,
+            inline,
             // these fields are not hashed because they aren't relevant/a
             // reliable source of obj v. obj distinction
             span: _,
             attributes: _,
             implementing_type: _,
+            symbol_name: _,
             where_clause: _,
+            storage_access: _,
+            diverges: _,
+            is_constructor: _,
+            reentrancy_guard: _,
+            is_fallback: _,
+            complexity: _,
+            gas_hint: _,
+            is_const: _,
         } = self;
         name.hash(state);
         body.hash(state, engines);
@@ -93,6 +134,7 @@ impl HashWithEngines for TyFunctionDecl {
         visibility.hash(state);
         is_contract_call.hash(state);
         purity.hash(state);
+        inline.hash(state);
     }
 }
 
@@ -105,6 +147,10 @@ impl SubstTypes for TyFunctionDecl {
             .iter_mut()
             .for_each(|x| x.subst(type_mapping, engines));
         self.return_type.subst(type_mapping, engines);
+        self.where_clause
+            .iter_mut()
+            .flat_map(|(_, bounds)| bounds.iter_mut())
+            .for_each(|x| x.subst(type_mapping, engines));
         self.body.subst(type_mapping, engines);
     }
 }
@@ -118,6 +164,10 @@ impl ReplaceSelfType for TyFunctionDecl {
             .iter_mut()
             .for_each(|x| x.replace_self_type(engines, self_type));
         self.return_type.replace_self_type(engines, self_type);
+        self.where_clause
+            .iter_mut()
+            .flat_map(|(_, bounds)| bounds.iter_mut())
+            .for_each(|x| x.replace_self_type(engines, self_type));
         self.body.replace_self_type(engines, self_type);
     }
 }
@@ -222,11 +272,14 @@ impl TyFunctionDecl {
             visibility,
             purity,
             where_clause,
+            is_const,
             ..
         } = decl;
         TyFunctionDecl {
             purity,
+            storage_access: None,
             name,
+            symbol_name: None,
             body: TyCodeBlock {
                 contents: Default::default(),
             },
@@ -238,7 +291,15 @@ impl TyFunctionDecl {
             visibility,
             return_type,
             type_parameters: Default::default(),
+            inline: None,
+            diverges: false,
+            is_constructor: false,
+            reentrancy_guard: false,
+            is_fallback: false,
             where_clause,
+            complexity: 1,
+            gas_hint: None,
+            is_const,
         }
     }
 
@@ -283,31 +344,89 @@ impl TyFunctionDecl {
         Ok(buf)
     }
 
+    /// Returns the name the function selector is computed from, e.g. `foo(s(u64,bool),a[u8;2])`.
+    /// A name given with the `#[selector("..")]` attribute replaces the computed one, and a name
+    /// given with the `#[abi_name("..")]` attribute, or else the rewritten `symbol_name`, replaces
+    /// the function name in it.
+    ///
+    /// The parameter types must be monomorphized, as generic types have no ABI encoding.
     pub fn to_selector_name(
         &self,
         handler: &Handler,
         engines: &Engines,
     ) -> Result<String, ErrorEmitted> {
-        let named_params = self
-            .parameters
-            .iter()
-            .map(|TyFunctionParameter { type_argument, .. }| {
-                engines
-                    .te()
-                    .to_typeinfo(type_argument.type_id, &type_argument.span)
-                    .expect("unreachable I think?")
-                    .to_selector_name(handler, engines, &type_argument.span)
-            })
-            .filter_map(|name| name.ok())
-            .collect::<Vec<String>>();
+        if let Some(selector) = self.attributes.selector_override() {
+            return Ok(selector.to_string());
+        }
+
+        let mut named_params = vec![];
+        for TyFunctionParameter { type_argument, .. } in &self.parameters {
+            let ty = engines
+                .te()
+                .to_typeinfo(type_argument.type_id, &type_argument.span)
+                .map_err(|e| handler.emit_err(e.into()))?;
+            named_params.push(ty.to_selector_name(handler, engines, &type_argument.span)?);
+        }
 
         Ok(format!(
             "{}({})",
-            self.name.as_str(),
+            self.attributes.abi_name_override().unwrap_or_else(|| self
+                .symbol_name
+                .as_ref()
+                .unwrap_or(&self.name)
+                .as_str()),
             named_params.join(","),
         ))
     }
 
+    /// Splits a selector name, as produced by [TyFunctionDecl::to_selector_name], into the
+    /// function name and the selector names of the parameter types.
+    ///
+    /// Commas within the parameter types, e.g. in tuples or struct fields, are not treated as
+    /// separators, and a trailing comma after the last parameter type is allowed.
+    pub fn parse_selector_name(selector: &str) -> Result<(String, Vec<String>), CompileError> {
+        let malformed = || CompileError::MalformedSelectorName {
+            selector: selector.to_string(),
+            span: Span::from_string(selector.to_string()),
+        };
+
+        let (name, params) = selector.split_once('(').ok_or_else(malformed)?;
+        let params = params.strip_suffix(')').ok_or_else(malformed)?;
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(malformed());
+        }
+
+        let mut param_names = vec![];
+        let mut depth = 0usize;
+        let mut start = 0;
+        for (i, c) in params.char_indices() {
+            match c {
+                '(' | '[' | '<' => depth += 1,
+                ')' | ']' | '>' => depth = depth.checked_sub(1).ok_or_else(malformed)?,
+                ',' if depth == 0 => {
+                    param_names.push(&params[start..i]);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        if depth != 0 {
+            return Err(malformed());
+        }
+        // The last parameter type is only missing if there are none, or after a trailing comma.
+        if start < params.len() {
+            param_names.push(&params[start..]);
+        }
+        if param_names.iter().any(|param_name| param_name.is_empty()) {
+            return Err(malformed());
+        }
+
+        Ok((
+            name.to_string(),
+            param_names.into_iter().map(str::to_string).collect(),
+        ))
+    }
+
     /// Whether or not this function is the default entry point.
     pub fn is_main_entry(&self) -> bool {
         // NOTE: We may want to make this check more sophisticated or customisable in the future,
@@ -321,26 +440,272 @@ impl TyFunctionDecl {
             .contains_key(&transform::AttributeKind::Test)
     }
 
-    pub fn inline(&self) -> Option<Inline> {
-        match self
-            .attributes
-            .get(&transform::AttributeKind::Inline)?
-            .last()?
-            .args
-            .first()?
-            .name
-            .as_str()
-        {
-            INLINE_NEVER_NAME => Some(Inline::Never),
-            INLINE_ALWAYS_NAME => Some(Inline::Always),
-            _ => None,
-        }
-    }
-
     /// Whether or not this function describes a program entry point.
     pub fn is_entry(&self) -> bool {
         self.is_main_entry() || self.is_test()
     }
+
+    /// Whether or not this function is declared to neither read nor write storage.
+    pub fn is_pure(&self) -> bool {
+        self.purity == Purity::Pure
+    }
+
+    /// Returns the spans of the points this function exits at: its explicit `return`s, in the
+    /// order in which they appear, followed by the tail expression of its body, if any.
+    pub fn return_sites(&self) -> Vec<Span> {
+        let mut return_sites: Vec<Span> = self
+            .body
+            .contents
+            .iter()
+            .flat_map(|node| node.gather_return_statements())
+            .map(|stmt| stmt.span.clone())
+            .collect();
+        return_sites.extend(
+            self.body
+                .contents
+                .iter()
+                .filter_map(|node| match &node.content {
+                    TyAstNodeContent::ImplicitReturnExpression(exp) => Some(exp.span.clone()),
+                    _ => None,
+                }),
+        );
+        return_sites
+    }
+
+    /// The way this function takes its `self` receiver, or `None` if it doesn't have one.
+    pub fn self_kind(&self) -> Option<SelfKind> {
+        self.parameters.first().and_then(|param| param.self_kind())
+    }
+
+    /// Returns each type parameter of this function along with its trait bounds, whether
+    /// they are declared inline, as in `<T: Eq>`, or in the `where` clause. These are the
+    /// bounds checked for the type arguments of every call of the function.
+    pub fn trait_constraints(&self) -> Vec<(Ident, Vec<TraitConstraint>)> {
+        // The bounds of the `where` clause are merged into the constraints of the type
+        // parameters they apply to.
+        let mut constraints: Vec<(Ident, Vec<TraitConstraint>)> = self
+            .type_parameters
+            .iter()
+            .map(|type_param| {
+                (
+                    type_param.name_ident.clone(),
+                    type_param.trait_constraints.clone(),
+                )
+            })
+            .collect();
+        for (name, bounds) in &self.where_clause {
+            if !constraints.iter().any(|(ty_name, _)| ty_name == name) {
+                constraints.push((name.clone(), bounds.clone()));
+            }
+        }
+        constraints
+    }
+
+    /// Returns the paths of the functions called in the body of this function, methods
+    /// included, in the order in which they are first called. Each callee is listed once.
+    ///
+    /// A path is the one the call was written with, but it ends with the name of the
+    /// declaration the call resolved to.
+    pub fn called_functions(&self, engines: &Engines) -> Vec<CallPath> {
+        let decl_engine = engines.de();
+        let mut called_functions: Vec<CallPath> = vec![];
+        self.body.visit_expressions(&mut |expr| {
+            if let TyExpressionVariant::FunctionApplication {
+                call_path, fn_ref, ..
+            } = &expr.expression
+            {
+                let call_path = CallPath {
+                    suffix: decl_engine.get_function(fn_ref).name,
+                    ..call_path.clone()
+                };
+                if !called_functions.contains(&call_path) {
+                    called_functions.push(call_path);
+                }
+            }
+        });
+        called_functions
+    }
+
+    /// Renders the signature of this function as it would be declared, e.g.
+    /// `pub fn f<T: Eq>(ref mut self, x: T) -> u64 where T: Ord`.
+    ///
+    /// Unlike [TyFunctionDecl::to_selector_name], this is meant to be read by users.
+    pub fn signature_string(&self, engines: &Engines) -> String {
+        let bounds_string = |bounds: &[&TraitConstraint]| {
+            bounds
+                .iter()
+                .map(|bound| {
+                    let mut bound_string = bound.trait_name.suffix.to_string();
+                    if !bound.type_arguments.is_empty() {
+                        let type_arguments = bound
+                            .type_arguments
+                            .iter()
+                            .map(|arg| engines.help_out(arg.type_id).to_string())
+                            .collect::<Vec<_>>();
+                        bound_string.push_str(&format!("<{}>", type_arguments.join(", ")));
+                    }
+                    bound_string
+                })
+                .collect::<Vec<_>>()
+                .join(" + ")
+        };
+        let where_bounds = |name: &Ident| {
+            self.where_clause
+                .iter()
+                .filter(|(ty_name, _)| ty_name == name)
+                .flat_map(|(_, bounds)| bounds.iter())
+                .collect::<Vec<_>>()
+        };
+
+        let mut signature = String::new();
+        match self.visibility {
+            Visibility::Public => signature.push_str("pub "),
+            Visibility::Package => signature.push_str("pub(package) "),
+            Visibility::Private => {}
+        }
+        signature.push_str(&format!("fn {}", self.name));
+
+        // The bounds of the `where` clause are merged into the constraints of the type
+        // parameters, and are only rendered in the `where` clause.
+        if !self.type_parameters.is_empty() {
+            let type_parameters = self
+                .type_parameters
+                .iter()
+                .map(|type_param| {
+                    let where_bounds = where_bounds(&type_param.name_ident);
+                    let inline_bounds = type_param
+                        .trait_constraints
+                        .iter()
+                        .filter(|bound| {
+                            !where_bounds
+                                .iter()
+                                .any(|where_bound| where_bound.trait_name == bound.trait_name)
+                        })
+                        .collect::<Vec<_>>();
+                    if inline_bounds.is_empty() {
+                        type_param.name_ident.to_string()
+                    } else {
+                        format!(
+                            "{}: {}",
+                            type_param.name_ident,
+                            bounds_string(&inline_bounds)
+                        )
+                    }
+                })
+                .collect::<Vec<_>>();
+            signature.push_str(&format!("<{}>", type_parameters.join(", ")));
+        }
+
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|param| {
+                let prefix = if param.is_reference && param.is_mutable {
+                    "ref mut "
+                } else {
+                    ""
+                };
+                if param.is_self() {
+                    format!("{prefix}self")
+                } else {
+                    format!(
+                        "{prefix}{}: {}",
+                        param.name,
+                        engines.help_out(param.type_argument.type_id)
+                    )
+                }
+            })
+            .collect::<Vec<_>>();
+        signature.push_str(&format!("({})", parameters.join(", ")));
+
+        if !engines.te().get(self.return_type.type_id).is_unit() {
+            signature.push_str(&format!(
+                " -> {}",
+                engines.help_out(self.return_type.type_id)
+            ));
+        }
+
+        if !self.where_clause.is_empty() {
+            let where_clause = self
+                .where_clause
+                .iter()
+                .map(|(name, bounds)| {
+                    format!(
+                        "{name}: {}",
+                        bounds_string(&bounds.iter().collect::<Vec<_>>())
+                    )
+                })
+                .collect::<Vec<_>>();
+            signature.push_str(&format!(" where {}", where_clause.join(", ")));
+        }
+
+        signature
+    }
+
+    /// Describes the signature of this function as JSON, for consumption by external tools.
+    ///
+    /// Types are rendered by name rather than by their type ids, so the output of the same
+    /// declaration is identical across compilations. The `selector` is `null` if the function
+    /// has no selector name, e.g. because it is generic.
+    pub fn to_signature_json(&self, engines: &Engines) -> serde_json::Value {
+        let purity = match self.purity {
+            Purity::Pure => "pure",
+            Purity::Reads => "reads",
+            Purity::Writes => "writes",
+            Purity::ReadsWrites => "reads_writes",
+        };
+        let bound_string = |bound: &TraitConstraint| {
+            let mut bound_string = bound.trait_name.suffix.to_string();
+            if !bound.type_arguments.is_empty() {
+                let type_arguments = bound
+                    .type_arguments
+                    .iter()
+                    .map(|arg| engines.help_out(arg.type_id).to_string())
+                    .collect::<Vec<_>>();
+                bound_string.push_str(&format!("<{}>", type_arguments.join(", ")));
+            }
+            bound_string
+        };
+
+        let generics = self
+            .trait_constraints()
+            .into_iter()
+            .map(|(name, bounds)| {
+                serde_json::json!({
+                    "name": name.as_str(),
+                    "bounds": bounds.iter().map(bound_string).collect::<Vec<_>>(),
+                })
+            })
+            .collect::<Vec<_>>();
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|param| {
+                serde_json::json!({
+                    "name": param.name.as_str(),
+                    "type": engines.help_out(param.type_argument.type_id).to_string(),
+                    "is_reference": param.is_reference,
+                    "is_mutable": param.is_mutable,
+                })
+            })
+            .collect::<Vec<_>>();
+        // Failing to compute the selector is not an error here, so the diagnostics are dropped.
+        let selector = self.to_selector_name(&Handler::default(), engines).ok();
+
+        serde_json::json!({
+            "name": self.name.as_str(),
+            "visibility": match self.visibility {
+                Visibility::Public => "public",
+                Visibility::Package => "package",
+                Visibility::Private => "private",
+            },
+            "purity": purity,
+            "generics": generics,
+            "parameters": parameters,
+            "return_type": engines.help_out(self.return_type.type_id).to_string(),
+            "selector": selector,
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -350,6 +715,9 @@ pub struct TyFunctionParameter {
     pub is_mutable: bool,
     pub mutability_span: Span,
     pub type_argument: TypeArgument,
+    /// The value used for this parameter when a call site omits the argument.
+    pub default_value: Option<TyExpression>,
+    pub attributes: transform::AttributesMap,
 }
 
 impl EqWithEngines for TyFunctionParameter {}
@@ -372,6 +740,8 @@ impl HashWithEngines for TyFunctionParameter {
             // these fields are not hashed because they aren't relevant/a
             // reliable source of obj v. obj distinction
             mutability_span: _,
+            default_value: _,
+            attributes: _,
         } = self;
         name.hash(state);
         type_argument.hash(state, engines);
@@ -383,6 +753,9 @@ impl HashWithEngines for TyFunctionParameter {
 impl SubstTypes for TyFunctionParameter {
     fn subst_inner(&mut self, type_mapping: &TypeSubstMap, engines: &Engines) {
         self.type_argument.type_id.subst(type_mapping, engines);
+        if let Some(default_value) = &mut self.default_value {
+            default_value.subst(type_mapping, engines);
+        }
     }
 }
 
@@ -391,6 +764,9 @@ impl ReplaceSelfType for TyFunctionParameter {
         self.type_argument
             .type_id
             .replace_self_type(engines, self_type);
+        if let Some(default_value) = &mut self.default_value {
+            default_value.replace_self_type(engines, self_type);
+        }
     }
 }
 
@@ -398,6 +774,61 @@ impl TyFunctionParameter {
     pub fn is_self(&self) -> bool {
         self.name.as_str() == "self"
     }
+
+    /// Returns the documentation of this parameter, given by its doc comments and `#[doc(..)]`
+    /// attributes in the order they are declared, one per line, or `None` if it has none.
+    pub fn doc_string(&self) -> Option<String> {
+        let lines = self
+            .attributes
+            .in_order()
+            .into_iter()
+            .flat_map(|(kind, attr)| {
+                attr.args.iter().filter_map(move |arg| {
+                    let text = arg.name.as_str();
+                    match kind {
+                        // Doc comments keep the space following the `///`.
+                        transform::AttributeKind::DocComment => {
+                            Some(text.strip_prefix(' ').unwrap_or(text))
+                        }
+                        transform::AttributeKind::Doc => Some(
+                            text.strip_prefix('"')
+                                .and_then(|text| text.strip_suffix('"'))
+                                .unwrap_or(text),
+                        ),
+                        _ => None,
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        if lines.is_empty() {
+            None
+        } else {
+            Some(lines.join("\n"))
+        }
+    }
+
+    /// The way this parameter takes the receiver, or `None` if it isn't `self`.
+    pub fn self_kind(&self) -> Option<SelfKind> {
+        if !self.is_self() {
+            return None;
+        }
+        Some(if self.is_reference && self.is_mutable {
+            SelfKind::RefMut
+        } else {
+            SelfKind::Value
+        })
+    }
+}
+
+/// The form of the `self` receiver of a method.
+///
+/// `mut self` is rejected during type checking, so it has no kind of its own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SelfKind {
+    /// `self`, the receiver is taken by value and can't be mutated.
+    Value,
+    /// `ref mut self`, mutations of the receiver are visible to the caller.
+    RefMut,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
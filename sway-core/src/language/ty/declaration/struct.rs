@@ -126,6 +126,21 @@ impl TyStructDecl {
             }
         }
     }
+
+    /// Returns the only field of a struct marked `#[abi_transparent]`, which stands for the
+    /// struct in function selectors, or `None` if the struct isn't transparent.
+    pub fn abi_transparent_field(&self) -> Option<&TyStructField> {
+        if !self
+            .attributes
+            .contains_key(&transform::AttributeKind::AbiTransparent)
+        {
+            return None;
+        }
+        match self.fields.as_slice() {
+            [field] => Some(field),
+            _ => None,
+        }
+    }
 }
 
 impl Spanned for TyStructField {
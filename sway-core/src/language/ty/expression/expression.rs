@@ -260,7 +260,14 @@ impl CollectTypesMetadata for TyExpression {
                     res.append(&mut content.collect_types_metadata(handler, ctx)?);
                 }
             }
-            Return(exp) => res.append(&mut exp.collect_types_metadata(handler, ctx)?),
+            Return(exp) | TryBreak(exp) => {
+                res.append(&mut exp.collect_types_metadata(handler, ctx)?)
+            }
+            TryBlock(body) => {
+                for content in body.contents.iter() {
+                    res.append(&mut content.collect_types_metadata(handler, ctx)?);
+                }
+            }
             // storage access can never be generic
             // variable expressions don't ever have return types themselves, they're stored in
             // `TyExpression::return_type`. Variable expressions are just names of variables.
@@ -374,6 +381,9 @@ impl DeterministicallyAborts for TyExpression {
             // someone could write `return break;` in a loop, which would mean the return never
             // gets executed.
             Return(..) => true,
+            TryBlock(body) => body.deterministically_aborts(decl_engine, check_call_body),
+            // Leaving a `try` block doesn't leave the function.
+            TryBreak(exp) => exp.deterministically_aborts(decl_engine, check_call_body),
         }
     }
 }
@@ -393,7 +403,24 @@ impl TyExpression {
     /// This does _not_ extract implicit return statements as those are not control flow! This is
     /// _only_ for explicit returns.
     pub(crate) fn gather_return_statements(&self) -> Vec<&TyExpression> {
-        self.expression.gather_return_statements()
+        match &self.expression {
+            // The whole `return` expression is gathered, rather than just the returned value, so
+            // that diagnostics can point at the statement.
+            TyExpressionVariant::Return(_) => {
+                let mut buf = vec![self];
+                buf.append(&mut self.expression.gather_return_statements());
+                buf
+            }
+            _ => self.expression.gather_return_statements(),
+        }
+    }
+
+    /// Calls `f` on `self` and then, recursively, on every expression nested within it.
+    pub(crate) fn visit<'a>(&'a self, f: &mut impl FnMut(&'a TyExpression)) {
+        f(self);
+        for expr in self.expression.sub_expressions() {
+            expr.visit(f);
+        }
     }
 
     /// gathers the mutability of the expressions within
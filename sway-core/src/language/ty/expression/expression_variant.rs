@@ -136,6 +136,12 @@ pub enum TyExpressionVariant {
     Continue,
     Reassignment(Box<TyReassignment>),
     Return(Box<TyExpression>),
+    /// A `try` block. Its value is the `Result` which the `?` operators within it short-circuit
+    /// to, and the value of its body is already wrapped in the `Ok` variant.
+    TryBlock(TyCodeBlock),
+    /// Exits the innermost `try` block with the given `Err` value. This is what the `?` operator
+    /// turns into within a `try` block.
+    TryBreak(Box<TyExpression>),
 }
 
 impl EqWithEngines for TyExpressionVariant {}
@@ -394,6 +400,9 @@ impl PartialEqWithEngines for TyExpressionVariant {
                     condition: r_condition,
                 },
             ) => l_body.eq(r_body, engines) && l_condition.eq(r_condition, engines),
+            (Self::Return(l_exp), Self::Return(r_exp))
+            | (Self::TryBreak(l_exp), Self::TryBreak(r_exp)) => l_exp.eq(r_exp, engines),
+            (Self::TryBlock(l_body), Self::TryBlock(r_body)) => l_body.eq(r_body, engines),
             (l, r) => std::mem::discriminant(l) == std::mem::discriminant(r),
         }
     }
@@ -590,9 +599,12 @@ impl HashWithEngines for TyExpressionVariant {
             Self::Reassignment(exp) => {
                 exp.hash(state, engines);
             }
-            Self::Return(exp) => {
+            Self::Return(exp) | Self::TryBreak(exp) => {
                 exp.hash(state, engines);
             }
+            Self::TryBlock(body) => {
+                body.hash(state, engines);
+            }
         }
     }
 }
@@ -737,6 +749,8 @@ impl SubstTypes for TyExpressionVariant {
             Continue => (),
             Reassignment(reassignment) => reassignment.subst(type_mapping, engines),
             Return(stmt) => stmt.subst(type_mapping, engines),
+            TryBlock(block) => block.subst(type_mapping, engines),
+            TryBreak(stmt) => stmt.subst(type_mapping, engines),
         }
     }
 }
@@ -875,6 +889,8 @@ impl ReplaceSelfType for TyExpressionVariant {
             Continue => (),
             Reassignment(reassignment) => reassignment.replace_self_type(engines, self_type),
             Return(stmt) => stmt.replace_self_type(engines, self_type),
+            TryBlock(block) => block.replace_self_type(engines, self_type),
+            TryBreak(stmt) => stmt.replace_self_type(engines, self_type),
         }
     }
 }
@@ -982,6 +998,8 @@ impl ReplaceDecls for TyExpressionVariant {
             Continue => (),
             Reassignment(reassignment) => reassignment.replace_decls(decl_mapping, engines),
             Return(stmt) => stmt.replace_decls(decl_mapping, engines),
+            TryBlock(block) => block.replace_decls(decl_mapping, engines),
+            TryBreak(stmt) => stmt.replace_decls(decl_mapping, engines),
         }
     }
 }
@@ -1082,6 +1100,8 @@ impl UpdateConstantExpression for TyExpressionVariant {
                 reassignment.update_constant_expression(engines, implementing_type)
             }
             Return(stmt) => stmt.update_constant_expression(engines, implementing_type),
+            TryBlock(block) => block.update_constant_expression(engines, implementing_type),
+            TryBreak(stmt) => stmt.update_constant_expression(engines, implementing_type),
         }
     }
 }
@@ -1243,6 +1263,10 @@ impl DebugWithEngines for TyExpressionVariant {
             TyExpressionVariant::Return(exp) => {
                 format!("return {:?}", engines.help_out(&**exp))
             }
+            TyExpressionVariant::TryBlock(_) => "try block entry".into(),
+            TyExpressionVariant::TryBreak(exp) => {
+                format!("try break {:?}", engines.help_out(&**exp))
+            }
         };
         write!(f, "{s}")
     }
@@ -1354,9 +1378,17 @@ impl TyExpressionVariant {
             TyExpressionVariant::EnumTag { exp } => exp.gather_return_statements(),
             TyExpressionVariant::UnsafeDowncast { exp, .. } => exp.gather_return_statements(),
 
-            TyExpressionVariant::Return(exp) => {
-                vec![exp]
+            // The `return` expression itself is gathered by `TyExpression::gather_return_statements`,
+            // so only the returns nested within the returned value are left to gather here.
+            TyExpressionVariant::Return(exp) => exp.gather_return_statements(),
+            TyExpressionVariant::TryBlock(TyCodeBlock { contents, .. }) => {
+                let mut buf = vec![];
+                for node in contents {
+                    buf.append(&mut node.gather_return_statements())
+                }
+                buf
             }
+            TyExpressionVariant::TryBreak(exp) => exp.gather_return_statements(),
             // if it is impossible for an expression to contain a return _statement_ (not an
             // implicit return!), put it in the pattern below.
             TyExpressionVariant::Literal(_)
@@ -1370,4 +1402,90 @@ impl TyExpressionVariant {
             | TyExpressionVariant::Continue => vec![],
         }
     }
+
+    /// Returns the expressions directly nested within `self`.
+    /// Code blocks are looked through, yielding the expressions of their nodes, including the
+    /// initializers of variable declarations.
+    pub(crate) fn sub_expressions(&self) -> Vec<&TyExpression> {
+        match self {
+            TyExpressionVariant::FunctionApplication {
+                contract_call_params,
+                arguments,
+                selector,
+                ..
+            } => contract_call_params
+                .values()
+                .chain(arguments.iter().map(|(_name, expr)| expr))
+                .chain(
+                    selector
+                        .iter()
+                        .map(|contract_call_params| &*contract_call_params.contract_address),
+                )
+                .collect(),
+            TyExpressionVariant::LazyOperator { lhs, rhs, .. } => vec![lhs, rhs],
+            TyExpressionVariant::Tuple { fields } => fields.iter().collect(),
+            TyExpressionVariant::Array { contents, .. } => contents.iter().collect(),
+            TyExpressionVariant::ArrayIndex { prefix, index } => vec![prefix, index],
+            TyExpressionVariant::StructExpression { fields, .. } => {
+                fields.iter().map(|field| &field.value).collect()
+            }
+            TyExpressionVariant::CodeBlock(code_block) => code_block.expressions(),
+            TyExpressionVariant::MatchExp { desugared, .. } => vec![desugared],
+            TyExpressionVariant::IfExp {
+                condition,
+                then,
+                r#else,
+            } => {
+                let mut buf: Vec<&TyExpression> = vec![condition, then];
+                if let Some(r#else) = r#else {
+                    buf.push(r#else);
+                }
+                buf
+            }
+            TyExpressionVariant::AsmExpression { registers, .. } => registers
+                .iter()
+                .filter_map(|register| register.initializer.as_ref())
+                .collect(),
+            TyExpressionVariant::StructFieldAccess { prefix, .. } => vec![prefix],
+            TyExpressionVariant::TupleElemAccess { prefix, .. } => vec![prefix],
+            TyExpressionVariant::EnumInstantiation { contents, .. } => {
+                contents.iter().map(|expr| &**expr).collect()
+            }
+            TyExpressionVariant::AbiCast { address, .. } => vec![address],
+            TyExpressionVariant::IntrinsicFunction(intrinsic_function_kind) => {
+                intrinsic_function_kind.arguments.iter().collect()
+            }
+            TyExpressionVariant::EnumTag { exp } => vec![exp],
+            TyExpressionVariant::UnsafeDowncast { exp, .. } => vec![exp],
+            TyExpressionVariant::WhileLoop { condition, body } => {
+                let mut buf: Vec<&TyExpression> = vec![condition];
+                buf.append(&mut body.expressions());
+                buf
+            }
+            TyExpressionVariant::Reassignment(reassignment) => {
+                let mut buf: Vec<&TyExpression> = reassignment
+                    .lhs_indices
+                    .iter()
+                    .filter_map(|projection| match projection {
+                        ProjectionKind::ArrayIndex { index, .. } => Some(&**index),
+                        ProjectionKind::StructField { .. } | ProjectionKind::TupleField { .. } => {
+                            None
+                        }
+                    })
+                    .collect();
+                buf.push(&reassignment.rhs);
+                buf
+            }
+            TyExpressionVariant::Return(exp) | TyExpressionVariant::TryBreak(exp) => vec![exp],
+            TyExpressionVariant::TryBlock(code_block) => code_block.expressions(),
+            TyExpressionVariant::Literal(_)
+            | TyExpressionVariant::ConstantExpression { .. }
+            | TyExpressionVariant::VariableExpression { .. }
+            | TyExpressionVariant::FunctionParameter
+            | TyExpressionVariant::StorageAccess(_)
+            | TyExpressionVariant::AbiName(_)
+            | TyExpressionVariant::Break
+            | TyExpressionVariant::Continue => vec![],
+        }
+    }
 }
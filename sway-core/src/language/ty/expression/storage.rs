@@ -1,4 +1,7 @@
-use std::hash::{Hash, Hasher};
+use std::{
+    collections::BTreeSet,
+    hash::{Hash, Hasher},
+};
 
 use sway_types::{state::StateIndex, Ident, Span, Spanned};
 
@@ -48,6 +51,20 @@ impl TyStorageAccess {
     pub fn storage_field_name(&self) -> Ident {
         self.fields[0].name.clone()
     }
+
+    /// Returns the names of the accessed field and of its subfields, e.g. `[foo, bar]` for
+    /// `storage.foo.bar`.
+    pub fn field_path(&self) -> Vec<Ident> {
+        self.fields.iter().map(|field| field.name.clone()).collect()
+    }
+}
+
+/// The storage fields read and written by a function, each given by its
+/// [TyStorageAccess::field_path].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageAccessSet {
+    pub reads: BTreeSet<Vec<Ident>>,
+    pub writes: BTreeSet<Vec<Ident>>,
 }
 
 /// Describes a single subfield access in the sequence when accessing a subfield within storage.
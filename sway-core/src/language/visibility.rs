@@ -2,6 +2,9 @@
 pub enum Visibility {
     Private,
     Public,
+    /// Declared `pub(package)`: visible within the package, but not to the packages depending
+    /// on it.
+    Package,
 }
 
 impl Visibility {
@@ -9,6 +12,16 @@ impl Visibility {
         matches!(self, &Visibility::Public)
     }
     pub fn is_private(&self) -> bool {
-        !self.is_public()
+        matches!(self, &Visibility::Private)
+    }
+    /// Whether a symbol with this visibility can be accessed from outside of the module
+    /// declaring it, where `within_package` tells whether the access is made from the package
+    /// declaring the symbol.
+    pub fn is_accessible(&self, within_package: bool) -> bool {
+        match self {
+            Visibility::Private => false,
+            Visibility::Public => true,
+            Visibility::Package => within_package,
+        }
     }
 }
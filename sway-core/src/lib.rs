@@ -26,7 +26,7 @@ use crate::source_map::SourceMap;
 pub use asm_generation::from_ir::compile_ir_to_asm;
 use asm_generation::FinalizedAsm;
 pub use asm_generation::{CompiledBytecode, FinalizedEntry};
-pub use build_config::{BuildConfig, BuildTarget};
+pub use build_config::{BuildConfig, BuildTarget, LintConfig};
 use control_flow_analysis::ControlFlowGraph;
 use metadata::MetadataManager;
 use std::collections::HashMap;
@@ -357,6 +357,8 @@ pub fn parsed_to_ast(
         parse_program,
         initial_namespace,
         package_name,
+        build_config.map_or(LintConfig::default(), |config| config.lint_config),
+        build_config.and_then(|config| config.fn_name_rewriter),
     );
 
     let mut typed_program = match typed_program_opt {
@@ -64,7 +64,7 @@ pub(crate) fn gather_from_exp_inner(
             // gather_from_exp(ctx.by_ref(), handler, index)?;
         }
         ty::TyExpressionVariant::StructExpression { .. } => todo!(),
-        ty::TyExpressionVariant::CodeBlock(block) => {
+        ty::TyExpressionVariant::CodeBlock(block) | ty::TyExpressionVariant::TryBlock(block) => {
             gather_from_code_block(ctx, handler, block)?;
         }
         ty::TyExpressionVariant::IfExp { .. } => todo!(),
@@ -85,7 +85,7 @@ pub(crate) fn gather_from_exp_inner(
         ty::TyExpressionVariant::UnsafeDowncast { .. } => todo!(),
         ty::TyExpressionVariant::WhileLoop { .. } => todo!(),
         ty::TyExpressionVariant::Reassignment(_) => todo!(),
-        ty::TyExpressionVariant::Return(exp) => {
+        ty::TyExpressionVariant::Return(exp) | ty::TyExpressionVariant::TryBreak(exp) => {
             gather_from_exp(ctx.by_ref(), handler, exp)?;
         }
         ty::TyExpressionVariant::Literal(_) => {}
@@ -147,8 +147,9 @@ impl ty::TyAbiDecl {
                 ty::TyFunctionDecl::type_check(handler, ctx.by_ref(), method.clone(), false, false)
                     .unwrap_or_else(|_| ty::TyFunctionDecl::error(method.clone()));
             error_on_shadowing_superabi_method(&method.name, &mut ctx);
+            // `ref` parameters are already reported when type checking the method.
             for param in &method.parameters {
-                if param.is_reference || param.is_mutable {
+                if param.is_mutable && !param.is_reference {
                     handler.emit_err(CompileError::RefMutableNotAllowedInContractAbi {
                         param_name: param.name.clone(),
                         span: param.name.span(),
@@ -53,7 +53,8 @@ impl ty::TyConstantDecl {
 
         let value = match value {
             Some(value) => {
-                let result = ty::TyExpression::type_check(handler, ctx.by_ref(), value);
+                let result =
+                    ty::TyExpression::type_check(handler, ctx.by_ref().with_const_context(), value);
 
                 if !is_screaming_snake_case(name.as_str()) {
                     handler.emit_warn(CompileWarning {
@@ -1,21 +1,54 @@
 mod function_parameter;
 
+use std::collections::{HashMap, HashSet};
+
 pub use function_parameter::*;
 use sway_error::{
+    convert_parse_tree_error::ConvertParseTreeError,
     error::CompileError,
     handler::{ErrorEmitted, Handler},
     warning::{CompileWarning, Warning},
 };
 
 use crate::{
-    language::{parsed::*, ty, Visibility},
-    semantic_analysis::*,
+    engine_threading::{Engines, PartialEqWithEngines},
+    language::{parsed::*, ty, Inline, Purity, Visibility},
+    semantic_analysis::{namespace::GlobImport, *},
+    transform,
     type_system::*,
 };
-use sway_types::{style::is_snake_case, Spanned};
+use sway_ast::Intrinsic;
+use sway_types::{
+    constants::{
+        ALLOW_NON_SNAKE_CASE_NAME, INLINE_ALWAYS_NAME, INLINE_NEVER_NAME, REENTRANCY_GUARD_NAME,
+    },
+    style::{is_snake_case, to_snake_case},
+    Ident, Span, Spanned,
+};
 
 impl ty::TyFunctionDecl {
     pub fn type_check(
+        handler: &Handler,
+        ctx: TypeCheckContext,
+        fn_decl: FunctionDeclaration,
+        is_method: bool,
+        is_in_impl_self: bool,
+    ) -> Result<Self, ErrorEmitted> {
+        // The warnings of the lints allowed by the `allow` attributes of the function, including
+        // the ones of its nested functions, are dropped before they reach `handler`.
+        let allowed_lints = allowed_lints(&fn_decl.attributes);
+        let fn_handler = Handler::default();
+        let res = Self::type_check_inner(&fn_handler, ctx, fn_decl, is_method, is_in_impl_self);
+        let (errors, warnings) = fn_handler.consume();
+        let warnings = warnings
+            .into_iter()
+            .filter(|warning| !is_lint_allowed(&allowed_lints, &warning.warning_content))
+            .collect();
+        handler.append(Handler::from_parts(errors, warnings));
+        res
+    }
+
+    fn type_check_inner(
         handler: &Handler,
         mut ctx: TypeCheckContext,
         fn_decl: FunctionDeclaration,
@@ -33,59 +66,223 @@ impl ty::TyFunctionDecl {
             visibility,
             purity,
             where_clause,
+            is_const,
         } = fn_decl;
 
         let type_engine = ctx.engines.te();
         let engines = ctx.engines();
 
-        // If functions aren't allowed in this location, return an error.
-        if ctx.functions_disallowed() {
-            return Err(handler.emit_err(CompileError::Unimplemented(
-                "Nested function definitions are not allowed at this time.",
-                span,
-            )));
+        // The rewritten name only replaces `name` in the selector, so that the function is still
+        // declared, called and matched against the trait interfaces by its source name.
+        let symbol_name = ctx.rewrite_fn_name(&name);
+
+        // Warn against non-snake case function names, or reject them in the strict style mode.
+        let checked_name = symbol_name.as_ref().unwrap_or(&name);
+        if !is_snake_case(checked_name.as_str()) {
+            if ctx.lint_config().style_as_error {
+                handler.emit_err(CompileError::NonSnakeCaseFunctionName {
+                    name: checked_name.clone(),
+                });
+            } else {
+                handler.emit_warn(CompileWarning {
+                    span: checked_name.span(),
+                    warning_content: Warning::NonSnakeCaseFunctionName {
+                        name: checked_name.clone(),
+                        suggestion: to_snake_case(checked_name.as_str()),
+                    },
+                })
+            }
         }
 
-        // Warn against non-snake case function names.
-        if !is_snake_case(name.as_str()) {
-            handler.emit_warn(CompileWarning {
-                span: name.span(),
-                warning_content: Warning::NonSnakeCaseFunctionName { name: name.clone() },
-            })
+        // A function named after an intrinsic would be confused with it at the call sites.
+        if Intrinsic::try_from_str(name.as_str()).is_some() {
+            handler.emit_err(CompileError::FunctionNameShadowsIntrinsic { name: name.clone() });
         }
 
+        check_conflicting_attributes(handler, &attributes);
+
+        // An invalid `inline` attribute is reported, but doesn't prevent the rest of the
+        // function from being type checked.
+        let inline = type_check_inline_attribute(handler, &attributes).unwrap_or(None);
+        let gas_hint = type_check_gas_hint_attribute(handler, &attributes).unwrap_or(None);
+
+        // Same goes for misplaced `payable` and `reentrancy` attributes.
+        let payable = type_check_payable_attribute(handler, &ctx, &attributes).unwrap_or(false);
+        let reentrancy_guard =
+            type_check_reentrancy_attribute(handler, &ctx, &attributes).unwrap_or(false);
+
         // create a namespace for the function
+        //
+        // A nested function behaves like an item: it may use anything declared around the
+        // enclosing function, but not the enclosing function's parameters, variables and type
+        // parameters.
         let mut fn_namespace = ctx.namespace.clone();
+        if ctx.in_function_body() {
+            fn_namespace.hide_enclosing_fn_locals();
+        }
         let mut ctx = ctx
             .by_ref()
             .scoped(&mut fn_namespace)
             .with_purity(purity)
+            .with_payable(payable)
             .with_const_shadowing_mode(ConstShadowingMode::Sequential)
-            .disallow_functions();
+            .enter_function_body();
 
         // Type check the type parameters. This will also insert them into the
         // current namespace.
         let new_type_parameters =
             TypeParameter::type_check_type_params(handler, ctx.by_ref(), type_parameters)?;
 
+        if let Some(limit) = ctx.lint_config().max_type_parameters {
+            let count = new_type_parameters.len();
+            if count > limit {
+                handler.emit_warn(CompileWarning {
+                    span: name.span(),
+                    warning_content: Warning::TooManyTypeParameters { count, limit },
+                });
+            }
+        }
+
+        // The bounds of the where clause were merged into the type parameters and checked there.
+        // Keep the checked ones, so that the where clause follows the type parameters through
+        // monomorphization.
+        let where_clause = resolve_where_clause(where_clause, &new_type_parameters);
+
         // type check the function parameters, which will also insert them into the namespace
         let mut new_parameters = vec![];
         let mut error_emitted = None;
-        for parameter in parameters.into_iter() {
-            new_parameters.push(
-                match ty::TyFunctionParameter::type_check(handler, ctx.by_ref(), parameter) {
-                    Ok(val) => val,
-                    Err(err) => {
-                        error_emitted = Some(err);
-                        continue;
-                    }
-                },
-            );
+        let mut parameter_names = HashMap::new();
+        let mut default_value_seen = false;
+        for (index, parameter) in parameters.into_iter().enumerate() {
+            // Omitted arguments are filled in from the end of the parameter list, so once a
+            // parameter has a default value all of the following ones need one too.
+            if parameter.default_value.is_some() {
+                default_value_seen = true;
+            } else if default_value_seen {
+                error_emitted = Some(handler.emit_err(
+                    CompileError::RequiredParameterAfterDefaultParameter {
+                        name: parameter.name.clone(),
+                        span: parameter.name.span(),
+                    },
+                ));
+            }
+            // A repeated parameter is skipped, so that it doesn't shadow the first one in the
+            // namespace.
+            if let Err(err) = ty::TyFunctionParameter::check_duplicate_name(
+                handler,
+                &mut parameter_names,
+                &parameter,
+            ) {
+                error_emitted = Some(err);
+                continue;
+            }
+            // A parameter named after a type parameter shadows it in the body.
+            if let Some(type_parameter) = new_type_parameters
+                .iter()
+                .find(|type_param| type_param.name_ident == parameter.name)
+            {
+                handler.emit_warn(CompileWarning {
+                    span: parameter.name.span(),
+                    warning_content: Warning::ParameterShadowsTypeParameter {
+                        name: parameter.name.clone(),
+                        type_parameter_span: type_parameter.name_ident.span(),
+                    },
+                });
+            }
+            // The errors of a parameter are attributed to its position and name, so that they
+            // can be traced back to it.
+            let name = parameter.name.clone();
+            let parameter_handler = Handler::default();
+            let res =
+                ty::TyFunctionParameter::type_check(&parameter_handler, ctx.by_ref(), parameter);
+            let (errors, warnings) = parameter_handler.consume();
+            for warning in warnings {
+                handler.emit_warn(warning);
+            }
+            for error in errors {
+                handler.emit_err(CompileError::InvalidFunctionParameter {
+                    index,
+                    name: name.clone(),
+                    source: Box::new(error),
+                });
+            }
+            match res {
+                Ok(val) => new_parameters.push(val),
+                Err(err) => error_emitted = Some(err),
+            }
         }
         if let Some(err) = error_emitted {
             return Err(err);
         }
 
+        // The arguments of a contract call are passed by value, so a `ref` parameter could
+        // never refer to the caller's value.
+        if matches!(ctx.abi_mode(), AbiMode::ImplAbiFn(..)) {
+            for param in new_parameters.iter().filter(|param| param.is_reference) {
+                handler.emit_err(CompileError::RefParameterInAbiMethod {
+                    param_name: param.name.clone(),
+                    span: param.mutability_span.clone(),
+                });
+            }
+        }
+
+        type_check_selector_attribute(handler, &ctx, &attributes, &new_parameters);
+        type_check_abi_name_attribute(handler, &ctx, &attributes);
+        let is_fallback =
+            type_check_fallback_attribute(handler, &ctx, &name, &attributes, &new_parameters)
+                .unwrap_or(false);
+
+        if let Some(limit) = ctx.lint_config().max_function_parameters {
+            let count = new_parameters
+                .iter()
+                .filter(|param| !param.is_self())
+                .count();
+            if count > limit {
+                handler.emit_warn(CompileWarning {
+                    span: name.span(),
+                    warning_content: Warning::TooManyParameters { count, limit },
+                });
+            }
+        }
+
+        if let Some(limit) = ctx.lint_config().max_bool_parameters {
+            // Only the parameters which are `bool`s themselves count, not the ones holding some.
+            let count = new_parameters
+                .iter()
+                .filter(|param| {
+                    matches!(
+                        type_engine.get(param.type_argument.type_id),
+                        TypeInfo::Boolean
+                    )
+                })
+                .count();
+            if count > limit {
+                handler.emit_warn(CompileWarning {
+                    span: name.span(),
+                    warning_content: Warning::MultipleBooleanParameters { count },
+                });
+            }
+        }
+
+        // An `impl Trait` return type stands for the type of the returned values, which is
+        // inferred from the body and then checked against the bounds. That type has to be known
+        // to encode the returned values of an ABI method.
+        let opaque_return_bounds = match type_engine.get(return_type.type_id) {
+            TypeInfo::Placeholder(type_param) if !type_param.trait_constraints.is_empty() => {
+                Some(type_param.trait_constraints)
+            }
+            _ => None,
+        };
+        if let Some(bounds) = &opaque_return_bounds {
+            if matches!(ctx.abi_mode(), AbiMode::ImplAbiFn(..)) {
+                return Err(handler.emit_err(CompileError::OpaqueReturnTypeInAbi {
+                    span: return_type.span.clone(),
+                }));
+            }
+            check_opaque_bounds_are_traits(handler, &ctx, bounds)?;
+            return_type.type_id = type_engine.insert(engines, TypeInfo::Unknown);
+        }
+
         // type check the return type
         return_type.type_id = ctx
             .resolve_type_with_self(
@@ -97,23 +294,172 @@ impl ty::TyFunctionDecl {
             )
             .unwrap_or_else(|_| type_engine.insert(engines, TypeInfo::ErrorRecovery));
 
+        if let Some(limit) = ctx.lint_config().max_generic_depth {
+            warn_deep_generic_nesting(handler, engines, limit, &new_parameters, &return_type);
+        }
+
+        // The selector of a contract call is computed from the concrete types of its signature.
+        if matches!(ctx.abi_mode(), AbiMode::ImplAbiFn(..)) {
+            check_abi_method_is_monomorphic(
+                handler,
+                engines,
+                &name,
+                &new_type_parameters,
+                &new_parameters,
+                &return_type,
+            )?;
+        }
+
+        if attributes.contains_key(&transform::AttributeKind::Test) {
+            check_test_signature(
+                handler,
+                &ctx,
+                &name,
+                is_method,
+                &new_parameters,
+                &return_type,
+            );
+        }
+
+        // An unresolved return type is already reported. Checking the body and the returned
+        // values against it would only produce spurious mismatches, so the body is checked on
+        // its own instead.
+        let return_type_is_unresolved = matches!(
+            type_engine.get(return_type.type_id),
+            TypeInfo::ErrorRecovery
+        );
+        // A return type of `_` is inferred from the body instead.
+        let return_type_is_inferred =
+            matches!(type_engine.get(return_type.type_id), TypeInfo::Unknown);
+        let body_type_annotation = if return_type_is_unresolved || return_type_is_inferred {
+            type_engine.insert(engines, TypeInfo::Unknown)
+        } else {
+            return_type.type_id
+        };
+
+        // A function which returns `()` but ends with an expression most likely misses the
+        // semicolon which would turn that expression into a statement.
+        let ends_with_expression = matches!(
+            body.contents.last(),
+            Some(AstNode {
+                content: AstNodeContent::ImplicitReturnExpression(_),
+                ..
+            })
+        );
+        let help_text = if ends_with_expression && type_engine.get(return_type.type_id).is_unit() {
+            "Function body's return type does not match up with its return type annotation. If \
+             the last expression is meant to be a statement, end it with a semicolon."
+        } else {
+            "Function body's return type does not match up with its return type annotation."
+        };
+
         // type check the function body
         //
         // If there are no implicit block returns, then we do not want to type check them, so we
         // stifle the errors. If there _are_ implicit block returns, we want to type_check them.
-        let (body, _implicit_block_return) = {
+        //
+        // The body is checked with its own handler so that lints over the typed body can be
+        // skipped when parts of it failed to type check and were dropped.
+        let body_handler = Handler::default();
+        let (body, implicit_block_return) = {
             let ctx = ctx
                 .by_ref()
                 .with_purity(purity)
-                .with_help_text("Function body's return type does not match up with its return type annotation.")
-                .with_type_annotation(return_type.type_id);
-            ty::TyCodeBlock::type_check(handler, ctx, body).unwrap_or_else(|_| {
+                .with_help_text(help_text)
+                .with_type_annotation(body_type_annotation)
+                .with_function_return_type(return_type.type_id);
+            ty::TyCodeBlock::type_check(&body_handler, ctx, body).unwrap_or_else(|_| {
                 (
                     ty::TyCodeBlock { contents: vec![] },
                     type_engine.insert(engines, TypeInfo::ErrorRecovery),
                 )
             })
         };
+        let body_has_errors = body_handler.has_error();
+        handler.append(body_handler);
+
+        if return_type_is_inferred {
+            return_type.type_id = inferred_return_type(&body, implicit_block_return);
+        }
+
+        if purity == Purity::Pure {
+            check_pure_function_calls(handler, &ctx, &name, &body);
+        }
+
+        if is_const && !body_has_errors {
+            check_const_fn_body(handler, &ctx, &name, &body);
+        }
+
+        if !body_has_errors {
+            // The signature of a trait method is dictated by the trait.
+            if !is_method || is_in_impl_self {
+                warn_passed_through_parameters(handler, &new_parameters, &body);
+                if is_method && ctx.lint_config().unused_self {
+                    warn_unused_self(handler, &name, &new_parameters, &body);
+                }
+                warn_unused_type_parameters(
+                    handler,
+                    engines,
+                    &new_type_parameters,
+                    &where_clause,
+                    &new_parameters,
+                    &return_type,
+                    &body,
+                );
+            }
+            warn_unreachable_code(handler, &body);
+            if ctx.lint_config().redundant_tail_return {
+                warn_redundant_tail_return(handler, &body);
+            }
+            if is_method {
+                check_direct_recursion(handler, &ctx, &name, inline, &body);
+            }
+            check_uninhabited_return_type(
+                handler,
+                &ctx,
+                &name,
+                &new_parameters,
+                &return_type,
+                &body,
+            );
+        }
+
+        let complexity = function_complexity(&body);
+        if let Some(limit) = ctx.lint_config().max_complexity {
+            if complexity > limit {
+                handler.emit_warn(CompileWarning {
+                    span: name.span(),
+                    warning_content: Warning::HighComplexity {
+                        value: complexity,
+                        limit,
+                    },
+                });
+            }
+        }
+
+        // The storage attributes of a trait method have to match the ones of the trait, so only
+        // free functions and inherent methods can drop the storage attributes they don't need.
+        // Scripts and predicates can't access storage at all, which is reported elsewhere.
+        let storage_is_available = !matches!(ctx.kind(), TreeType::Script | TreeType::Predicate);
+        if !body_has_errors && (!is_method || is_in_impl_self) && storage_is_available {
+            let inferred_purity = infer_purity(&ctx, purity, &body);
+            if inferred_purity != purity {
+                let span = attributes
+                    .get(&transform::AttributeKind::Storage)
+                    .and_then(|attrs| attrs.first())
+                    .map(|attr| attr.span.clone())
+                    .unwrap_or_else(|| name.span());
+                handler.emit_warn(CompileWarning {
+                    span,
+                    warning_content: Warning::PurityOverAnnotated {
+                        declared_attrib: purity.to_attribute_syntax(),
+                        needed_attrib: inferred_purity.to_attribute_syntax(),
+                    },
+                });
+            }
+        }
+
+        let storage_access = (purity != Purity::Pure).then(|| collect_storage_access(&ctx, &body));
 
         // gather the return statements
         let return_statements: Vec<&ty::TyExpression> = body
@@ -122,13 +468,42 @@ impl ty::TyFunctionDecl {
             .flat_map(|node| node.gather_return_statements())
             .collect();
 
-        unify_return_statements(
-            handler,
-            ctx.by_ref(),
-            &return_statements,
-            return_type.type_id,
-        )?;
+        // A function diverges if it neither returns nor reaches the end of its body.
+        let diverges = !body_has_errors && function_diverges(engines, &return_statements, &body);
+
+        if !return_type_is_unresolved {
+            unify_return_statements(
+                handler,
+                ctx.by_ref(),
+                &return_statements,
+                return_type.type_id,
+            )?;
+        }
+
+        if let Some(bounds) = opaque_return_bounds {
+            if !body_has_errors {
+                ctx.namespace
+                    .implemented_traits
+                    .check_if_trait_constraints_are_satisfied_for_type(
+                        handler,
+                        return_type.type_id,
+                        &bounds,
+                        &return_type.span,
+                        engines,
+                    )?;
+            }
+        }
 
+        // Only inherent impls have constructors, the methods of a trait are dictated by the trait.
+        let is_constructor = is_method
+            && is_in_impl_self
+            && !new_parameters.iter().any(|param| param.is_self())
+            && type_engine
+                .get(return_type.type_id)
+                .eq(&type_engine.get(ctx.self_type()), engines);
+
+        // The methods of a trait are as visible as the trait itself, the parser rejects any
+        // visibility qualifier on them, `pub(package)` included.
         let (visibility, is_contract_call) = if is_method {
             if is_in_impl_self {
                 (visibility, false)
@@ -139,6 +514,24 @@ impl ty::TyFunctionDecl {
             (visibility, matches!(ctx.abi_mode(), AbiMode::ImplAbiFn(..)))
         };
 
+        if is_contract_call {
+            check_unit_parameters(handler, engines, &name, &new_parameters);
+        }
+
+        // The methods of a trait are dictated by the trait, and the methods of a private type are
+        // only as visible as the type itself.
+        let is_exposed = !visibility.is_private()
+            && (!is_method
+                || (is_in_impl_self && private_type(engines, ctx.self_type()).is_none()));
+        if is_exposed {
+            warn_leaked_private_types(handler, engines, &new_parameters, &return_type);
+        }
+
+        // Only free functions can be confused with the methods of the imported traits.
+        if !is_method {
+            warn_function_shadows_import(handler, &ctx, &name);
+        }
+
         return_type.type_id.check_type_parameter_bounds(
             handler,
             &ctx,
@@ -148,6 +541,7 @@ impl ty::TyFunctionDecl {
 
         let function_decl = ty::TyFunctionDecl {
             name,
+            symbol_name,
             body,
             parameters: new_parameters,
             implementing_type: None,
@@ -158,15 +552,522 @@ impl ty::TyFunctionDecl {
             visibility,
             is_contract_call,
             purity,
+            storage_access,
+            inline,
+            diverges,
+            is_constructor,
+            reentrancy_guard,
+            is_fallback,
             where_clause,
+            complexity,
+            gas_hint,
+            is_const,
         };
 
         Ok(function_decl)
     }
 }
 
+/// Replaces every bound of `where_clause` with its type checked counterpart from the
+/// constraints of the matching type parameter. All the `+`-separated bounds of a type are kept.
+fn resolve_where_clause(
+    where_clause: Vec<(Ident, Vec<TraitConstraint>)>,
+    type_parameters: &[TypeParameter],
+) -> Vec<(Ident, Vec<TraitConstraint>)> {
+    where_clause
+        .into_iter()
+        .map(|(ty_name, bounds)| {
+            let type_parameter = type_parameters
+                .iter()
+                .find(|type_param| type_param.name_ident == ty_name);
+            let bounds = bounds
+                .into_iter()
+                .map(|bound| {
+                    type_parameter
+                        .and_then(|type_param| {
+                            type_param
+                                .trait_constraints
+                                .iter()
+                                .find(|checked| checked.trait_name == bound.trait_name)
+                        })
+                        .cloned()
+                        .unwrap_or(bound)
+                })
+                .collect();
+            (ty_name, bounds)
+        })
+        .collect()
+}
+
+/// Reports an ABI method which is generic, either through its own type parameters or through
+/// a type parameter in the type of a parameter or of the return value.
+fn check_abi_method_is_monomorphic(
+    handler: &Handler,
+    engines: &Engines,
+    name: &Ident,
+    type_parameters: &[TypeParameter],
+    parameters: &[ty::TyFunctionParameter],
+    return_type: &TypeArgument,
+) -> Result<(), ErrorEmitted> {
+    let is_generic = |type_id: TypeId| {
+        !type_id
+            .extract_any_including_self(
+                engines,
+                &|type_info| matches!(type_info, TypeInfo::UnknownGeneric { .. }),
+                vec![],
+            )
+            .is_empty()
+    };
+    let span = if let Some(type_param) = type_parameters.first() {
+        Some(type_param.name_ident.span())
+    } else if let Some(param) = parameters
+        .iter()
+        .find(|param| is_generic(param.type_argument.type_id))
+    {
+        Some(param.type_argument.span.clone())
+    } else if is_generic(return_type.type_id) {
+        Some(return_type.span.clone())
+    } else {
+        None
+    };
+    match span {
+        Some(span) => Err(handler.emit_err(CompileError::GenericAbiMethod {
+            name: name.clone(),
+            span,
+        })),
+        None => Ok(()),
+    }
+}
+
+/// Reports the bounds of an `impl Trait` type which don't name a trait in scope. Unlike the
+/// bounds of type parameters, they aren't resolved before the type is checked against them.
+fn check_opaque_bounds_are_traits(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    bounds: &[TraitConstraint],
+) -> Result<(), ErrorEmitted> {
+    let mut error_emitted = None;
+    for bound in bounds {
+        let resolved = ctx
+            .namespace
+            .resolve_call_path(&Handler::default(), &bound.trait_name)
+            .ok();
+        if !matches!(resolved, Some(ty::TyDecl::TraitDecl(_))) {
+            error_emitted = Some(handler.emit_err(CompileError::UnknownTraitInImplTrait {
+                name: bound.trait_name.suffix.clone(),
+                span: bound.trait_name.span(),
+            }));
+        }
+    }
+    match error_emitted {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Reports a test function which can't be run by the test harness. A test is a free function
+/// which takes no parameters and returns the unit type.
+fn check_test_signature(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    name: &Ident,
+    is_method: bool,
+    parameters: &[ty::TyFunctionParameter],
+    return_type: &TypeArgument,
+) {
+    let span = if is_method || matches!(ctx.abi_mode(), AbiMode::ImplAbiFn(..)) {
+        Some(name.span())
+    } else if let Some(param) = parameters.first() {
+        Some(param.name.span())
+    } else if !ctx.engines.te().get(return_type.type_id).is_unit() {
+        Some(return_type.span.clone())
+    } else {
+        None
+    };
+    if let Some(span) = span {
+        handler.emit_err(CompileError::InvalidTestSignature {
+            name: name.clone(),
+            span,
+        });
+    }
+}
+
+/// Warns if a trait imported by name has a method called like the free function `name`.
+///
+/// Glob imports, like the one of the prelude, are not considered. They bring common method names
+/// such as `eq` into scope, which free functions legitimately reuse.
+fn warn_function_shadows_import(handler: &Handler, ctx: &TypeCheckContext, name: &Ident) {
+    let decl_engine = ctx.engines().de();
+    for (_, glob_import, decl, _) in ctx.namespace.use_synonyms.values() {
+        let decl_id = match (glob_import, decl) {
+            (GlobImport::No, ty::TyDecl::TraitDecl(ty::TraitDecl { decl_id, .. })) => decl_id,
+            _ => continue,
+        };
+        let trait_decl = decl_engine.get_trait(decl_id);
+        let interface_methods = trait_decl
+            .interface_surface
+            .iter()
+            .filter_map(|item| match item {
+                ty::TyTraitInterfaceItem::TraitFn(decl_ref) => Some(decl_ref.name()),
+                ty::TyTraitInterfaceItem::Constant(_) => None,
+            });
+        let provided_methods = trait_decl.items.iter().filter_map(|item| match item {
+            ty::TyTraitItem::Fn(decl_ref) => Some(decl_ref.name()),
+            ty::TyTraitItem::Constant(_) => None,
+        });
+        if let Some(method) = interface_methods
+            .chain(provided_methods)
+            .find(|method| *method == name)
+        {
+            handler.emit_warn(CompileWarning {
+                span: name.span(),
+                warning_content: Warning::FunctionShadowsImport {
+                    name: name.clone(),
+                    trait_name: trait_decl.name.clone(),
+                    method: method.clone(),
+                },
+            });
+        }
+    }
+}
+
+/// The pairs of attributes which make no sense together on a function. The `inline` attribute
+/// is identified along with its argument.
+const CONFLICTING_ATTRIBUTES: &[(&str, &str)] = &[
+    ("inline(always)", "inline(never)"),
+    ("test", "payable"),
+    ("fallback", "selector"),
+];
+
+/// Reports each attribute of a function which conflicts with one of the attributes declared
+/// before it, according to [CONFLICTING_ATTRIBUTES].
+fn check_conflicting_attributes(handler: &Handler, attributes: &transform::AttributesMap) {
+    let mut seen: Vec<String> = vec![];
+    for (kind, attr) in attributes.in_order() {
+        let named_attrs = match kind {
+            transform::AttributeKind::Inline => attr
+                .args
+                .iter()
+                .map(|arg| (format!("{}({})", attr.name, arg.name), arg.span.clone()))
+                .collect(),
+            _ => vec![(attr.name.as_str().to_owned(), attr.name.span())],
+        };
+        for (name, span) in named_attrs {
+            let conflicting = seen.iter().find(|seen_name| {
+                CONFLICTING_ATTRIBUTES.iter().any(|&(a, b)| {
+                    (a == seen_name.as_str() && b == name) || (b == seen_name.as_str() && a == name)
+                })
+            });
+            if let Some(conflicting) = conflicting {
+                handler.emit_err(CompileError::ConflictingAttributes {
+                    a: conflicting.clone(),
+                    b: name.clone(),
+                    span,
+                });
+            }
+            seen.push(name);
+        }
+    }
+}
+
+/// Validates the `#[inline(..)]` attributes of a function and returns the requested inlining.
+///
+/// Only `always` and `never` are accepted as arguments. If there are several `inline`
+/// attributes, the last one wins.
+fn type_check_inline_attribute(
+    handler: &Handler,
+    attributes: &transform::AttributesMap,
+) -> Result<Option<Inline>, ErrorEmitted> {
+    let mut inline = None;
+    let mut error_emitted = None;
+    let inline_args = attributes
+        .get(&transform::AttributeKind::Inline)
+        .into_iter()
+        .flatten()
+        .flat_map(|attr| &attr.args);
+    for arg in inline_args {
+        match arg.name.as_str() {
+            INLINE_ALWAYS_NAME => inline = Some(Inline::Always),
+            INLINE_NEVER_NAME => inline = Some(Inline::Never),
+            _otherwise => {
+                let error = ConvertParseTreeError::InvalidAttributeArgument {
+                    attribute: "inline".to_owned(),
+                    span: arg.span.clone(),
+                };
+                error_emitted = Some(handler.emit_err(error.into()));
+            }
+        }
+    }
+    match error_emitted {
+        Some(err) => Err(err),
+        None => Ok(inline),
+    }
+}
+
+/// Validates the `#[gas_hint(..)]` attribute of a function and returns the expected gas cost.
+///
+/// The cost has to be a single positive integer, and the attribute can only be given once.
+fn type_check_gas_hint_attribute(
+    handler: &Handler,
+    attributes: &transform::AttributesMap,
+) -> Result<Option<u64>, ErrorEmitted> {
+    let attr = match attributes
+        .get(&transform::AttributeKind::GasHint)
+        .map(Vec::as_slice)
+    {
+        Some([attr]) => attr,
+        Some([_, attr, ..]) => {
+            return Err(handler.emit_err(CompileError::DuplicateAttribute {
+                attribute: "gas_hint".to_owned(),
+                span: attr.span.clone(),
+            }))
+        }
+        _ => return Ok(None),
+    };
+    let arg = match attr.args.as_slice() {
+        [arg] => arg,
+        args => {
+            return Err(handler.emit_err(CompileError::GasHintArgumentCount {
+                count: args.len(),
+                span: attr.span.clone(),
+            }))
+        }
+    };
+    match arg.name.as_str().replace('_', "").parse::<u64>() {
+        Ok(hint) if hint > 0 => Ok(Some(hint)),
+        _ => Err(handler.emit_err(CompileError::InvalidGasHint {
+            hint: arg.name.as_str().to_owned(),
+            span: arg.span.clone(),
+        })),
+    }
+}
+
+/// Returns the names of the lints allowed by the `#[allow(..)]` attributes of a function.
+///
+/// The lint names are validated along with the attributes of every item, when they are parsed.
+fn allowed_lints(attributes: &transform::AttributesMap) -> HashSet<String> {
+    attributes
+        .get(&transform::AttributeKind::Allow)
+        .into_iter()
+        .flatten()
+        .flat_map(|attr| &attr.args)
+        .map(|arg| arg.name.as_str().to_owned())
+        .collect()
+}
+
+/// Returns whether `warning` belongs to one of the `allowed_lints`.
+///
+/// Dead code is reported by the control flow analysis, which checks `dead_code` on its own.
+fn is_lint_allowed(allowed_lints: &HashSet<String>, warning: &Warning) -> bool {
+    let lint = match warning {
+        Warning::NonSnakeCaseFunctionName { .. } => ALLOW_NON_SNAKE_CASE_NAME,
+        _ => return false,
+    };
+    allowed_lints.contains(lint)
+}
+
+/// Validates the `#[payable]` attributes of a function and returns whether it is payable.
+///
+/// Coins can only be forwarded in contract calls, so the attribute is only allowed on the methods
+/// of an `abi` declaration and of its implementation for `Contract`.
+fn type_check_payable_attribute(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    attributes: &transform::AttributesMap,
+) -> Result<bool, ErrorEmitted> {
+    let payable_attributes = match attributes.get(&transform::AttributeKind::Payable) {
+        Some(payable_attributes) => payable_attributes,
+        None => return Ok(false),
+    };
+    if matches!(ctx.abi_mode(), AbiMode::ImplAbiFn(..)) {
+        return Ok(true);
+    }
+    let mut error_emitted = None;
+    for attr in payable_attributes {
+        error_emitted = Some(handler.emit_err(CompileError::InvalidAttributeOnItem {
+            attribute: "payable".to_owned(),
+            allowed_on: "ABI methods and their implementations".to_owned(),
+            span: attr.span.clone(),
+        }));
+    }
+    match error_emitted {
+        Some(err) => Err(err),
+        None => Ok(false),
+    }
+}
+
+/// Validates the `#[reentrancy(guard)]` attributes of a function and returns whether it is
+/// guarded.
+///
+/// Only a contract call can reenter the contract, so, like `#[payable]`, the attribute is only
+/// allowed on the methods of an `abi` declaration and of its implementation for `Contract`.
+fn type_check_reentrancy_attribute(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    attributes: &transform::AttributesMap,
+) -> Result<bool, ErrorEmitted> {
+    let reentrancy_attributes = match attributes.get(&transform::AttributeKind::Reentrancy) {
+        Some(reentrancy_attributes) => reentrancy_attributes,
+        None => return Ok(false),
+    };
+    if matches!(ctx.abi_mode(), AbiMode::ImplAbiFn(..)) {
+        return Ok(reentrancy_attributes
+            .iter()
+            .flat_map(|attr| &attr.args)
+            .any(|arg| arg.name.as_str() == REENTRANCY_GUARD_NAME));
+    }
+    let mut error_emitted = None;
+    for attr in reentrancy_attributes {
+        error_emitted = Some(handler.emit_err(CompileError::InvalidAttributeOnItem {
+            attribute: "reentrancy".to_owned(),
+            allowed_on: "ABI methods and their implementations".to_owned(),
+            span: attr.span.clone(),
+        }));
+    }
+    match error_emitted {
+        Some(err) => Err(err),
+        None => Ok(false),
+    }
+}
+
+/// Validates the `#[fallback]` attributes of a function and returns whether it is the fallback
+/// method of a contract.
+///
+/// The fallback method is called with the arguments of a call to any other method, so it can't
+/// declare parameters of its own. Whether a contract has more than one is checked along with
+/// its ABI implementation.
+fn type_check_fallback_attribute(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    name: &Ident,
+    attributes: &transform::AttributesMap,
+    parameters: &[ty::TyFunctionParameter],
+) -> Result<bool, ErrorEmitted> {
+    let fallback_attributes = match attributes.get(&transform::AttributeKind::Fallback) {
+        Some(fallback_attributes) => fallback_attributes,
+        None => return Ok(false),
+    };
+    if !matches!(ctx.abi_mode(), AbiMode::ImplAbiFn(..)) {
+        let mut error_emitted = None;
+        for attr in fallback_attributes {
+            error_emitted = Some(handler.emit_err(CompileError::InvalidAttributeOnItem {
+                attribute: "fallback".to_owned(),
+                allowed_on: "ABI methods and their implementations".to_owned(),
+                span: attr.span.clone(),
+            }));
+        }
+        return match error_emitted {
+            Some(err) => Err(err),
+            None => Ok(false),
+        };
+    }
+    if !parameters.is_empty() {
+        return Err(handler.emit_err(CompileError::FallbackWithParameters {
+            name: name.clone(),
+            span: name.span(),
+        }));
+    }
+    Ok(true)
+}
+
+/// Validates the `#[selector("..")]` attributes of an ABI method, which replace the name its
+/// selector is computed from.
+///
+/// The name has to be a valid selector name, and is expected to take as many parameters as the
+/// method.
+pub(crate) fn type_check_selector_attribute(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    attributes: &transform::AttributesMap,
+    parameters: &[ty::TyFunctionParameter],
+) {
+    let selector_attributes = match attributes.get(&transform::AttributeKind::Selector) {
+        Some(selector_attributes) => selector_attributes,
+        None => return,
+    };
+    if !matches!(ctx.abi_mode(), AbiMode::ImplAbiFn(..)) {
+        for attr in selector_attributes {
+            handler.emit_err(CompileError::InvalidAttributeOnItem {
+                attribute: "selector".to_owned(),
+                allowed_on: "ABI methods and their implementations".to_owned(),
+                span: attr.span.clone(),
+            });
+        }
+        return;
+    }
+    for arg in selector_attributes.iter().flat_map(|attr| &attr.args) {
+        // The name has to be given as a string, e.g. `#[selector("foo(u64)")]`.
+        let is_string = arg.name.as_str().starts_with('"');
+        let selector = arg.name.as_str().trim_matches('"');
+        match ty::TyFunctionDecl::parse_selector_name(selector) {
+            Ok((_, selector_params)) if is_string => {
+                if selector_params.len() != parameters.len() {
+                    handler.emit_warn(CompileWarning {
+                        span: arg.span.clone(),
+                        warning_content: Warning::SelectorArityMismatch {
+                            selector: selector.to_string(),
+                            selector_parameters: selector_params.len(),
+                            parameters: parameters.len(),
+                        },
+                    });
+                }
+            }
+            _ => {
+                handler.emit_err(CompileError::MalformedSelectorName {
+                    selector: selector.to_string(),
+                    span: arg.span.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// Validates the `#[abi_name("..")]` attributes of an ABI method, which replace the name in its
+/// selector. The name has to be a valid identifier.
+pub(crate) fn type_check_abi_name_attribute(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    attributes: &transform::AttributesMap,
+) {
+    let abi_name_attributes = match attributes.get(&transform::AttributeKind::AbiName) {
+        Some(abi_name_attributes) => abi_name_attributes,
+        None => return,
+    };
+    if !matches!(ctx.abi_mode(), AbiMode::ImplAbiFn(..)) {
+        for attr in abi_name_attributes {
+            handler.emit_err(CompileError::InvalidAttributeOnItem {
+                attribute: "abi_name".to_owned(),
+                allowed_on: "ABI methods and their implementations".to_owned(),
+                span: attr.span.clone(),
+            });
+        }
+        return;
+    }
+    for arg in abi_name_attributes.iter().flat_map(|attr| &attr.args) {
+        // The name has to be given as a string, e.g. `#[abi_name("foo")]`.
+        let is_string = arg.name.as_str().starts_with('"');
+        let name = arg.name.as_str().trim_matches('"');
+        let mut chars = name.chars();
+        let is_identifier = chars
+            .next()
+            .map_or(false, |c| c.is_alphabetic() || c == '_')
+            && chars.all(|c| c.is_alphanumeric() || c == '_');
+        if !is_string || !is_identifier {
+            handler.emit_err(CompileError::InvalidAbiName {
+                name: name.to_string(),
+                span: arg.span.clone(),
+            });
+        }
+    }
+}
+
 /// Unifies the types of the return statements and the return type of the
 /// function declaration.
+///
+/// Every mismatching return statement is reported with its own error, which shows both the
+/// declared and the returned type.
+/// The arms of a `match` which is the implicit return of the body are reported with the same
+/// error, when the arms are type checked.
 fn unify_return_statements(
     handler: &Handler,
     ctx: TypeCheckContext,
@@ -174,24 +1075,48 @@ fn unify_return_statements(
     return_type: TypeId,
 ) -> Result<(), ErrorEmitted> {
     let type_engine = ctx.engines.te();
+    let engines = ctx.engines();
 
     let mut error_emitted = None;
 
     for stmt in return_statements.iter() {
+        let returned_value = match &stmt.expression {
+            ty::TyExpressionVariant::Return(value) => value,
+            _ => continue,
+        };
+        // A pure function has no business handing out access to storage.
+        if ctx.purity() == Purity::Pure && is_storage_type(&ctx, returned_value.return_type) {
+            handler.emit_warn(CompileWarning {
+                span: returned_value.span.clone(),
+                warning_content: Warning::ReturningStorageFromPureFunction {
+                    r#type: engines.help_out(returned_value.return_type).to_string(),
+                },
+            });
+        }
         let (warnings, errors) = type_engine.unify_with_self(
-            ctx.engines(),
-            stmt.return_type,
+            engines,
+            returned_value.return_type,
             return_type,
             ctx.self_type(),
-            &stmt.span,
+            &returned_value.span,
             "Return statement must return the declared function return type.",
             None,
         );
         for warn in warnings {
             handler.emit_warn(warn);
         }
-        for err in errors {
-            error_emitted = Some(handler.emit_err(err));
+        if !errors.is_empty() {
+            let expected = engines.help_out(return_type).to_string();
+            let span = stmt.span.clone();
+            error_emitted = Some(handler.emit_err(if is_bare_return(stmt, returned_value) {
+                CompileError::MissingReturnValue { expected, span }
+            } else {
+                CompileError::ReturnTypeMismatch {
+                    expected,
+                    found: engines.help_out(returned_value.return_type).to_string(),
+                    span,
+                }
+            }));
         }
     }
     if let Some(err) = error_emitted {
@@ -201,37 +1126,857 @@ fn unify_return_statements(
     }
 }
 
-#[test]
-fn test_function_selector_behavior() {
-    use crate::language::Visibility;
-    use crate::Engines;
-    use sway_types::{integer_bits::IntegerBits, Ident, Span};
+/// Returns `true` if `stmt` is a `return;` without a value. It returns the unit value, which
+/// is spanned by the whole statement.
+fn is_bare_return(stmt: &ty::TyExpression, returned_value: &ty::TyExpression) -> bool {
+    matches!(&returned_value.expression, ty::TyExpressionVariant::Tuple { fields } if fields.is_empty())
+        && returned_value.span == stmt.span
+}
 
-    let engines = Engines::default();
-    let handler = Handler::default();
-    let decl = ty::TyFunctionDecl {
-        purity: Default::default(),
-        name: Ident::new_no_span("foo".into()),
-        implementing_type: None,
-        body: ty::TyCodeBlock { contents: vec![] },
-        parameters: vec![],
-        span: Span::dummy(),
-        attributes: Default::default(),
-        return_type: TypeId::from(0).into(),
-        type_parameters: vec![],
-        visibility: Visibility::Public,
-        is_contract_call: false,
-        where_clause: vec![],
+/// The type of the values returned by `body`, whose type is `implicit_block_return`: the type
+/// of its tail expression or, if there is none, the type of its first `return`. The remaining
+/// returns are unified with it as usual.
+fn inferred_return_type(body: &ty::TyCodeBlock, implicit_block_return: TypeId) -> TypeId {
+    let has_tail_expression = body.contents.iter().any(|node| {
+        matches!(
+            node.content,
+            ty::TyAstNodeContent::ImplicitReturnExpression(_)
+        )
+    });
+    if has_tail_expression {
+        return implicit_block_return;
+    }
+    body.contents
+        .iter()
+        .flat_map(|node| node.gather_return_statements())
+        .find_map(|stmt| match &stmt.expression {
+            ty::TyExpressionVariant::Return(value) => Some(value.return_type),
+            _ => None,
+        })
+        .unwrap_or(implicit_block_return)
+}
+
+/// Whether `type_id` refers to contract storage, i.e. it is the storage itself or a
+/// `core::storage::StorageKey`.
+///
+/// `StorageKey` is resolved in the namespace, and the struct is compared against its
+/// declaration, of which the instantiations of the generic struct are copies.
+fn is_storage_type(ctx: &TypeCheckContext, type_id: TypeId) -> bool {
+    let decl_engine = ctx.engines.de();
+    let decl_ref = match ctx.engines.te().get(type_id) {
+        TypeInfo::Storage { .. } => return true,
+        TypeInfo::Struct(decl_ref) => decl_ref,
+        _ => return false,
     };
+    let storage_key_mod_path = [
+        Ident::new_no_span("core".into()),
+        Ident::new_no_span("storage".into()),
+    ];
+    let storage_key_ident = Ident::new_no_span("StorageKey".into());
+    match ctx.namespace.root().resolve_symbol(
+        &Handler::default(),
+        &storage_key_mod_path,
+        &storage_key_ident,
+    ) {
+        Ok(ty::TyDecl::StructDecl(ty::StructDecl { decl_id, .. })) => {
+            decl_engine.get_struct(decl_id).span == decl_engine.get_struct(&decl_ref).span
+        }
+        _ => false,
+    }
+}
 
-    let selector_text = decl
-        .to_selector_name(&handler, &engines)
-        .expect("test failure");
+/// Warns about the code which follows an unconditional `return`.
+///
+/// Only the first unreachable node of each code block is reported, and the nodes after it are
+/// not inspected any further.
+fn warn_unreachable_code(handler: &Handler, body: &ty::TyCodeBlock) {
+    code_block_returns(handler, body);
+}
 
-    assert_eq!(selector_text, "foo()".to_string());
+/// Warns about a `return` with a value which ends the function body. Bare `return`s, as well
+/// as the `return`s which cannot be reached, are left alone.
+fn warn_redundant_tail_return(handler: &Handler, body: &ty::TyCodeBlock) {
+    let (last_node, preceding_nodes) = match body.contents.split_last() {
+        Some(split) => split,
+        None => return,
+    };
+    let stmt = match last_node.expression() {
+        Some(stmt) => stmt,
+        None => return,
+    };
+    let returned_value = match &stmt.expression {
+        ty::TyExpressionVariant::Return(returned_value) => returned_value,
+        _ => return,
+    };
+    if is_bare_return(stmt, returned_value) {
+        return;
+    }
+    // An unreachable `return` is reported by `warn_unreachable_code` already.
+    let is_reachable = !preceding_nodes.iter().any(|node| {
+        node.expression()
+            .map(|expr| expression_returns(&Handler::default(), expr))
+            .unwrap_or(false)
+    });
+    if is_reachable {
+        handler.emit_warn(CompileWarning {
+            span: stmt.span.clone(),
+            warning_content: Warning::RedundantTailReturn,
+        });
+    }
+}
+
+/// Returns `true` if evaluating `code_block` always ends in a `return`, emitting an
+/// [Warning::UnreachableCode] for the node which follows the diverging one.
+fn code_block_returns(handler: &Handler, code_block: &ty::TyCodeBlock) -> bool {
+    for (i, node) in code_block.contents.iter().enumerate() {
+        let returns = node
+            .expression()
+            .map(|expr| expression_returns(handler, expr))
+            .unwrap_or(false);
+        if returns {
+            if let Some(next_node) = code_block.contents.get(i + 1) {
+                handler.emit_warn(CompileWarning {
+                    span: next_node.span.clone(),
+                    warning_content: Warning::UnreachableCode,
+                });
+            }
+            return true;
+        }
+    }
+    false
+}
+
+/// Reports a function returning an uninhabited type, e.g. an enum without variants, unless its
+/// body never completes normally. A function with an uninhabited parameter can never be called,
+/// so it may return anything.
+fn check_uninhabited_return_type(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    name: &Ident,
+    parameters: &[ty::TyFunctionParameter],
+    return_type: &TypeArgument,
+    body: &ty::TyCodeBlock,
+) {
+    let engines = ctx.engines();
+    let (type_engine, decl_engine) = (engines.te(), engines.de());
+    let is_uninhabited = |type_id| {
+        type_engine
+            .get(type_id)
+            .is_uninhabited(type_engine, decl_engine)
+    };
+    if !is_uninhabited(return_type.type_id)
+        || parameters
+            .iter()
+            .any(|param| is_uninhabited(param.type_argument.type_id))
+        || code_block_diverges(engines, body)
+    {
+        return;
+    }
+    handler.emit_err(CompileError::CannotReturnUninhabitedType {
+        fn_name: name.clone(),
+        ty: engines.help_out(return_type.type_id).to_string(),
+        span: return_type.span.clone(),
+    });
+}
+
+/// Returns `true` if a function with the given `body` can never return to its caller. Each of
+/// its `return_statements` has to diverge before returning, and so has the body itself.
+fn function_diverges(
+    engines: &Engines,
+    return_statements: &[&ty::TyExpression],
+    body: &ty::TyCodeBlock,
+) -> bool {
+    return_statements
+        .iter()
+        .all(|ret| expression_diverges(engines, ret))
+        && code_block_diverges(engines, body)
+}
+
+/// Returns `true` if evaluating `code_block` never completes normally, because it reverts or
+/// calls a function which cannot return.
+fn code_block_diverges(engines: &Engines, code_block: &ty::TyCodeBlock) -> bool {
+    code_block.contents.iter().any(|node| {
+        node.expression()
+            .map(|expr| expression_diverges(engines, expr))
+            .unwrap_or(false)
+    })
+}
+
+fn expression_diverges(engines: &Engines, expr: &ty::TyExpression) -> bool {
+    match &expr.expression {
+        ty::TyExpressionVariant::IntrinsicFunction(intrinsic) => {
+            matches!(intrinsic.kind, Intrinsic::Revert)
+        }
+        ty::TyExpressionVariant::FunctionApplication { fn_ref, .. } => {
+            engines
+                .te()
+                .get(expr.return_type)
+                .is_uninhabited(engines.te(), engines.de())
+                || engines.de().get_function(fn_ref).diverges
+        }
+        ty::TyExpressionVariant::Return(exp) => expression_diverges(engines, exp),
+        ty::TyExpressionVariant::CodeBlock(code_block) => code_block_diverges(engines, code_block),
+        ty::TyExpressionVariant::MatchExp { desugared, .. } => {
+            expression_diverges(engines, desugared)
+        }
+        ty::TyExpressionVariant::IfExp {
+            condition,
+            then,
+            r#else,
+        } => {
+            expression_diverges(engines, condition)
+                || r#else.as_ref().map_or(false, |r#else| {
+                    expression_diverges(engines, then) && expression_diverges(engines, r#else)
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if evaluating `expr` always ends in a `return`, checking the code blocks
+/// nested within it along the way.
+fn expression_returns(handler: &Handler, expr: &ty::TyExpression) -> bool {
+    match &expr.expression {
+        ty::TyExpressionVariant::Return(exp) => {
+            expression_returns(handler, exp);
+            true
+        }
+        ty::TyExpressionVariant::CodeBlock(code_block)
+        | ty::TyExpressionVariant::TryBlock(code_block) => code_block_returns(handler, code_block),
+        ty::TyExpressionVariant::MatchExp { desugared, .. } => {
+            expression_returns(handler, desugared)
+        }
+        ty::TyExpressionVariant::IfExp {
+            condition,
+            then,
+            r#else,
+        } => {
+            if expression_returns(handler, condition) {
+                return true;
+            }
+            let then_returns = expression_returns(handler, then);
+            let else_returns = r#else
+                .as_ref()
+                .map(|r#else| expression_returns(handler, r#else))
+                .unwrap_or(false);
+            then_returns && else_returns
+        }
+        // The right hand side is not always evaluated.
+        ty::TyExpressionVariant::LazyOperator { lhs, rhs, .. } => {
+            if expression_returns(handler, lhs) {
+                return true;
+            }
+            expression_returns(handler, rhs);
+            false
+        }
+        // The loop body is not always evaluated.
+        ty::TyExpressionVariant::WhileLoop { condition, body } => {
+            if expression_returns(handler, condition) {
+                return true;
+            }
+            code_block_returns(handler, body);
+            false
+        }
+        expr => expr
+            .sub_expressions()
+            .into_iter()
+            .any(|expr| expression_returns(handler, expr)),
+    }
+}
+
+/// Rejects the parameters of type `()` of an ABI method. They carry no data, but still have
+/// to be encoded by every caller of the contract.
+fn check_unit_parameters(
+    handler: &Handler,
+    engines: &Engines,
+    fn_name: &Ident,
+    parameters: &[ty::TyFunctionParameter],
+) {
+    let type_engine = engines.te();
+    for param in parameters {
+        if matches!(type_engine.get(param.type_argument.type_id), TypeInfo::Tuple(fields) if fields.is_empty())
+        {
+            handler.emit_err(CompileError::UnitParameterInAbiMethod {
+                fn_name: fn_name.clone(),
+                name: param.name.clone(),
+                span: param.type_argument.span.clone(),
+            });
+        }
+    }
+}
+
+/// Warns about the parameter and return types of a public function which are private structs or
+/// enums. Their type arguments aren't looked into, e.g. a public function can return a
+/// `Result<T, E>` of a private error `E`.
+fn warn_leaked_private_types(
+    handler: &Handler,
+    engines: &Engines,
+    parameters: &[ty::TyFunctionParameter],
+    return_type: &TypeArgument,
+) {
+    let type_arguments = parameters
+        .iter()
+        .map(|param| &param.type_argument)
+        .chain(std::iter::once(return_type));
+    for type_argument in type_arguments {
+        if let Some(ty) = private_type(engines, type_argument.type_id) {
+            handler.emit_warn(CompileWarning {
+                span: type_argument.span.clone(),
+                warning_content: Warning::LeakedPrivateTypeInPublicApi { ty },
+            });
+        }
+    }
+}
+
+/// Returns the name of the struct or enum declared by `type_id` if it is private.
+fn private_type(engines: &Engines, type_id: TypeId) -> Option<String> {
+    let decl_engine = engines.de();
+    let (name, visibility) = match engines.te().get(type_id) {
+        TypeInfo::Struct(decl_ref) => {
+            let decl = decl_engine.get_struct(&decl_ref);
+            (decl.call_path.suffix, decl.visibility)
+        }
+        TypeInfo::Enum(decl_ref) => {
+            let decl = decl_engine.get_enum(&decl_ref);
+            (decl.call_path.suffix, decl.visibility)
+        }
+        _ => return None,
+    };
+    (visibility == Visibility::Private).then(|| name.to_string())
+}
+
+/// Warns about the parameter and return types which nest generic types deeper than `limit`.
+fn warn_deep_generic_nesting(
+    handler: &Handler,
+    engines: &Engines,
+    limit: usize,
+    parameters: &[ty::TyFunctionParameter],
+    return_type: &TypeArgument,
+) {
+    let type_arguments = parameters
+        .iter()
+        .map(|param| &param.type_argument)
+        .chain(std::iter::once(return_type));
+    for type_argument in type_arguments {
+        let depth = generic_depth(engines, type_argument.type_id);
+        if depth > limit {
+            handler.emit_warn(CompileWarning {
+                span: type_argument.span.clone(),
+                warning_content: Warning::DeepGenericNesting { depth, limit },
+            });
+        }
+    }
+}
+
+/// Returns how deep generic types are nested in `type_id`, e.g. 0 for `u64` and 2 for
+/// `Vec<Option<u64>>`. Only the instantiations of generic structs and enums add a level; tuples,
+/// arrays and the like are looked through.
+fn generic_depth(engines: &Engines, type_id: TypeId) -> usize {
+    let decl_engine = engines.de();
+    let max_depth = |type_ids: &mut dyn Iterator<Item = TypeId>| {
+        type_ids
+            .map(|type_id| generic_depth(engines, type_id))
+            .max()
+            .unwrap_or(0)
+    };
+    match engines.te().get(type_id) {
+        TypeInfo::Struct(decl_ref) => {
+            let type_parameters = decl_engine.get_struct(&decl_ref).type_parameters;
+            match type_parameters.is_empty() {
+                true => 0,
+                false => 1 + max_depth(&mut type_parameters.iter().map(|param| param.type_id)),
+            }
+        }
+        TypeInfo::Enum(decl_ref) => {
+            let type_parameters = decl_engine.get_enum(&decl_ref).type_parameters;
+            match type_parameters.is_empty() {
+                true => 0,
+                false => 1 + max_depth(&mut type_parameters.iter().map(|param| param.type_id)),
+            }
+        }
+        TypeInfo::Tuple(fields) => max_depth(&mut fields.iter().map(|field| field.type_id)),
+        TypeInfo::Array(elem_ty, _)
+        | TypeInfo::Ptr(elem_ty)
+        | TypeInfo::Slice(elem_ty)
+        | TypeInfo::Alias { ty: elem_ty, .. } => generic_depth(engines, elem_ty.type_id),
+        _ => 0,
+    }
+}
+
+/// Returns the cyclomatic complexity of `body`, i.e. one plus the number of its branches.
+///
+/// Every `if` and every `while` loop is a branch. The arms of a `match` are counted through the
+/// `if` expressions it is desugared to, so only the arms which test the matched value count.
+fn function_complexity(body: &ty::TyCodeBlock) -> u32 {
+    let mut branches = 0;
+    body.visit_expressions(&mut |expr| {
+        if matches!(
+            expr.expression,
+            ty::TyExpressionVariant::IfExp { .. } | ty::TyExpressionVariant::WhileLoop { .. }
+        ) {
+            branches += 1;
+        }
+    });
+    1 + branches
+}
+
+/// Warns about the method `name` if its `self` receiver is never referenced in its body, in
+/// which case the method could be an associated function instead. Methods whose names start
+/// with an underscore are exempt.
+fn warn_unused_self(
+    handler: &Handler,
+    name: &Ident,
+    parameters: &[ty::TyFunctionParameter],
+    body: &ty::TyCodeBlock,
+) {
+    if name.as_str().starts_with('_') || !parameters.iter().any(|param| param.is_self()) {
+        return;
+    }
+    let mut uses_self = false;
+    body.visit_expressions(&mut |expr| match &expr.expression {
+        ty::TyExpressionVariant::VariableExpression { name, .. } if name.as_str() == "self" => {
+            uses_self = true;
+        }
+        ty::TyExpressionVariant::Reassignment(reassignment)
+            if reassignment.lhs_base_name.as_str() == "self" =>
+        {
+            uses_self = true;
+        }
+        _ => {}
+    });
+    if !uses_self {
+        handler.emit_warn(CompileWarning {
+            span: name.span(),
+            warning_content: Warning::MethodDoesNotUseSelf { name: name.clone() },
+        });
+    }
+}
+
+/// Warns about the parameters which are returned unchanged, as the value of the body or with a
+/// `return`, and used nowhere else. The `self` receiver and parameters whose names start with
+/// an underscore are exempt.
+fn warn_passed_through_parameters(
+    handler: &Handler,
+    parameters: &[ty::TyFunctionParameter],
+    body: &ty::TyCodeBlock,
+) {
+    let mut returned_values: Vec<&ty::TyExpression> = body
+        .contents
+        .iter()
+        .flat_map(|node| node.gather_return_statements())
+        .filter_map(|stmt| match &stmt.expression {
+            ty::TyExpressionVariant::Return(returned_value) => Some(&**returned_value),
+            _ => None,
+        })
+        .collect();
+    if let Some(ty::TyAstNode {
+        content: ty::TyAstNodeContent::ImplicitReturnExpression(returned_value),
+        ..
+    }) = body.contents.last()
+    {
+        returned_values.push(returned_value);
+    }
+
+    let mut returned_counts: HashMap<&str, usize> = HashMap::new();
+    for returned_value in returned_values {
+        if let ty::TyExpressionVariant::VariableExpression { name, .. } = &returned_value.expression
+        {
+            *returned_counts.entry(name.as_str()).or_default() += 1;
+        }
+    }
+    if returned_counts.is_empty() {
+        return;
+    }
+
+    let mut use_counts: HashMap<&str, usize> = HashMap::new();
+    body.visit_expressions(&mut |expr| match &expr.expression {
+        ty::TyExpressionVariant::VariableExpression { name, .. } => {
+            *use_counts.entry(name.as_str()).or_default() += 1;
+        }
+        ty::TyExpressionVariant::Reassignment(reassignment) => {
+            *use_counts
+                .entry(reassignment.lhs_base_name.as_str())
+                .or_default() += 1;
+        }
+        _ => {}
+    });
+
+    for param in parameters {
+        let name = param.name.as_str();
+        if param.is_self() || name.starts_with('_') {
+            continue;
+        }
+        let returned_count = returned_counts.get(name).copied().unwrap_or(0);
+        if returned_count > 0 && use_counts.get(name).copied() == Some(returned_count) {
+            handler.emit_warn(CompileWarning {
+                span: param.name.span(),
+                warning_content: Warning::ParameterPassedThrough {
+                    name: param.name.clone(),
+                },
+            });
+        }
+    }
+}
+
+/// Warns about the type parameters which appear neither in the signature nor in the body of
+/// the function. Mentioning a type parameter in a bound of the where clause counts as a use.
+fn warn_unused_type_parameters(
+    handler: &Handler,
+    engines: &Engines,
+    type_parameters: &[TypeParameter],
+    where_clause: &[(Ident, Vec<TraitConstraint>)],
+    parameters: &[ty::TyFunctionParameter],
+    return_type: &TypeArgument,
+    body: &ty::TyCodeBlock,
+) {
+    let type_engine = engines.te();
+
+    let mut used_type_ids = vec![return_type.type_id];
+    used_type_ids.extend(parameters.iter().map(|param| param.type_argument.type_id));
+    let constraints = type_parameters
+        .iter()
+        .flat_map(|type_param| type_param.trait_constraints.iter())
+        .chain(where_clause.iter().flat_map(|(_, bounds)| bounds.iter()));
+    for constraint in constraints {
+        used_type_ids.extend(constraint.type_arguments.iter().map(|arg| arg.type_id));
+    }
+    body.visit_expressions(&mut |expr| {
+        used_type_ids.push(expr.return_type);
+        match &expr.expression {
+            ty::TyExpressionVariant::FunctionApplication {
+                type_binding: Some(type_binding),
+                ..
+            } => {
+                let type_arguments = type_binding.type_arguments.to_vec();
+                used_type_ids.extend(type_arguments.iter().map(|arg| arg.type_id));
+            }
+            ty::TyExpressionVariant::IntrinsicFunction(kind) => {
+                used_type_ids.extend(kind.type_arguments.iter().map(|arg| arg.type_id));
+            }
+            _ => {}
+        }
+    });
+
+    let mut used_names: HashSet<Ident> =
+        where_clause.iter().map(|(name, _)| name.clone()).collect();
+    // Type arguments which weren't resolved in place are still mentioned by name.
+    for type_id in used_type_ids {
+        let generics = type_id.extract_any_including_self(
+            engines,
+            &|type_info| {
+                matches!(
+                    type_info,
+                    TypeInfo::UnknownGeneric { .. } | TypeInfo::Custom { .. }
+                )
+            },
+            vec![],
+        );
+        for generic in generics.keys() {
+            match type_engine.get(*generic) {
+                TypeInfo::UnknownGeneric { name, .. } => {
+                    used_names.insert(name);
+                }
+                TypeInfo::Custom { call_path, .. } if call_path.prefixes.is_empty() => {
+                    used_names.insert(call_path.suffix);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    for type_param in type_parameters {
+        if used_names.contains(&type_param.name_ident) {
+            continue;
+        }
+        handler.emit_warn(CompileWarning {
+            span: type_param.name_ident.span(),
+            warning_content: Warning::UnusedTypeParameter {
+                name: type_param.name_ident.clone(),
+            },
+        });
+    }
+}
+
+/// Reports the calls of functions which access storage from the body of the pure function
+/// `caller`.
+///
+/// Contract calls are exempt, as they access the storage of the called contract.
+fn check_pure_function_calls(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    caller: &Ident,
+    body: &ty::TyCodeBlock,
+) {
+    let decl_engine = ctx.engines.de();
+
+    body.visit_expressions(&mut |expr| {
+        if let ty::TyExpressionVariant::FunctionApplication { fn_ref, .. } = &expr.expression {
+            let callee = decl_engine.get_function(fn_ref);
+            if !callee.is_contract_call && callee.purity != Purity::Pure {
+                handler.emit_err(CompileError::StorageAccessInPureFunction {
+                    callee: callee.name,
+                    caller: caller.clone(),
+                    attrs: callee.purity.to_attribute_syntax(),
+                    span: expr.span.clone(),
+                });
+            }
+        }
+    });
+}
+
+/// Reports the operations in the body of the const function `fn_name` which can't be evaluated
+/// at compile time: storage accesses, reverts, `asm` blocks and calls of functions which access
+/// storage or other contracts.
+fn check_const_fn_body(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    fn_name: &Ident,
+    body: &ty::TyCodeBlock,
+) {
+    let decl_engine = ctx.engines.de();
+
+    body.visit_expressions(&mut |expr| {
+        let operation = match &expr.expression {
+            ty::TyExpressionVariant::StorageAccess(_) => Some("access storage".to_string()),
+            ty::TyExpressionVariant::IntrinsicFunction(intrinsic) => match intrinsic.kind {
+                Intrinsic::StateLoadWord
+                | Intrinsic::StateLoadQuad
+                | Intrinsic::StateStoreWord
+                | Intrinsic::StateStoreQuad
+                | Intrinsic::StateClear => Some("access storage".to_string()),
+                Intrinsic::Revert => Some("revert".to_string()),
+                _ => None,
+            },
+            ty::TyExpressionVariant::AsmExpression { .. } => {
+                Some("contain `asm` blocks".to_string())
+            }
+            ty::TyExpressionVariant::FunctionApplication { fn_ref, .. } => {
+                let callee = decl_engine.get_function(fn_ref);
+                if callee.is_contract_call {
+                    Some(format!("call the contract method \"{}\"", callee.name))
+                } else if callee.purity != Purity::Pure {
+                    Some(format!("call \"{}\", which accesses storage", callee.name))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+        if let Some(operation) = operation {
+            handler.emit_err(CompileError::NonConstOperationInConstFn {
+                fn_name: fn_name.clone(),
+                operation,
+                span: expr.span.clone(),
+            });
+        }
+    });
+}
+
+/// Infers the storage access of a function from its body, and returns the declared `purity`
+/// narrowed down to it.
+///
+/// Storage is accessed by the storage intrinsics, by `asm` blocks with storage instructions and
+/// by calls of functions which access storage. The functions called are taken to access storage
+/// as they declare, since that's what calling them requires. If the body accesses more than the
+/// declared `purity` allows, it is returned unchanged, and the mismatch is reported elsewhere.
+fn infer_purity(ctx: &TypeCheckContext, purity: Purity, body: &ty::TyCodeBlock) -> Purity {
+    let decl_engine = ctx.engines.de();
+
+    let mut reads = false;
+    let mut writes = false;
+    body.visit_expressions(&mut |expr| match &expr.expression {
+        ty::TyExpressionVariant::StorageAccess(_) => reads = true,
+        ty::TyExpressionVariant::IntrinsicFunction(intrinsic) => match intrinsic.kind {
+            Intrinsic::StateLoadWord | Intrinsic::StateLoadQuad => reads = true,
+            Intrinsic::StateStoreWord | Intrinsic::StateStoreQuad | Intrinsic::StateClear => {
+                writes = true
+            }
+            _ => {}
+        },
+        ty::TyExpressionVariant::AsmExpression { body, .. } => {
+            for op in body {
+                match op.op_name.as_str() {
+                    "scwq" | "srw" | "srwq" => reads = true,
+                    "sww" | "swwq" => writes = true,
+                    _ => {}
+                }
+            }
+        }
+        // Contract calls access the storage of the called contract.
+        ty::TyExpressionVariant::FunctionApplication { fn_ref, .. } => {
+            let callee = decl_engine.get_function(fn_ref);
+            if !callee.is_contract_call {
+                match callee.purity {
+                    Purity::Pure => {}
+                    Purity::Reads => reads = true,
+                    Purity::Writes => writes = true,
+                    Purity::ReadsWrites => {
+                        reads = true;
+                        writes = true;
+                    }
+                }
+            }
+        }
+        _ => {}
+    });
+
+    // `storage(write)` allows reading as well.
+    let allows_reads = purity != Purity::Pure;
+    let allows_writes = matches!(purity, Purity::Writes | Purity::ReadsWrites);
+    if (reads && !allows_reads) || (writes && !allows_writes) {
+        return purity;
+    }
+    match (reads, writes) {
+        (false, false) => Purity::Pure,
+        (true, false) => Purity::Reads,
+        (false, true) => Purity::Writes,
+        (true, true) => purity,
+    }
+}
+
+/// Collects the storage fields accessed in `body`.
+///
+/// A storage field passed to a function, e.g. as the receiver of `storage.foo.write(..)`, is
+/// accessed as the function declares. Any other mention of it is a read.
+fn collect_storage_access(ctx: &TypeCheckContext, body: &ty::TyCodeBlock) -> ty::StorageAccessSet {
+    let decl_engine = ctx.engines.de();
+
+    let mut storage_access = ty::StorageAccessSet::default();
+    let mut passed_spans = HashSet::new();
+    let mut other_accesses = vec![];
+    body.visit_expressions(&mut |expr| match &expr.expression {
+        ty::TyExpressionVariant::FunctionApplication {
+            fn_ref, arguments, ..
+        } => {
+            let callee = decl_engine.get_function(fn_ref);
+            for (_, arg) in arguments {
+                if let ty::TyExpressionVariant::StorageAccess(access) = &arg.expression {
+                    passed_spans.insert(arg.span.clone());
+                    if matches!(callee.purity, Purity::Reads | Purity::ReadsWrites) {
+                        storage_access.reads.insert(access.field_path());
+                    }
+                    if matches!(callee.purity, Purity::Writes | Purity::ReadsWrites) {
+                        storage_access.writes.insert(access.field_path());
+                    }
+                }
+            }
+        }
+        ty::TyExpressionVariant::StorageAccess(access) => {
+            other_accesses.push((expr.span.clone(), access.field_path()));
+        }
+        _ => {}
+    });
+
+    for (span, path) in other_accesses {
+        if !passed_spans.contains(&span) {
+            storage_access.reads.insert(path);
+        }
+    }
+    storage_access
+}
+
+/// Warns about the calls of a method from within its own body, as recursion can easily
+/// exhaust the bounded call stack of the FuelVM. Such a call is an error if the method is
+/// `#[inline(always)]`, as inlining it would never end.
+///
+/// A call of a method with the same name on the type being implemented, e.g. through `self` or
+/// `Self`, is recursive: inside of a trait implementation such calls resolve to the trait's
+/// interface rather than to the method being declared. Only direct self-recursion is detected.
+/// Recursive free functions are already rejected when ordering the declarations by their
+/// dependencies, so they never reach this check.
+fn check_direct_recursion(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    name: &Ident,
+    inline: Option<Inline>,
+    body: &ty::TyCodeBlock,
+) {
+    let type_engine = ctx.engines.te();
+    let decl_engine = ctx.engines.de();
+    let engines = ctx.engines();
+
+    body.visit_expressions(&mut |expr| {
+        if let ty::TyExpressionVariant::FunctionApplication {
+            fn_ref,
+            call_path_typeid,
+            ..
+        } = &expr.expression
+        {
+            let callee = decl_engine.get_function(fn_ref);
+            let calls_itself = callee.name == *name
+                && call_path_typeid.map_or(false, |type_id| {
+                    type_engine
+                        .get(type_id)
+                        .eq(&type_engine.get(ctx.self_type()), engines)
+                });
+            if !calls_itself {
+                return;
+            }
+            if inline == Some(Inline::Always) {
+                handler.emit_err(CompileError::RecursiveAlwaysInline {
+                    name: name.clone(),
+                    span: expr.span.clone(),
+                });
+            } else {
+                handler.emit_warn(CompileWarning {
+                    span: expr.span.clone(),
+                    warning_content: Warning::RecursiveFunction { name: name.clone() },
+                });
+            }
+        }
+    });
+}
+
+#[test]
+fn test_function_selector_behavior() {
+    use crate::language::Visibility;
+    use crate::Engines;
+    use sway_types::{integer_bits::IntegerBits, Ident, Span};
+
+    let engines = Engines::default();
+    let handler = Handler::default();
+    let decl = ty::TyFunctionDecl {
+        purity: Default::default(),
+        storage_access: None,
+        symbol_name: None,
+        diverges: false,
+        is_constructor: false,
+        reentrancy_guard: false,
+        is_fallback: false,
+        complexity: 1,
+        gas_hint: None,
+        is_const: false,
+        name: Ident::new_no_span("foo".into()),
+        implementing_type: None,
+        body: ty::TyCodeBlock { contents: vec![] },
+        parameters: vec![],
+        span: Span::dummy(),
+        attributes: Default::default(),
+        return_type: TypeId::from(0).into(),
+        type_parameters: vec![],
+        visibility: Visibility::Public,
+        is_contract_call: false,
+        inline: None,
+        where_clause: vec![],
+    };
+
+    let selector_text = decl
+        .to_selector_name(&handler, &engines)
+        .expect("test failure");
+
+    assert_eq!(selector_text, "foo()".to_string());
 
     let decl = ty::TyFunctionDecl {
         purity: Default::default(),
+        storage_access: None,
+        symbol_name: None,
+        diverges: false,
+        is_constructor: false,
+        reentrancy_guard: false,
+        is_fallback: false,
+        complexity: 1,
+        gas_hint: None,
+        is_const: false,
         name: Ident::new_with_override("bar".into(), Span::dummy()),
         implementing_type: None,
         body: ty::TyCodeBlock { contents: vec![] },
@@ -245,6 +1990,8 @@ fn test_function_selector_behavior() {
                     .te()
                     .insert(&engines, TypeInfo::Str(Length::new(5, Span::dummy())))
                     .into(),
+                default_value: None,
+                attributes: Default::default(),
             },
             ty::TyFunctionParameter {
                 name: Ident::new_no_span("baz".into()),
@@ -261,6 +2008,8 @@ fn test_function_selector_behavior() {
                     span: Span::dummy(),
                     call_path_tree: None,
                 },
+                default_value: None,
+                attributes: Default::default(),
             },
         ],
         span: Span::dummy(),
@@ -269,6 +2018,7 @@ fn test_function_selector_behavior() {
         type_parameters: vec![],
         visibility: Visibility::Public,
         is_contract_call: false,
+        inline: None,
         where_clause: vec![],
     };
 
@@ -278,3 +2028,1382 @@ fn test_function_selector_behavior() {
 
     assert_eq!(selector_text, "bar(str[5],u32)".to_string());
 }
+
+#[test]
+fn test_function_selector_behavior_composite_types() {
+    use crate::decl_engine::DeclEngineInsert;
+    use crate::language::{CallPath, Visibility};
+    use crate::Engines;
+    use sway_types::{integer_bits::IntegerBits, Ident, Span};
+
+    let engines = Engines::default();
+    let handler = Handler::default();
+    let u64_type = engines
+        .te()
+        .insert(&engines, TypeInfo::UnsignedInteger(IntegerBits::SixtyFour));
+    let bool_type = engines.te().insert(&engines, TypeInfo::Boolean);
+    let unit_type = engines.te().insert(&engines, TypeInfo::Tuple(vec![]));
+
+    let fn_decl = |name: &str, parameter_types: Vec<TypeId>| ty::TyFunctionDecl {
+        purity: Default::default(),
+        storage_access: None,
+        symbol_name: None,
+        diverges: false,
+        is_constructor: false,
+        reentrancy_guard: false,
+        is_fallback: false,
+        complexity: 1,
+        gas_hint: None,
+        is_const: false,
+        name: Ident::new_no_span(name.into()),
+        implementing_type: None,
+        body: ty::TyCodeBlock { contents: vec![] },
+        parameters: parameter_types
+            .into_iter()
+            .map(|type_id| ty::TyFunctionParameter {
+                name: Ident::new_no_span("param".into()),
+                is_reference: false,
+                is_mutable: false,
+                mutability_span: Span::dummy(),
+                type_argument: type_id.into(),
+                default_value: None,
+                attributes: Default::default(),
+            })
+            .collect(),
+        span: Span::dummy(),
+        attributes: Default::default(),
+        return_type: TypeId::from(0).into(),
+        type_parameters: vec![],
+        visibility: Visibility::Public,
+        is_contract_call: false,
+        inline: None,
+        where_clause: vec![],
+    };
+    let call_path = |name: &str| CallPath {
+        prefixes: vec![],
+        suffix: Ident::new_no_span(name.into()),
+        is_absolute: false,
+    };
+    let struct_field = |name: &str, type_id: TypeId| ty::TyStructField {
+        name: Ident::new_no_span(name.into()),
+        span: Span::dummy(),
+        type_argument: type_id.into(),
+        attributes: Default::default(),
+    };
+
+    // struct Point { x: u64, y: bool }
+    let point_decl = engines.de().insert(ty::TyStructDecl {
+        call_path: call_path("Point"),
+        fields: vec![struct_field("x", u64_type), struct_field("y", bool_type)],
+        type_parameters: vec![],
+        visibility: Visibility::Public,
+        span: Span::dummy(),
+        attributes: Default::default(),
+    });
+    let point_type = engines.te().insert(&engines, TypeInfo::Struct(point_decl));
+
+    let selector_text = fn_decl("bar", vec![point_type])
+        .to_selector_name(&handler, &engines)
+        .expect("test failure");
+
+    assert_eq!(selector_text, "bar(s(u64,bool))".to_string());
+
+    // enum Choice { A: u64, B: () }
+    let choice_decl = engines.de().insert(ty::TyEnumDecl {
+        call_path: call_path("Choice"),
+        type_parameters: vec![],
+        attributes: Default::default(),
+        variants: [("A", u64_type), ("B", unit_type)]
+            .into_iter()
+            .enumerate()
+            .map(|(tag, (name, type_id))| ty::TyEnumVariant {
+                name: Ident::new_no_span(name.into()),
+                type_argument: type_id.into(),
+                tag,
+                span: Span::dummy(),
+                attributes: Default::default(),
+            })
+            .collect(),
+        span: Span::dummy(),
+        visibility: Visibility::Public,
+    });
+    let choice_type = engines.te().insert(&engines, TypeInfo::Enum(choice_decl));
+
+    let selector_text = fn_decl("bar", vec![choice_type])
+        .to_selector_name(&handler, &engines)
+        .expect("test failure");
+
+    assert_eq!(selector_text, "bar(e(u64,()))".to_string());
+
+    // (u64, bool)
+    let tuple_type = engines.te().insert(
+        &engines,
+        TypeInfo::Tuple(vec![u64_type.into(), bool_type.into()]),
+    );
+
+    let selector_text = fn_decl("bar", vec![tuple_type])
+        .to_selector_name(&handler, &engines)
+        .expect("test failure");
+
+    assert_eq!(selector_text, "bar((u64,bool))".to_string());
+
+    // [Point; 3]
+    let array_type = engines.te().insert(
+        &engines,
+        TypeInfo::Array(point_type.into(), Length::new(3, Span::dummy())),
+    );
+
+    let selector_text = fn_decl("bar", vec![array_type, u64_type])
+        .to_selector_name(&handler, &engines)
+        .expect("test failure");
+
+    assert_eq!(selector_text, "bar(a[s(u64,bool);3],u64)".to_string());
+
+    // struct Wrapper<T> { inner: T }, monomorphized with T = (u64, bool)
+    let type_parameter = |type_id: TypeId| TypeParameter {
+        type_id,
+        initial_type_id: type_id,
+        name_ident: Ident::new_no_span("T".into()),
+        trait_constraints: vec![],
+        trait_constraints_span: Span::dummy(),
+        is_from_parent: false,
+    };
+    let wrapper_decl = |type_id: TypeId| ty::TyStructDecl {
+        call_path: call_path("Wrapper"),
+        fields: vec![struct_field("inner", type_id)],
+        type_parameters: vec![type_parameter(type_id)],
+        visibility: Visibility::Public,
+        span: Span::dummy(),
+        attributes: Default::default(),
+    };
+    let wrapper_type = engines.te().insert(
+        &engines,
+        TypeInfo::Struct(engines.de().insert(wrapper_decl(tuple_type))),
+    );
+
+    let selector_text = fn_decl("bar", vec![wrapper_type])
+        .to_selector_name(&handler, &engines)
+        .expect("test failure");
+
+    assert_eq!(selector_text, "bar(s<(u64,bool)>((u64,bool)))".to_string());
+
+    // Generic types which are not monomorphized have no selector encoding.
+    let generic_type = engines.te().insert(
+        &engines,
+        TypeInfo::UnknownGeneric {
+            name: Ident::new_no_span("T".into()),
+            trait_constraints: VecSet(vec![]),
+        },
+    );
+    let generic_wrapper_type = engines.te().insert(
+        &engines,
+        TypeInfo::Struct(engines.de().insert(wrapper_decl(generic_type))),
+    );
+
+    assert!(fn_decl("bar", vec![generic_wrapper_type])
+        .to_selector_name(&handler, &engines)
+        .is_err());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{namespace, BuildConfig, BuildTarget, LintConfig};
+    use std::{collections::BTreeSet, path::PathBuf, sync::Arc};
+
+    /// Compiles `src`, returning its typed program and the messages of the errors and warnings
+    /// emitted.
+    fn compile(
+        engines: &Engines,
+        src: &str,
+        build_config: Option<&BuildConfig>,
+    ) -> (Option<ty::TyProgram>, Vec<String>, Vec<String>) {
+        let handler = Handler::default();
+        let program = crate::compile_to_ast(
+            &handler,
+            engines,
+            Arc::from(src),
+            namespace::Module::default(),
+            build_config,
+            "test",
+            &mut sway_utils::PerformanceData::default(),
+        )
+        .ok()
+        .and_then(|programs| programs.typed);
+        let (errors, warnings) = handler.consume();
+        let errors = errors.iter().map(|error| error.to_string()).collect();
+        let warnings = warnings
+            .iter()
+            .map(|warning| warning.warning_content.to_string())
+            .collect();
+        (program, errors, warnings)
+    }
+
+    /// Returns the build configuration of a library, to be adjusted by each test.
+    fn build_config() -> BuildConfig {
+        BuildConfig::root_from_file_name_and_manifest_path(
+            PathBuf::from("/test/src/lib.sw"),
+            PathBuf::from("/test"),
+            BuildTarget::default(),
+        )
+    }
+
+    /// Returns the free functions and the methods of the `impl` blocks of `program`, in order of
+    /// declaration.
+    fn functions(engines: &Engines, program: &ty::TyProgram) -> Vec<ty::TyFunctionDecl> {
+        let decl_engine = engines.de();
+        program
+            .root
+            .all_nodes
+            .iter()
+            .flat_map(|node| match &node.content {
+                ty::TyAstNodeContent::Declaration(ty::TyDecl::FunctionDecl(ty::FunctionDecl {
+                    decl_id,
+                    ..
+                })) => vec![decl_engine.get_function(decl_id)],
+                ty::TyAstNodeContent::Declaration(ty::TyDecl::ImplTrait(ty::ImplTrait {
+                    decl_id,
+                    ..
+                })) => decl_engine
+                    .get_impl_trait(decl_id)
+                    .items
+                    .iter()
+                    .filter_map(|item| match item {
+                        ty::TyTraitItem::Fn(decl_ref) => Some(decl_engine.get_function(decl_ref)),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => vec![],
+            })
+            .collect()
+    }
+
+    /// Compiles `src`, which must not have any errors, returning its functions and the messages
+    /// of the warnings emitted.
+    fn compile_functions(engines: &Engines, src: &str) -> (Vec<ty::TyFunctionDecl>, Vec<String>) {
+        let (program, errors, warnings) = compile(engines, src, None);
+        assert!(errors.is_empty(), "{errors:?}");
+        (
+            functions(engines, &program.expect("test failure")),
+            warnings,
+        )
+    }
+
+    /// Returns the selector names of `functions`.
+    fn selectors(engines: &Engines, functions: &[ty::TyFunctionDecl]) -> Vec<String> {
+        functions
+            .iter()
+            .map(|fn_decl| {
+                fn_decl
+                    .to_selector_name(&Handler::default(), engines)
+                    .expect("test failure")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_non_snake_case_function_name_style_as_error() {
+        let diagnostics = |style_as_error: bool| {
+            let build_config = build_config().lint_config(LintConfig {
+                style_as_error,
+                ..Default::default()
+            });
+            let (_, errors, warnings) = compile(
+                &Engines::default(),
+                "library; pub fn doStuff() {}",
+                Some(&build_config),
+            );
+            (errors, warnings)
+        };
+        let message = "Function name \"doStuff\" is not idiomatic. Function names should be \
+                       snake_case, like \"do_stuff\".";
+
+        let (errors, warnings) = diagnostics(false);
+        assert!(errors.is_empty(), "{errors:?}");
+        assert_eq!(warnings, [message]);
+
+        let (errors, warnings) = diagnostics(true);
+        assert!(warnings.is_empty(), "{warnings:?}");
+        assert_eq!(errors, [message]);
+    }
+
+    #[test]
+    fn test_max_function_parameters() {
+        let warnings = |limit: usize| {
+            let build_config = build_config().lint_config(LintConfig {
+                max_function_parameters: Some(limit),
+                ..Default::default()
+            });
+            let (_, errors, warnings) = compile(
+                &Engines::default(),
+                "library;
+                pub struct S {}
+                impl S {
+                    pub fn f(self, _a: u64, _b: u64) {}
+                }",
+                Some(&build_config),
+            );
+            assert!(errors.is_empty(), "{errors:?}");
+            warnings
+        };
+
+        // The `self` receiver doesn't count towards the limit.
+        assert!(warnings(2).is_empty());
+        assert_eq!(
+            warnings(1),
+            ["This function has 2 parameters, which is more than the limit of 1."]
+        );
+    }
+
+    #[test]
+    fn test_max_type_parameters() {
+        let warnings = |limit: usize| {
+            let build_config = build_config().lint_config(LintConfig {
+                max_type_parameters: Some(limit),
+                ..Default::default()
+            });
+            let (_, errors, warnings) = compile(
+                &Engines::default(),
+                "library; pub fn f<A, B>(_a: A, _b: B) {}",
+                Some(&build_config),
+            );
+            assert!(errors.is_empty(), "{errors:?}");
+            warnings
+        };
+
+        assert!(warnings(2).is_empty());
+        assert_eq!(
+            warnings(1),
+            ["This function has 2 type parameters, which is more than the limit of 1."]
+        );
+    }
+
+    #[test]
+    fn test_max_generic_depth() {
+        let warnings = |limit: usize| {
+            let build_config = build_config().lint_config(LintConfig {
+                max_generic_depth: Some(limit),
+                ..Default::default()
+            });
+            let (_, errors, warnings) = compile(
+                &Engines::default(),
+                "library;
+                pub struct Vec<T> { x: T }
+                pub fn f(_v: Vec<Vec<Vec<u64>>>) -> (u64, Vec<u64>) { (0, Vec { x: 0 }) }",
+                Some(&build_config),
+            );
+            assert!(errors.is_empty(), "{errors:?}");
+            warnings
+        };
+
+        assert!(warnings(3).is_empty());
+        // The return type only nests one level deep.
+        assert_eq!(
+            warnings(2),
+            [
+                "This type nests generic types 3 levels deep, which is more than the limit of 2. \
+                 Every instantiation of it is monomorphized separately."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_max_bool_parameters() {
+        let warnings = |limit: usize| {
+            let build_config = build_config().lint_config(LintConfig {
+                max_bool_parameters: Some(limit),
+                ..Default::default()
+            });
+            let (_, errors, warnings) = compile(
+                &Engines::default(),
+                "library; pub fn f(_a: bool, _b: bool, _c: bool, _d: (bool, bool), _e: u64) {}",
+                Some(&build_config),
+            );
+            assert!(errors.is_empty(), "{errors:?}");
+            warnings
+        };
+
+        // The tuple of `bool`s doesn't count.
+        assert!(warnings(3).is_empty());
+        assert_eq!(
+            warnings(2),
+            [
+                "This function takes 3 boolean parameters, which are easily mixed up at the call \
+                 site. Consider passing a struct of options instead."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_max_complexity() {
+        let warnings = |limit: u32| {
+            let build_config = build_config().lint_config(LintConfig {
+                max_complexity: Some(limit),
+                ..Default::default()
+            });
+            let (_, errors, warnings) = compile(
+                &Engines::default(),
+                "library;
+                pub fn f(a: bool, b: bool) -> u64 {
+                    let mut n = 0;
+                    while a {
+                        if b {
+                            n = 1;
+                        } else if a {
+                            n = 2;
+                        }
+                    }
+                    if b { 3 } else { n }
+                }",
+                Some(&build_config),
+            );
+            assert!(errors.is_empty(), "{errors:?}");
+            warnings
+        };
+
+        // The `while` loop and the three `if`s are branches.
+        assert!(warnings(5).is_empty());
+        assert_eq!(
+            warnings(4),
+            ["This function has a cyclomatic complexity of 5, which is more than the limit of 4."]
+        );
+    }
+
+    #[test]
+    fn test_redundant_tail_return() {
+        let warnings = |redundant_tail_return: bool| {
+            let build_config = build_config().lint_config(LintConfig {
+                redundant_tail_return,
+                ..Default::default()
+            });
+            let (_, errors, warnings) = compile(
+                &Engines::default(),
+                "library;
+                pub fn increment(x: u64) -> u64 {
+                    let y = __add(x, 1);
+                    return y;
+                }
+                pub fn early(x: u64) -> u64 {
+                    if __eq(x, 0) {
+                        return 1;
+                    }
+                    x
+                }
+                pub fn unit() {
+                    return;
+                }",
+                Some(&build_config),
+            );
+            assert!(errors.is_empty(), "{errors:?}");
+            warnings
+        };
+
+        // Neither the early `return` nor the bare one are redundant.
+        assert!(warnings(false).is_empty());
+        assert_eq!(
+            warnings(true),
+            [
+                "This `return` is redundant. The value can be returned implicitly by removing \
+                 `return` and the trailing semicolon."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unused_self() {
+        let warnings = |unused_self: bool| {
+            let build_config = build_config().lint_config(LintConfig {
+                unused_self,
+                ..Default::default()
+            });
+            let (_, errors, warnings) = compile(
+                &Engines::default(),
+                "library;
+                pub struct S { x: u64 }
+                impl S {
+                    pub fn two(self) -> u64 { 2 }
+                    pub fn get(self) -> u64 { self.x }
+                    pub fn _three(self) -> u64 { 3 }
+                }",
+                Some(&build_config),
+            );
+            assert!(errors.is_empty(), "{errors:?}");
+            warnings
+        };
+
+        // The methods whose names start with an underscore are exempt.
+        assert!(warnings(false).is_empty());
+        assert_eq!(
+            warnings(true),
+            [
+                "Method \"two\" never uses \"self\", and could be an associated function \
+                 instead. If this is intentional, prefix the method name with an underscore."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_test_signature() {
+        let errors = |src: &str| {
+            let build_config = build_config().include_tests(true);
+            let (_, errors, _) = compile(
+                &Engines::default(),
+                &format!("library; pub struct S {{}} {src}"),
+                Some(&build_config),
+            );
+            errors
+        };
+
+        assert!(errors("#[test] fn valid() {}").is_empty());
+        for (name, invalid) in [
+            ("takes_parameter", "#[test] fn takes_parameter(_x: u64) {}"),
+            ("returns_value", "#[test] fn returns_value() -> u64 { 0 }"),
+            ("method", "impl S { #[test] fn method() {} }"),
+        ] {
+            assert_eq!(
+                errors(invalid),
+                [format!(
+                    "Test function \"{name}\" can't be run. A test must be a free function which \
+                     takes no parameters and returns \"()\"."
+                )]
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_selector_name() {
+        let parse =
+            |selector| ty::TyFunctionDecl::parse_selector_name(selector).expect("test failure");
+
+        assert_eq!(parse("foo()"), ("foo".to_string(), vec![]));
+        assert_eq!(
+            parse("bar(str[5],u32)"),
+            (
+                "bar".to_string(),
+                vec!["str[5]".to_string(), "u32".to_string()]
+            )
+        );
+        assert_eq!(
+            parse("f((u8,u8),bool)"),
+            (
+                "f".to_string(),
+                vec!["(u8,u8)".to_string(), "bool".to_string()]
+            )
+        );
+        assert_eq!(
+            parse("g(s<u64>(u64,bool),)"),
+            ("g".to_string(), vec!["s<u64>(u64,bool)".to_string()])
+        );
+
+        for selector in [
+            "foo",
+            "(u64)",
+            "foo(u64",
+            "foo((u64)",
+            "foo(u64))",
+            "foo(,u64)",
+        ] {
+            assert!(ty::TyFunctionDecl::parse_selector_name(selector).is_err());
+        }
+    }
+
+    #[test]
+    fn test_trait_constraints() {
+        let engines = Engines::default();
+        let (functions, _) = compile_functions(
+            &engines,
+            "library;
+            pub trait A {}
+            pub trait B {}
+            pub fn f<T: A, U>(_t: T, _u: U) where T: B, U: A {}",
+        );
+
+        let constraints = functions[0]
+            .trait_constraints()
+            .into_iter()
+            .map(|(name, bounds)| {
+                let bounds = bounds
+                    .iter()
+                    .map(|bound| bound.trait_name.suffix.to_string())
+                    .collect::<Vec<_>>();
+                (name.to_string(), bounds)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            constraints,
+            [
+                ("T".to_string(), vec!["A".to_string(), "B".to_string()]),
+                ("U".to_string(), vec!["A".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_signature_string() {
+        let engines = Engines::default();
+        let (functions, _) = compile_functions(
+            &engines,
+            "library;
+            pub trait A {}
+            pub trait B {}
+            pub fn f<T: A, U>(t: T, _u: U) -> T where T: B, U: A { t }
+            pub struct S {}
+            impl S {
+                fn get(ref mut self, x: u64) -> u64 { x }
+            }",
+        );
+
+        let signatures = functions
+            .iter()
+            .map(|fn_decl| fn_decl.signature_string(&engines))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            signatures,
+            [
+                "pub fn f<T: A, U>(t: T, _u: U) -> T where T: B, U: A",
+                "fn get(ref mut self, x: u64) -> u64",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_signature_json() {
+        let engines = Engines::default();
+        let (functions, _) = compile_functions(
+            &engines,
+            "library;
+            pub trait A {}
+            pub fn bar(_foo: str[5], _baz: u32) -> u64 { 0 }
+            fn id<T>(t: T) -> T where T: A { t }",
+        );
+
+        assert_eq!(
+            functions[0].to_signature_json(&engines),
+            serde_json::json!({
+                "name": "bar",
+                "visibility": "public",
+                "purity": "pure",
+                "generics": [],
+                "parameters": [
+                    { "name": "_foo", "type": "str[5]", "is_reference": false, "is_mutable": false },
+                    { "name": "_baz", "type": "u32", "is_reference": false, "is_mutable": false },
+                ],
+                "return_type": "u64",
+                "selector": "bar(str[5],u32)",
+            })
+        );
+        // A generic function has no selector.
+        assert_eq!(
+            functions[1].to_signature_json(&engines),
+            serde_json::json!({
+                "name": "id",
+                "visibility": "private",
+                "purity": "pure",
+                "generics": [{ "name": "T", "bounds": ["A"] }],
+                "parameters": [
+                    { "name": "t", "type": "T", "is_reference": false, "is_mutable": false },
+                ],
+                "return_type": "T",
+                "selector": null,
+            })
+        );
+    }
+
+    #[test]
+    fn test_attributes_in_order() {
+        let engines = Engines::default();
+        let (functions, _) = compile_functions(
+            &engines,
+            "library;
+            #[inline(never)]
+            #[allow(dead_code)]
+            pub fn f() {}",
+        );
+
+        let attributes = functions[0]
+            .attributes
+            .in_order()
+            .into_iter()
+            .map(|(kind, attr)| (kind.clone(), attr.span.as_str().to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            attributes,
+            [
+                (
+                    transform::AttributeKind::Inline,
+                    "#[inline(never)]".to_string()
+                ),
+                (
+                    transform::AttributeKind::Allow,
+                    "#[allow(dead_code)]".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_function_diverges() {
+        let engines = Engines::default();
+        let (functions, _) = compile_functions(
+            &engines,
+            "library;
+            pub fn always_fails() -> u64 { __revert(0) }
+            pub fn fails_through_call() -> u64 { let x = always_fails(); x }
+            pub fn sometimes_fails(b: bool) -> u64 {
+                if b { return 1; }
+                __revert(0)
+            }
+            pub fn fails_on_both_branches(b: bool) -> u64 {
+                if b { __revert(1) } else { __revert(2) }
+            }",
+        );
+
+        let diverges = functions
+            .iter()
+            .map(|fn_decl| (fn_decl.name.as_str().to_string(), fn_decl.diverges))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            diverges,
+            [
+                ("always_fails".to_string(), true),
+                ("fails_through_call".to_string(), true),
+                ("sometimes_fails".to_string(), false),
+                ("fails_on_both_branches".to_string(), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_selector_override() {
+        let engines = Engines::default();
+        let (functions, warnings) = compile_functions(
+            &engines,
+            r#"contract;
+            abi A {
+                #[selector("old_name(u64)")]
+                fn new_name(x: u64) -> u64;
+                fn other(x: u64) -> u64;
+            }
+            impl A for Contract {
+                #[selector("old_name(u64)")]
+                fn new_name(x: u64) -> u64 { x }
+                fn other(x: u64) -> u64 { x }
+            }"#,
+        );
+        assert!(warnings.is_empty(), "{warnings:?}");
+
+        assert_eq!(
+            selectors(&engines, &functions),
+            ["old_name(u64)", "other(u64)"]
+        );
+    }
+
+    #[test]
+    fn test_abi_name_override() {
+        let engines = Engines::default();
+        let (functions, warnings) = compile_functions(
+            &engines,
+            r#"contract;
+            abi A {
+                #[abi_name("oldName")]
+                fn new_name(x: u64) -> u64;
+            }
+            impl A for Contract {
+                #[abi_name("oldName")]
+                fn new_name(x: u64) -> u64 { x }
+            }"#,
+        );
+        assert!(warnings.is_empty(), "{warnings:?}");
+        assert_eq!(selectors(&engines, &functions), ["oldName(u64)"]);
+
+        let (_, errors, _) = compile(
+            &engines,
+            r#"contract;
+            abi A {
+                #[abi_name("old name")]
+                fn new_name(x: u64) -> u64;
+            }"#,
+            None,
+        );
+        assert_eq!(
+            errors,
+            [
+                "\"old name\" is not a valid ABI name. The name must be an identifier given as a \
+                 string."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_abi_transparent_selector() {
+        let engines = Engines::default();
+        let (functions, _) = compile_functions(
+            &engines,
+            "contract;
+            #[abi_transparent]
+            struct Id { inner: u64 }
+            struct Point { x: u64 }
+            abi A {
+                fn by_id(id: Id);
+                fn by_point(point: Point);
+            }
+            impl A for Contract {
+                fn by_id(id: Id) {}
+                fn by_point(point: Point) {}
+            }",
+        );
+        assert_eq!(
+            selectors(&engines, &functions),
+            ["by_id(u64)", "by_point(s(u64))"]
+        );
+
+        let (_, errors, _) = compile(
+            &engines,
+            "library;
+            #[abi_transparent]
+            pub struct Pair { a: u64, b: u64 }",
+            None,
+        );
+        assert_eq!(
+            errors,
+            [
+                "Struct \"Pair\" has 2 fields, but only a struct with a single field can be \
+                 \"abi_transparent\"."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_constructor() {
+        let engines = Engines::default();
+        let (functions, _) = compile_functions(
+            &engines,
+            "library;
+            pub struct S { x: u64 }
+            impl S {
+                pub fn new() -> Self { S { x: 0 } }
+                pub fn get(self) -> u64 { self.x }
+                pub fn with_x(self, x: u64) -> Self { S { x } }
+            }",
+        );
+
+        let constructors = functions
+            .iter()
+            .map(|fn_decl| (fn_decl.name.as_str().to_string(), fn_decl.is_constructor))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            constructors,
+            [
+                ("new".to_string(), true),
+                ("get".to_string(), false),
+                ("with_x".to_string(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parameter_docs() {
+        let engines = Engines::default();
+        let (functions, _) = compile_functions(
+            &engines,
+            r#"library;
+            pub fn transfer(
+                /// The amount to transfer.
+                /// Must not be zero.
+                amount: u64,
+                #[doc = "The recipient."]
+                recipient: b256,
+                undocumented: bool,
+            ) {}"#,
+        );
+
+        let docs = functions[0]
+            .parameters
+            .iter()
+            .map(|param| param.doc_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            docs,
+            [
+                Some("The amount to transfer.\nMust not be zero.".to_string()),
+                Some("The recipient.".to_string()),
+                None,
+            ]
+        );
+
+        let (_, errors, _) = compile(
+            &engines,
+            "library; pub fn f(#[storage(read)] x: u64) {}",
+            None,
+        );
+        assert_eq!(
+            errors,
+            [
+                "The \"storage\" attribute is not allowed on function parameters. Parameters can \
+                 only have doc comments and \"doc\" attributes."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reentrancy_guard() {
+        let engines = Engines::default();
+        let (functions, warnings) = compile_functions(
+            &engines,
+            "contract;
+            abi A {
+                fn guarded();
+                fn unguarded();
+            }
+            impl A for Contract {
+                #[reentrancy(guard)]
+                fn guarded() {}
+                fn unguarded() {}
+            }",
+        );
+        assert!(warnings.is_empty(), "{warnings:?}");
+
+        let guards = functions
+            .iter()
+            .map(|fn_decl| (fn_decl.name.as_str().to_string(), fn_decl.reentrancy_guard))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            guards,
+            [
+                ("guarded".to_string(), true),
+                ("unguarded".to_string(), false)
+            ]
+        );
+
+        let (_, errors, _) = compile(
+            &engines,
+            "library; #[reentrancy(guard)] pub fn f() {}",
+            None,
+        );
+        assert_eq!(
+            errors,
+            [
+                "The \"reentrancy\" attribute is not allowed here. It can only be used on ABI \
+                 methods and their implementations."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fallback() {
+        let engines = Engines::default();
+        let (functions, _) = compile_functions(
+            &engines,
+            "contract;
+            abi A {
+                fn fallback();
+                fn other(x: u64);
+            }
+            impl A for Contract {
+                #[fallback]
+                fn fallback() {}
+                fn other(x: u64) {}
+            }",
+        );
+
+        let fallbacks = functions
+            .iter()
+            .map(|fn_decl| (fn_decl.name.as_str().to_string(), fn_decl.is_fallback))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fallbacks,
+            [("fallback".to_string(), true), ("other".to_string(), false)]
+        );
+
+        let (_, errors, _) = compile(
+            &engines,
+            "contract;
+            abi A {
+                fn other(x: u64);
+            }
+            impl A for Contract {
+                #[fallback]
+                fn other(x: u64) {}
+            }",
+            None,
+        );
+        assert_eq!(
+            errors,
+            ["Fallback method \"other\" cannot have parameters."]
+        );
+    }
+
+    #[test]
+    fn test_where_clause_referencing_return_type() {
+        let engines = Engines::default();
+        let (functions, _) = compile_functions(
+            &engines,
+            "library;
+            pub trait Double {
+                fn double(self) -> Self;
+            }
+            pub fn double<R>(r: R) -> R where R: Double {
+                r.double()
+            }
+            pub fn double_twice<T, R>(t: T, r: R) -> R where T: Double, R: Double {
+                let _t = t.double();
+                r.double().double()
+            }",
+        );
+
+        let bounds = functions
+            .iter()
+            .map(|fn_decl| {
+                let bounds = fn_decl
+                    .where_clause
+                    .iter()
+                    .map(|(name, bounds)| {
+                        (name.to_string(), bounds[0].trait_name.suffix.to_string())
+                    })
+                    .collect::<Vec<_>>();
+                (
+                    bounds,
+                    engines.help_out(fn_decl.return_type.type_id).to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bounds,
+            [
+                (
+                    vec![("R".to_string(), "Double".to_string())],
+                    "R".to_string()
+                ),
+                (
+                    vec![
+                        ("T".to_string(), "Double".to_string()),
+                        ("R".to_string(), "Double".to_string()),
+                    ],
+                    "R".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fn_name_rewriter() {
+        let engines = Engines::default();
+        let build_config = build_config()
+            .fn_name_rewriter(|name| Ident::new_with_override(format!("{name}_v2"), name.span()));
+        let (program, errors, warnings) = compile(
+            &engines,
+            r#"
+            library;
+            pub trait Bar { fn bar(self) -> u64; }
+            pub struct S {}
+            impl Bar for S { fn bar(self) -> u64 { foo(1) } }
+            pub fn foo(_x: u64) -> u64 { 1 }
+            pub fn baz() -> u64 { S {}.bar() }
+            "#,
+            Some(&build_config),
+        );
+        // The trait method and the calls are still resolved by the source names.
+        assert!(errors.is_empty(), "{errors:?}");
+        assert!(warnings.is_empty(), "{warnings:?}");
+
+        let functions = functions(&engines, &program.expect("test failure"));
+        let names = functions
+            .iter()
+            .map(|decl| {
+                (
+                    decl.name.as_str(),
+                    decl.symbol_name.as_ref().map(Ident::as_str),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                ("foo", Some("foo_v2")),
+                ("bar", Some("bar_v2")),
+                ("baz", Some("baz_v2")),
+            ]
+        );
+        assert_eq!(
+            selectors(&engines, &functions),
+            ["foo_v2(u64)", "bar_v2(s())", "baz_v2()"]
+        );
+    }
+
+    #[test]
+    fn test_called_functions() {
+        let engines = Engines::default();
+        let (functions, _) = compile_functions(
+            &engines,
+            "library;
+            fn a() -> u64 { 1 }
+            fn b(x: u64) -> u64 { x }
+            pub struct S {}
+            impl S {
+                fn c(self) -> u64 { 2 }
+            }
+            pub fn f(s: S) -> u64 {
+                let x = b(a());
+                let _y = s.c();
+                b(x)
+            }",
+        );
+
+        let f = functions
+            .into_iter()
+            .find(|fn_decl| fn_decl.name.as_str() == "f")
+            .expect("test failure");
+        let called_functions = f
+            .called_functions(&engines)
+            .iter()
+            .map(|call_path| call_path.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(called_functions, ["b", "a", "c"]);
+    }
+
+    #[test]
+    fn test_gas_hint() {
+        let compile_with = |attr: &str| {
+            let engines = Engines::default();
+            let (program, errors, _) =
+                compile(&engines, &format!("library; {attr} pub fn f() {{}}"), None);
+            let gas_hint = program.and_then(|program| functions(&engines, &program)[0].gas_hint);
+            (gas_hint, errors)
+        };
+
+        let (gas_hint, errors) = compile_with("#[gas_hint(100)]");
+        assert!(errors.is_empty(), "{errors:?}");
+        assert_eq!(gas_hint, Some(100));
+
+        let (gas_hint, errors) = compile_with("");
+        assert!(errors.is_empty(), "{errors:?}");
+        assert_eq!(gas_hint, None);
+
+        let (_, errors) = compile_with("#[gas_hint(0)]");
+        assert_eq!(errors, ["The gas hint \"0\" is not a positive integer."]);
+
+        let (_, errors) = compile_with("#[gas_hint(lots)]");
+        assert_eq!(errors, ["The gas hint \"lots\" is not a positive integer."]);
+
+        let (_, errors) = compile_with("#[gas_hint(1, 2)]");
+        assert_eq!(
+            errors,
+            ["The \"gas_hint\" attribute takes exactly one argument, but 2 were given."]
+        );
+
+        let (_, errors) = compile_with("#[gas_hint(100)] #[gas_hint(200)]");
+        assert_eq!(
+            errors,
+            ["The \"gas_hint\" attribute can only be used once."]
+        );
+    }
+
+    #[test]
+    fn test_cfg_attr() {
+        let compile_with = |attr: &str| {
+            let engines = Engines::default();
+            let (program, errors, warnings) =
+                compile(&engines, &format!("library; {attr} pub fn f() {{}}"), None);
+            assert!(errors.is_empty(), "{errors:?}");
+            let inline = functions(&engines, &program.expect("test failure"))[0].inline;
+            (inline, warnings)
+        };
+
+        let (inline, warnings) =
+            compile_with(r#"#[cfg_attr(program_type = "library", inline(always))]"#);
+        assert_eq!(inline, Some(Inline::Always));
+        assert!(warnings.is_empty(), "{warnings:?}");
+
+        let (inline, warnings) =
+            compile_with(r#"#[cfg_attr(program_type = "script", inline(always))]"#);
+        assert_eq!(inline, None);
+        assert!(warnings.is_empty(), "{warnings:?}");
+
+        // The attribute is validated even if the predicate doesn't hold.
+        let (inline, warnings) =
+            compile_with(r#"#[cfg_attr(program_type = "script", inline(always, never))]"#);
+        assert_eq!(inline, None);
+        assert_eq!(
+            warnings,
+            ["Attribute: \"inline\" expected exactly 1 argument(s) received 2."]
+        );
+    }
+
+    #[test]
+    fn test_is_pure() {
+        let engines = Engines::default();
+        let (functions, _) = compile_functions(
+            &engines,
+            "library;
+            pub fn pure() {}
+            #[storage(read)]
+            pub fn reads() {}
+            #[storage(write)]
+            pub fn writes() {}
+            #[storage(read, write)]
+            pub fn reads_writes() {}",
+        );
+
+        let is_pure = functions
+            .iter()
+            .map(|fn_decl| (fn_decl.name.as_str().to_string(), fn_decl.is_pure()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            is_pure,
+            [
+                ("pure".to_string(), true),
+                ("reads".to_string(), false),
+                ("writes".to_string(), false),
+                ("reads_writes".to_string(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_storage_access() {
+        let engines = Engines::default();
+        let handler = Handler::default();
+        let compile_with = |src: &str, initial_namespace: namespace::Module| {
+            crate::compile_to_ast(
+                &handler,
+                &engines,
+                Arc::from(src),
+                initial_namespace,
+                None,
+                "test",
+                &mut sway_utils::PerformanceData::default(),
+            )
+            .ok()
+            .and_then(|programs| programs.typed)
+            .expect("test failure")
+        };
+
+        // A storage access is typed as a `core::storage::StorageKey`, so a stand-in for it is
+        // put in the namespace.
+        let storage_lib = compile_with(
+            "library; pub struct StorageKey<T> { slot: b256, offset: u64, field_id: b256 }",
+            namespace::Module::default(),
+        );
+        let mut storage =
+            namespace::Module::from(namespace::Root::from(storage_lib.root.namespace));
+        storage.visibility = Visibility::Public;
+        let mut core = namespace::Module::default();
+        core.visibility = Visibility::Public;
+        core.insert_submodule("storage".to_string(), storage);
+        let mut initial_namespace = namespace::Module::default();
+        initial_namespace.insert_submodule("core".to_string(), core);
+
+        let program = compile_with(
+            "contract;
+            use core::storage::StorageKey;
+            abi A {
+                #[storage(read)]
+                fn get() -> u64;
+                #[storage(read, write)]
+                fn set();
+                fn zero() -> u64;
+            }
+            storage {
+                x: u64 = 0,
+                y: u64 = 0,
+            }
+            #[storage(write)]
+            fn write(_key: StorageKey<u64>) {}
+            impl A for Contract {
+                #[storage(read)]
+                fn get() -> u64 {
+                    let _x = storage.x;
+                    0
+                }
+                #[storage(read, write)]
+                fn set() {
+                    write(storage.y);
+                }
+                fn zero() -> u64 {
+                    0
+                }
+            }",
+            initial_namespace,
+        );
+        let (errors, _) = handler.consume();
+        assert!(errors.is_empty(), "{errors:?}");
+
+        // A storage field passed to a function is accessed as the function declares.
+        let field_paths = |paths: &BTreeSet<Vec<Ident>>| {
+            paths
+                .iter()
+                .map(|path| path.iter().map(Ident::as_str).collect::<Vec<_>>().join("."))
+                .collect::<Vec<_>>()
+        };
+        let storage_access = functions(&engines, &program)
+            .iter()
+            .map(|fn_decl| {
+                let storage_access = fn_decl
+                    .storage_access
+                    .as_ref()
+                    .map(|access| (field_paths(&access.reads), field_paths(&access.writes)));
+                (fn_decl.name.to_string(), storage_access)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            storage_access,
+            [
+                ("write".to_string(), Some((vec![], vec![]))),
+                ("get".to_string(), Some((vec!["x".to_string()], vec![]))),
+                ("set".to_string(), Some((vec![], vec!["y".to_string()]))),
+                ("zero".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_return_sites() {
+        let engines = Engines::default();
+        let (functions, _) = compile_functions(
+            &engines,
+            "library;
+            pub fn f(a: bool, b: bool) -> u64 {
+                if a {
+                    return 1;
+                }
+                if b {
+                    return 2;
+                }
+                3
+            }",
+        );
+
+        let return_sites = functions[0].return_sites();
+        assert_eq!(
+            return_sites
+                .iter()
+                .map(|span| span.as_str())
+                .collect::<Vec<_>>(),
+            ["return 1", "return 2", "3"]
+        );
+    }
+
+    #[test]
+    fn test_type_alias_selector() {
+        let engines = Engines::default();
+        let (functions, _) = compile_functions(
+            &engines,
+            "contract;
+            type MyU64Alias = u64;
+            type Nested = (MyU64Alias, [MyU64Alias; 2]);
+            abi A {
+                fn f() -> MyU64Alias;
+                fn g(x: MyU64Alias, y: Nested) -> MyU64Alias;
+            }
+            impl A for Contract {
+                fn f() -> MyU64Alias { 0 }
+                fn g(x: MyU64Alias, y: Nested) -> MyU64Alias { x }
+            }",
+        );
+
+        assert_eq!(
+            selectors(&engines, &functions),
+            ["f()", "g(u64,(u64,a[u64;2]))"]
+        );
+    }
+}
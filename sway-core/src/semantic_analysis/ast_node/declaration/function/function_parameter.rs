@@ -1,4 +1,5 @@
 use crate::{
+    ir_generation::const_eval::evaluate_constant_expression,
     language::{parsed::FunctionParameter, ty},
     semantic_analysis::TypeCheckContext,
     type_system::*,
@@ -8,7 +9,9 @@ use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
 };
-use sway_types::Spanned;
+use sway_types::{Ident, Span, Spanned};
+
+use std::collections::HashMap;
 
 impl ty::TyFunctionParameter {
     pub(crate) fn type_check(
@@ -25,16 +28,20 @@ impl ty::TyFunctionParameter {
             is_mutable,
             mutability_span,
             mut type_argument,
+            default_value,
+            attributes,
         } = parameter;
 
-        type_argument.type_id = ctx
-            .resolve_type_with_self(
-                handler,
-                type_argument.type_id,
-                &type_argument.span,
-                EnforceTypeArguments::Yes,
-                None,
-            )
+        type_argument.type_id = check_bare_trait(handler, &ctx, &type_argument)
+            .and_then(|_| {
+                ctx.resolve_type_with_self(
+                    handler,
+                    type_argument.type_id,
+                    &type_argument.span,
+                    EnforceTypeArguments::Yes,
+                    None,
+                )
+            })
             .unwrap_or_else(|_| type_engine.insert(engines, TypeInfo::ErrorRecovery));
 
         type_argument.type_id.check_type_parameter_bounds(
@@ -44,6 +51,33 @@ impl ty::TyFunctionParameter {
             vec![],
         )?;
 
+        // The default value is copied into every call site which omits the argument, so it must
+        // be a constant expression, just like the value of a `const` declaration.
+        let default_value = match default_value {
+            Some(default_value) => {
+                let default_value = ty::TyExpression::type_check(
+                    handler,
+                    ctx.by_ref()
+                        .with_help_text(
+                            "The default value of this parameter does not match its declared type.",
+                        )
+                        .with_type_annotation(type_argument.type_id),
+                    default_value,
+                )?;
+                if evaluate_constant_expression(engines, ctx.namespace.module(), &default_value)
+                    .is_err()
+                {
+                    return Err(handler.emit_err(CompileError::CannotBeEvaluatedToConst {
+                        span: default_value.span,
+                    }));
+                }
+                Some(default_value)
+            }
+            None => None,
+        };
+
+        check_receiver(handler, &name, is_reference, is_mutable, &mutability_span)?;
+
         let mutability = ty::VariableMutability::new_from_ref_mut(is_reference, is_mutable);
         if mutability == ty::VariableMutability::Mutable {
             return Err(
@@ -60,6 +94,8 @@ impl ty::TyFunctionParameter {
             is_mutable,
             mutability_span,
             type_argument,
+            default_value,
+            attributes,
         };
 
         insert_into_namespace(handler, ctx, &typed_parameter);
@@ -67,6 +103,24 @@ impl ty::TyFunctionParameter {
         Ok(typed_parameter)
     }
 
+    /// Emits an error if the name of `parameter` is already bound by a previous parameter of the
+    /// same function, otherwise records it in `parameter_names`.
+    pub(crate) fn check_duplicate_name(
+        handler: &Handler,
+        parameter_names: &mut HashMap<Ident, Span>,
+        parameter: &FunctionParameter,
+    ) -> Result<(), ErrorEmitted> {
+        if let Some(first_span) = parameter_names.get(&parameter.name) {
+            return Err(handler.emit_err(CompileError::DuplicateParameterName {
+                name: parameter.name.clone(),
+                first_span: first_span.clone(),
+                duplicate_span: parameter.name.span(),
+            }));
+        }
+        parameter_names.insert(parameter.name.clone(), parameter.name.span());
+        Ok(())
+    }
+
     pub(crate) fn type_check_interface_parameter(
         handler: &Handler,
         mut ctx: TypeCheckContext,
@@ -81,16 +135,32 @@ impl ty::TyFunctionParameter {
             is_mutable,
             mutability_span,
             mut type_argument,
+            default_value,
+            attributes,
         } = parameter;
 
-        type_argument.type_id = ctx
-            .resolve_type_with_self(
-                handler,
-                type_argument.type_id,
-                &type_argument.span,
-                EnforceTypeArguments::Yes,
-                None,
-            )
+        // An invalid receiver doesn't prevent the implementations from being checked against
+        // the interface.
+        let _ = check_receiver(handler, &name, is_reference, is_mutable, &mutability_span);
+
+        // Interface methods are called through their implementations, or through a contract
+        // call, neither of which can see a default declared here.
+        if let Some(default_value) = default_value {
+            handler.emit_err(CompileError::DefaultParameterValueNotAllowed {
+                span: default_value.span,
+            });
+        }
+
+        type_argument.type_id = check_bare_trait(handler, &ctx, &type_argument)
+            .and_then(|_| {
+                ctx.resolve_type_with_self(
+                    handler,
+                    type_argument.type_id,
+                    &type_argument.span,
+                    EnforceTypeArguments::Yes,
+                    None,
+                )
+            })
             .unwrap_or_else(|_| type_engine.insert(engines, TypeInfo::ErrorRecovery));
 
         let typed_parameter = ty::TyFunctionParameter {
@@ -99,12 +169,59 @@ impl ty::TyFunctionParameter {
             is_mutable,
             mutability_span,
             type_argument,
+            default_value: None,
+            attributes,
         };
 
         Ok(typed_parameter)
     }
 }
 
+/// Rejects a parameter whose type names a trait, as in `x: SomeTrait`. Traits aren't sized
+/// types, the parameter most likely meant to take any type implementing the trait.
+fn check_bare_trait(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    type_argument: &TypeArgument,
+) -> Result<(), ErrorEmitted> {
+    let call_path = match ctx.engines.te().get(type_argument.type_id) {
+        TypeInfo::Custom {
+            call_path,
+            type_arguments: None,
+        } => call_path,
+        _ => return Ok(()),
+    };
+    let resolved = ctx
+        .namespace
+        .resolve_call_path(&Handler::default(), &call_path)
+        .ok();
+    if let Some(ty::TyDecl::TraitDecl(_)) = resolved {
+        return Err(handler.emit_err(CompileError::BareTraitParameter {
+            help: format!("did you mean `impl {}`?", call_path.suffix),
+            trait_name: call_path.suffix,
+            span: type_argument.span.clone(),
+        }));
+    }
+    Ok(())
+}
+
+/// Rejects `mut self`. A receiver is either taken by value, `self`, or by mutable reference,
+/// `ref mut self`.
+fn check_receiver(
+    handler: &Handler,
+    name: &Ident,
+    is_reference: bool,
+    is_mutable: bool,
+    mutability_span: &Span,
+) -> Result<(), ErrorEmitted> {
+    if name.as_str() == "self" && is_mutable && !is_reference {
+        return Err(handler.emit_err(CompileError::MutableSelfWithoutRef {
+            span: Span::join(mutability_span.clone(), name.span()),
+        }));
+    }
+    Ok(())
+}
+
 fn insert_into_namespace(
     handler: &Handler,
     ctx: TypeCheckContext,
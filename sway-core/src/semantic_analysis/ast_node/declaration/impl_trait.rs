@@ -43,7 +43,7 @@ impl ty::TyImplTrait {
             .by_ref()
             .scoped(&mut impl_namespace)
             .with_const_shadowing_mode(ConstShadowingMode::ItemStyle)
-            .allow_functions();
+            .leave_function_body();
 
         // Type check the type parameters. This will also insert them into the
         // current namespace.
@@ -187,6 +187,24 @@ impl ty::TyImplTrait {
                     &block_span,
                     true,
                 )?;
+
+                // Calls that match no selector are routed to the fallback method, so there can
+                // be only one.
+                let fallbacks = new_items
+                    .iter()
+                    .filter_map(|item| match item {
+                        ty::TyTraitItem::Fn(decl_ref) => Some(decl_engine.get_function(decl_ref)),
+                        _ => None,
+                    })
+                    .filter(|method| method.is_fallback)
+                    .collect::<Vec<_>>();
+                for method in fallbacks.iter().skip(1) {
+                    handler.emit_err(CompileError::MultipleFallbacks {
+                        first: fallbacks[0].name.clone(),
+                        span: method.name.span(),
+                    });
+                }
+
                 ty::TyImplTrait {
                     impl_type_parameters: vec![], // this is empty because abi definitions don't support generics
                     trait_name,
@@ -228,7 +246,7 @@ impl ty::TyImplTrait {
         let mut ctx = ctx
             .scoped(&mut impl_namespace)
             .with_const_shadowing_mode(ConstShadowingMode::ItemStyle)
-            .allow_functions();
+            .leave_function_body();
 
         // create the trait name
         let trait_name = CallPath {
@@ -529,25 +547,44 @@ fn type_check_trait_implementation(
             .map(|type_arg| type_arg.type_id)
             .collect(),
     );
+    //
+    // The methods are copied first, so that the calls between them, which are
+    // made through the stubs of the methods, are redirected to the copies as
+    // well.
+    let mut copied_item_refs: ItemMap = BTreeMap::new();
+    for item in trait_items.iter() {
+        if let TyImplItem::Fn(decl_ref) = item {
+            let copy_ref = decl_engine
+                .insert(decl_engine.get_function(decl_ref))
+                .with_parent(decl_engine, (*decl_ref.id()).into());
+            copied_item_refs.insert(decl_ref.name().clone(), TyTraitItem::Fn(copy_ref));
+        }
+    }
     interface_item_refs.extend(supertrait_interface_item_refs);
     impld_item_refs.extend(supertrait_impld_item_refs);
+    for (name, copy_ref) in copied_item_refs.iter() {
+        impld_item_refs
+            .entry(name.clone())
+            .or_insert_with(|| copy_ref.clone());
+    }
     let decl_mapping = DeclMapping::from_interface_and_item_and_impld_decl_refs(
         interface_item_refs,
-        BTreeMap::new(),
+        provided_method_stubs(engines, trait_items),
         impld_item_refs,
     );
     for item in trait_items.iter() {
         match item {
             TyImplItem::Fn(decl_ref) => {
-                let mut method = decl_engine.get_function(decl_ref);
+                let copy_ref = match copied_item_refs.get(decl_ref.name()) {
+                    Some(TyTraitItem::Fn(copy_ref)) => copy_ref.clone(),
+                    _ => continue,
+                };
+                let mut method = decl_engine.get_function(&copy_ref);
                 method.replace_decls(&decl_mapping, engines);
                 method.subst(&type_mapping, engines);
                 method.replace_self_type(engines, ctx.self_type());
-                all_items_refs.push(TyImplItem::Fn(
-                    decl_engine
-                        .insert(method)
-                        .with_parent(decl_engine, (*decl_ref.id()).into()),
-                ));
+                decl_engine.replace(*copy_ref.id(), method);
+                all_items_refs.push(TyImplItem::Fn(copy_ref));
             }
             TyImplItem::Constant(decl_ref) => {
                 let mut const_decl = decl_engine.get_constant(decl_ref);
@@ -596,6 +633,60 @@ fn type_check_trait_implementation(
 }
 
 #[allow(clippy::too_many_arguments)]
+/// Returns the stubs through which the provided methods in `trait_items` call
+/// each other, by the name of the method they stand for.
+///
+/// The stubs are keyed instead of the methods themselves, because the copies
+/// of the methods in the other impls of the trait descend from the methods too.
+/// Unlike those copies, the stubs don't have an implementing type.
+fn provided_method_stubs(engines: &Engines, trait_items: &[TyImplItem]) -> ItemMap {
+    let decl_engine = engines.de();
+    let methods: HashMap<Ident, AssociatedItemDeclId> = trait_items
+        .iter()
+        .filter_map(|item| match item {
+            TyImplItem::Fn(decl_ref) => Some((decl_ref.name().clone(), (*decl_ref.id()).into())),
+            TyImplItem::Constant(_) => None,
+        })
+        .collect();
+    let mut stub_refs: ItemMap = BTreeMap::new();
+    for item in trait_items.iter() {
+        let method = match item {
+            TyImplItem::Fn(decl_ref) => decl_engine.get_function(decl_ref),
+            TyImplItem::Constant(_) => continue,
+        };
+        method.body.visit_expressions(&mut |expr| {
+            let fn_ref = match &expr.expression {
+                ty::TyExpressionVariant::FunctionApplication { fn_ref, .. } => fn_ref,
+                _ => return,
+            };
+            for parent in decl_engine.find_all_parents(engines, fn_ref.id()) {
+                let parent_id = match parent {
+                    AssociatedItemDeclId::Function(parent_id) => parent_id,
+                    _ => continue,
+                };
+                let parent_decl = decl_engine.get_function(&parent_id);
+                let is_stub = parent_decl.implementing_type.is_none()
+                    && methods.get(&parent_decl.name).map_or(false, |method_id| {
+                        decl_engine
+                            .find_all_parents(engines, &parent_id)
+                            .contains(method_id)
+                    });
+                if is_stub {
+                    stub_refs.insert(
+                        parent_decl.name.clone(),
+                        TyTraitItem::Fn(DeclRef::new(
+                            parent_decl.name.clone(),
+                            parent_id,
+                            parent_decl.span.clone(),
+                        )),
+                    );
+                }
+            }
+        });
+    }
+    stub_refs
+}
+
 fn type_check_impl_method(
     handler: &Handler,
     mut ctx: TypeCheckContext,
@@ -774,6 +865,27 @@ fn type_check_impl_method(
         (true, true) | (false, false) => (), // no payability mismatch
     }
 
+    // check that the method signature and the method implementation
+    // agree on the selector, as callers only see the signature
+    if impl_method_signature.attributes.selector_override()
+        != impl_method.attributes.selector_override()
+    {
+        error_emitted = Some(handler.emit_err(CompileError::TraitImplSelectorMismatch {
+            fn_name: impl_method.name.clone(),
+            interface_name: interface_name(),
+            span: impl_method.span.clone(),
+        }));
+    }
+    if impl_method_signature.attributes.abi_name_override()
+        != impl_method.attributes.abi_name_override()
+    {
+        error_emitted = Some(handler.emit_err(CompileError::TraitImplAbiNameMismatch {
+            fn_name: impl_method.name.clone(),
+            interface_name: interface_name(),
+            span: impl_method.span.clone(),
+        }));
+    }
+
     if !type_engine.get(impl_method.return_type.type_id).eq(
         &type_engine.get(impl_method_signature.return_type.type_id),
         engines,
@@ -1,8 +1,12 @@
-use sway_error::handler::{ErrorEmitted, Handler};
+use sway_error::{
+    error::CompileError,
+    handler::{ErrorEmitted, Handler},
+};
 
 use crate::{
     language::{parsed::*, ty, CallPath},
     semantic_analysis::*,
+    transform,
     type_system::*,
 };
 
@@ -37,6 +41,19 @@ impl ty::TyStructDecl {
             new_fields.push(ty::TyStructField::type_check(handler, ctx.by_ref(), field)?);
         }
 
+        // A transparent struct is encoded as its field, which only works if it has exactly one.
+        if let Some(attrs) = attributes.get(&transform::AttributeKind::AbiTransparent) {
+            if new_fields.len() != 1 {
+                for attr in attrs {
+                    handler.emit_err(CompileError::AbiTransparentStructFieldCount {
+                        name: name.clone(),
+                        field_count: new_fields.len(),
+                        span: attr.span.clone(),
+                    });
+                }
+            }
+        }
+
         let mut path: CallPath = name.into();
         path = path.to_fullpath(ctx.namespace);
 
@@ -118,6 +118,31 @@ impl ty::TyTraitDecl {
             }
         }
 
+        // The provided methods may call each other regardless of their order, so their
+        // signatures are made available along with the interface surface. Their errors are
+        // reported when the methods themselves are type checked.
+        let mut method_stubs = vec![];
+        for method in methods.iter() {
+            if !method.type_parameters.is_empty() || ids.contains(&method.name) {
+                continue;
+            }
+            let signature = TraitFn {
+                name: method.name.clone(),
+                span: method.span.clone(),
+                attributes: method.attributes.clone(),
+                purity: method.purity,
+                parameters: method.parameters.clone(),
+                return_type: method.return_type.clone(),
+            };
+            if let Ok(signature) =
+                ty::TyTraitFn::type_check(&Handler::default(), ctx.by_ref(), signature)
+            {
+                let stub_ref = decl_engine.insert(signature.to_dummy_func(AbiMode::NonAbi));
+                method_stubs.push(stub_ref.clone());
+                dummy_interface_surface.push(ty::TyImplItem::Fn(stub_ref));
+            }
+        }
+
         // insert placeholder functions representing the interface surface
         // to allow methods to use those functions
         ctx.namespace.insert_trait_implementation(
@@ -136,13 +161,23 @@ impl ty::TyTraitDecl {
             engines,
         )?;
 
-        // Type check the items.
+        // Type check the items. The calls to the stub of a method are redirected to the
+        // method, and to its copies in the impls of the trait.
         let mut new_items = vec![];
         for method in methods.into_iter() {
             let method =
                 ty::TyFunctionDecl::type_check(handler, ctx.by_ref(), method.clone(), true, false)
                     .unwrap_or_else(|_| ty::TyFunctionDecl::error(method));
-            new_items.push(ty::TyTraitItem::Fn(decl_engine.insert(method)));
+            let method_ref = decl_engine.insert(method);
+            for stub_ref in method_stubs
+                .iter()
+                .filter(|stub_ref| stub_ref.name() == method_ref.name())
+            {
+                stub_ref
+                    .clone()
+                    .with_parent(decl_engine, (*method_ref.id()).into());
+            }
+            new_items.push(ty::TyTraitItem::Fn(method_ref));
         }
 
         let typed_trait_decl = ty::TyTraitDecl {
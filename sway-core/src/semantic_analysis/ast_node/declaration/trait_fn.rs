@@ -1,16 +1,25 @@
+use std::collections::HashMap;
+
 use sway_types::{Span, Spanned};
 
 use crate::{
     decl_engine::DeclId,
     language::{parsed, ty, Visibility},
+    transform,
+};
+use sway_error::{
+    error::CompileError,
+    handler::{ErrorEmitted, Handler},
+    warning::{CompileWarning, Warning},
 };
-use sway_error::handler::{ErrorEmitted, Handler};
 
 use crate::{
     semantic_analysis::{AbiMode, TypeCheckContext},
     type_system::*,
 };
 
+use super::function::{type_check_abi_name_attribute, type_check_selector_attribute};
+
 impl ty::TyTraitFn {
     pub(crate) fn type_check(
         handler: &Handler,
@@ -29,6 +38,33 @@ impl ty::TyTraitFn {
         let type_engine = ctx.engines.te();
         let engines = ctx.engines();
 
+        // Inlining only applies to functions with a body.
+        for attr in attributes
+            .get(&transform::AttributeKind::Inline)
+            .into_iter()
+            .flatten()
+        {
+            handler.emit_warn(CompileWarning {
+                span: attr.span.clone(),
+                warning_content: Warning::InlineAttributeWithoutBody,
+            });
+        }
+
+        // Only the methods of an `abi` can be called with coins.
+        if !matches!(ctx.abi_mode(), AbiMode::ImplAbiFn(..)) {
+            for attr in attributes
+                .get(&transform::AttributeKind::Payable)
+                .into_iter()
+                .flatten()
+            {
+                handler.emit_err(CompileError::InvalidAttributeOnItem {
+                    attribute: "payable".to_owned(),
+                    allowed_on: "ABI methods and their implementations".to_owned(),
+                    span: attr.span.clone(),
+                });
+            }
+        }
+
         // Create a namespace for the trait function.
         let mut fn_namespace = ctx.namespace.clone();
         let mut ctx = ctx.by_ref().scoped(&mut fn_namespace).with_purity(purity);
@@ -37,7 +73,13 @@ impl ty::TyTraitFn {
 
         // Type check the parameters.
         let mut typed_parameters = vec![];
+        let mut parameter_names = HashMap::new();
         for param in parameters.into_iter() {
+            if ty::TyFunctionParameter::check_duplicate_name(handler, &mut parameter_names, &param)
+                .is_err()
+            {
+                continue;
+            }
             typed_parameters.push(
                 match ty::TyFunctionParameter::type_check_interface_parameter(
                     handler,
@@ -50,6 +92,9 @@ impl ty::TyTraitFn {
             );
         }
 
+        type_check_selector_attribute(handler, &ctx, &attributes, &typed_parameters);
+        type_check_abi_name_attribute(handler, &ctx, &attributes);
+
         // Type check the return type.
         return_type.type_id = ctx
             .resolve_type_with_self(
@@ -79,7 +124,16 @@ impl ty::TyTraitFn {
     pub(crate) fn to_dummy_func(&self, abi_mode: AbiMode) -> ty::TyFunctionDecl {
         ty::TyFunctionDecl {
             purity: self.purity,
+            storage_access: None,
+            diverges: false,
+            is_constructor: false,
+            reentrancy_guard: false,
+            is_fallback: false,
+            complexity: 1,
+            gas_hint: None,
+            is_const: false,
             name: self.name.clone(),
+            symbol_name: None,
             body: ty::TyCodeBlock { contents: vec![] },
             parameters: self.parameters.clone(),
             implementing_type: match abi_mode.clone() {
@@ -100,6 +154,7 @@ impl ty::TyTraitFn {
             attributes: self.attributes.clone(),
             return_type: self.return_type.clone(),
             visibility: Visibility::Public,
+            inline: None,
             type_parameters: vec![],
             is_contract_call: matches!(abi_mode, AbiMode::ImplAbiFn(..)),
             where_clause: vec![],
@@ -1,4 +1,7 @@
-use sway_error::handler::{ErrorEmitted, Handler};
+use sway_error::{
+    error::CompileError,
+    handler::{ErrorEmitted, Handler},
+};
 use sway_types::Spanned;
 
 use crate::{
@@ -68,14 +71,28 @@ impl ty::TyMatchBranch {
         };
 
         // unify the return type from the typed result with the type annotation
+        let mut result_return_type = typed_result.return_type;
         if !typed_result.deterministically_aborts(decl_engine, true) {
             let (warnings, errors) =
                 ctx.unify_with_self(typed_result.return_type, &typed_result.span);
             for warn in warnings {
                 handler.emit_warn(warn);
             }
-            for err in errors {
-                handler.emit_err(err);
+            // The arms of a match which is the implicit return of a function are returned from
+            // the function, so a mismatching arm is reported like a mismatching `return`.
+            if !errors.is_empty() && ctx.function_return_type() == Some(ctx.type_annotation()) {
+                handler.emit_err(CompileError::ReturnTypeMismatch {
+                    expected: engines.help_out(ctx.type_annotation()).to_string(),
+                    found: engines.help_out(typed_result.return_type).to_string(),
+                    span: branch_span.clone(),
+                });
+                // The arm is already reported, the `if` expression which the match desugars into
+                // must not report it again.
+                result_return_type = type_engine.insert(engines, TypeInfo::ErrorRecovery);
+            } else {
+                for err in errors {
+                    handler.emit_err(err);
+                }
             }
         }
 
@@ -110,7 +127,7 @@ impl ty::TyMatchBranch {
             expression: ty::TyExpressionVariant::CodeBlock(ty::TyCodeBlock {
                 contents: code_block_contents,
             }),
-            return_type: typed_result.return_type,
+            return_type: result_return_type,
             span: typed_result_span,
         };
 
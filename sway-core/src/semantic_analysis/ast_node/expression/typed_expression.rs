@@ -27,6 +27,7 @@ use crate::{
     semantic_analysis::*,
     transform::to_parsed_lang::type_name_to_type_info_opt,
     type_system::*,
+    types::DeterministicallyAborts,
     Engines,
 };
 
@@ -158,6 +159,10 @@ impl ty::TyExpression {
                     span,
                 )
             }
+            // Named arguments are matched with the parameters of the function they are passed to
+            // before being type checked, so they are not valid anywhere else.
+            ExpressionKind::NamedArgument(_) => Err(handler
+                .emit_err(CompileError::NamedArgumentOutsideOfFunctionCall { span: span.clone() })),
             ExpressionKind::LazyOperator(LazyOperatorExpression { op, lhs, rhs }) => {
                 let ctx = ctx
                     .by_ref()
@@ -358,6 +363,13 @@ impl ty::TyExpression {
                 };
                 Ok(typed_expr)
             }
+            ExpressionKind::Try(expr) => Self::type_check_try(handler, ctx.by_ref(), *expr, span),
+            ExpressionKind::TryBlock(contents) => {
+                Self::type_check_try_block(handler, ctx.by_ref(), contents, span)
+            }
+            ExpressionKind::LetElseBlock(contents) => {
+                Self::type_check_let_else_block(handler, ctx.by_ref(), contents, span)
+            }
         };
         let mut typed_expression = match res {
             Ok(r) => r,
@@ -489,6 +501,13 @@ impl ty::TyExpression {
                 });
                 ty::TyExpression::error(name.span(), engines)
             }
+            None if ctx.namespace.is_enclosing_fn_local(&name) => {
+                handler.emit_err(CompileError::NestedFunctionCapturesLocal {
+                    name: name.clone(),
+                    span,
+                });
+                ty::TyExpression::error(name.span(), engines)
+            }
             None => {
                 handler.emit_err(CompileError::UnknownVariable {
                     var_name: name.clone(),
@@ -689,6 +708,386 @@ impl ty::TyExpression {
         Ok(match_exp)
     }
 
+    fn type_check_try(
+        handler: &Handler,
+        mut ctx: TypeCheckContext,
+        value: Expression,
+        span: Span,
+    ) -> Result<ty::TyExpression, ErrorEmitted> {
+        let type_engine = ctx.engines.te();
+        let decl_engine = ctx.engines.de();
+        let engines = ctx.engines();
+
+        // The enums the `?` operator works on, given by their full call paths, along with their
+        // "continue" and "break" variants. Other enums of the same names don't qualify.
+        const TRY_TYPES: &[(&[&str], &str, &str, &str)] = &[
+            (&["std", "result"], "Result", "Ok", "Err"),
+            (&["std", "option"], "Option", "Some", "None"),
+        ];
+
+        // type check the value
+        let typed_value = {
+            let ctx = ctx
+                .by_ref()
+                .with_help_text("")
+                .with_type_annotation(type_engine.insert(engines, TypeInfo::Unknown));
+            ty::TyExpression::type_check(handler, ctx, value.clone())
+                .unwrap_or_else(|_| ty::TyExpression::error(value.span(), engines))
+        };
+
+        // Within a `try` block, the `?` operator short-circuits to the end of the block.
+        let return_type = match ctx.try_block_type().or(ctx.function_return_type()) {
+            Some(return_type) => return_type,
+            None => {
+                return Err(
+                    handler.emit_err(CompileError::TryOperatorInvalidReturnType {
+                        return_type: "()".to_string(),
+                        span,
+                    }),
+                )
+            }
+        };
+        if matches!(type_engine.get(return_type), TypeInfo::ErrorRecovery)
+            || matches!(
+                type_engine.get(typed_value.return_type),
+                TypeInfo::ErrorRecovery
+            )
+        {
+            return Ok(ty::TyExpression::error(span, engines));
+        }
+
+        // Splits a try type into its declaration, its name, and its two variants.
+        let as_try_type = |type_id: TypeId| match type_engine.get(type_id) {
+            TypeInfo::Enum(decl_ref) => {
+                let enum_decl = decl_engine.get_enum(&decl_ref);
+                let variant = |name: &str| {
+                    enum_decl
+                        .variants
+                        .iter()
+                        .find(|variant| variant.name.as_str() == name)
+                        .cloned()
+                };
+                TRY_TYPES
+                    .iter()
+                    .find(|(prefixes, try_type, _, _)| {
+                        enum_decl.call_path.suffix.as_str() == *try_type
+                            && enum_decl
+                                .call_path
+                                .prefixes
+                                .iter()
+                                .map(Ident::as_str)
+                                .eq(prefixes.iter().copied())
+                    })
+                    .and_then(|(_, try_type, continue_variant, break_variant)| {
+                        Some((
+                            decl_ref.clone(),
+                            *try_type,
+                            variant(continue_variant)?,
+                            variant(break_variant)?,
+                        ))
+                    })
+            }
+            _ => None,
+        };
+
+        let (return_enum_ref, return_try_type, _, return_break_variant) =
+            match as_try_type(return_type) {
+                Some(try_type) => try_type,
+                None => {
+                    return Err(
+                        handler.emit_err(CompileError::TryOperatorInvalidReturnType {
+                            return_type: engines.help_out(return_type).to_string(),
+                            span,
+                        }),
+                    )
+                }
+            };
+        let type_mismatch = || CompileError::TryOperatorTypeMismatch {
+            expected: engines.help_out(return_type).to_string(),
+            found: engines.help_out(typed_value.return_type).to_string(),
+            span: span.clone(),
+        };
+        let (value_enum_ref, _, value_continue_variant, value_break_variant) =
+            match as_try_type(typed_value.return_type) {
+                Some(try_type) if try_type.1 == return_try_type => try_type,
+                _ => return Err(handler.emit_err(type_mismatch())),
+            };
+
+        // the value carried by the break variant, if any, must fit the one of the return type
+        let contents = if return_try_type == "Result" {
+            let (warnings, errors) = type_engine.unify(
+                engines,
+                value_break_variant.type_argument.type_id,
+                return_break_variant.type_argument.type_id,
+                &span,
+                "",
+                None,
+            );
+            for warn in warnings {
+                handler.emit_warn(warn);
+            }
+            if !errors.is_empty() {
+                return Err(handler.emit_err(type_mismatch()));
+            }
+            let (_, break_value) = instantiate_unsafe_downcast(
+                engines,
+                &typed_value,
+                value_break_variant.clone(),
+                value_enum_ref.clone().into(),
+                span.clone(),
+            );
+            Some(Box::new(break_value))
+        } else {
+            None
+        };
+
+        // desugar `value?` to
+        // `if value is break { return break(contents) } else { value as continue }`
+        let (_, continue_value) = instantiate_unsafe_downcast(
+            engines,
+            &typed_value,
+            value_continue_variant,
+            value_enum_ref.into(),
+            span.clone(),
+        );
+        let tag = ty::TyExpression {
+            expression: ty::TyExpressionVariant::EnumTag {
+                exp: Box::new(typed_value.clone()),
+            },
+            return_type: type_engine
+                .insert(engines, TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)),
+            span: span.clone(),
+        };
+        let break_tag = ty::TyExpression {
+            expression: ty::TyExpressionVariant::Literal(Literal::U64(
+                value_break_variant.tag as u64,
+            )),
+            return_type: type_engine
+                .insert(engines, TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)),
+            span: span.clone(),
+        };
+        let condition = ty::TyExpression {
+            expression: ty::TyExpressionVariant::IntrinsicFunction(ty::TyIntrinsicFunctionKind {
+                kind: Intrinsic::Eq,
+                arguments: vec![tag, break_tag],
+                type_arguments: vec![],
+                span: span.clone(),
+            }),
+            return_type: type_engine.insert(engines, TypeInfo::Boolean),
+            span: span.clone(),
+        };
+        let break_instantiation = ty::TyExpression {
+            expression: ty::TyExpressionVariant::EnumInstantiation {
+                enum_ref: return_enum_ref.clone(),
+                variant_name: return_break_variant.name.clone(),
+                tag: return_break_variant.tag,
+                contents,
+                variant_instantiation_span: span.clone(),
+                call_path_binding: TypeBinding {
+                    inner: CallPath {
+                        prefixes: vec![],
+                        suffix: return_break_variant.name.clone(),
+                        is_absolute: false,
+                    },
+                    type_arguments: TypeArgs::Regular(vec![]),
+                    span: span.clone(),
+                },
+                call_path_decl: return_enum_ref.into(),
+            },
+            return_type,
+            span: span.clone(),
+        };
+        let early_return = ty::TyExpression {
+            expression: match ctx.try_block_type() {
+                Some(_) => ty::TyExpressionVariant::TryBreak(Box::new(break_instantiation)),
+                None => ty::TyExpressionVariant::Return(Box::new(break_instantiation)),
+            },
+            return_type: type_engine.insert(engines, TypeInfo::Unknown),
+            span: span.clone(),
+        };
+
+        Ok(ty::TyExpression {
+            return_type: continue_value.return_type,
+            expression: ty::TyExpressionVariant::IfExp {
+                condition: Box::new(condition),
+                then: Box::new(early_return),
+                r#else: Some(Box::new(continue_value)),
+            },
+            span,
+        })
+    }
+
+    fn type_check_try_block(
+        handler: &Handler,
+        mut ctx: TypeCheckContext,
+        contents: CodeBlock,
+        span: Span,
+    ) -> Result<ty::TyExpression, ErrorEmitted> {
+        let type_engine = ctx.engines.te();
+        let decl_engine = ctx.engines.de();
+        let engines = ctx.engines();
+
+        // The block evaluates to a `Result`, whose type arguments are inferred from the type
+        // annotation, the `?` operators within the block and the value of its body.
+        let unknown_type_argument = || {
+            let type_id = type_engine.insert(engines, TypeInfo::Unknown);
+            TypeArgument {
+                type_id,
+                initial_type_id: type_id,
+                span: span.clone(),
+                call_path_tree: None,
+            }
+        };
+        let result_type = type_engine.insert(
+            engines,
+            TypeInfo::Custom {
+                call_path: CallPath {
+                    prefixes: vec![],
+                    suffix: Ident::new_with_override("Result".to_string(), span.clone()),
+                    is_absolute: false,
+                },
+                type_arguments: Some(vec![unknown_type_argument(), unknown_type_argument()]),
+            },
+        );
+        let result_type = ctx
+            .resolve_type_with_self(
+                &Handler::default(),
+                result_type,
+                &span,
+                EnforceTypeArguments::No,
+                None,
+            )
+            .ok();
+        let result = result_type.and_then(|result_type| match type_engine.get(result_type) {
+            TypeInfo::Enum(decl_ref) => {
+                let enum_decl = decl_engine.get_enum(&decl_ref);
+                let variant = |name: &str| {
+                    enum_decl
+                        .variants
+                        .iter()
+                        .find(|variant| variant.name.as_str() == name)
+                        .cloned()
+                };
+                variant("Err")?;
+                Some((result_type, decl_ref.clone(), variant("Ok")?))
+            }
+            _ => None,
+        });
+        let (result_type, result_enum_ref, ok_variant) = match result {
+            Some(result) => result,
+            None => return Err(handler.emit_err(CompileError::TryBlockWithoutResult { span })),
+        };
+
+        // Mismatches with the annotation are reported once the value of the whole block is
+        // checked against it. Unifying here only lets the annotation guide the inference.
+        let _ = ctx.unify_with_self(result_type, &span);
+
+        // type check the body, whose value goes in the `Ok` variant
+        let ok_type = ok_variant.type_argument.type_id;
+        let mut typed_block = {
+            let mut ctx = ctx
+                .by_ref()
+                .with_help_text("")
+                .with_type_annotation(ok_type)
+                .with_try_block_type(result_type);
+            let (typed_block, block_return_type) =
+                ty::TyCodeBlock::type_check(handler, ctx.by_ref(), contents).unwrap_or_else(|_| {
+                    (
+                        ty::TyCodeBlock { contents: vec![] },
+                        type_engine.insert(engines, TypeInfo::ErrorRecovery),
+                    )
+                });
+            let (warnings, errors) = ctx.unify_with_self(block_return_type, &span);
+            for warn in warnings {
+                handler.emit_warn(warn);
+            }
+            for err in errors {
+                handler.emit_err(err);
+            }
+            typed_block
+        };
+
+        // desugar the value of the body into `Ok(value)`
+        let (ok_value, ok_value_span) = match typed_block.contents.pop() {
+            Some(ty::TyAstNode {
+                content: ty::TyAstNodeContent::ImplicitReturnExpression(value),
+                span,
+            }) => (value, span),
+            node => {
+                typed_block.contents.extend(node);
+                let unit = ty::TyExpression {
+                    expression: ty::TyExpressionVariant::Tuple { fields: vec![] },
+                    return_type: type_engine.insert(engines, TypeInfo::Tuple(vec![])),
+                    span: span.clone(),
+                };
+                (unit, span.clone())
+            }
+        };
+        let ok_instantiation = ty::TyExpression {
+            expression: ty::TyExpressionVariant::EnumInstantiation {
+                enum_ref: result_enum_ref.clone(),
+                variant_name: ok_variant.name.clone(),
+                tag: ok_variant.tag,
+                contents: Some(Box::new(ok_value)),
+                variant_instantiation_span: ok_value_span.clone(),
+                call_path_binding: TypeBinding {
+                    inner: CallPath {
+                        prefixes: vec![],
+                        suffix: ok_variant.name.clone(),
+                        is_absolute: false,
+                    },
+                    type_arguments: TypeArgs::Regular(vec![]),
+                    span: ok_value_span.clone(),
+                },
+                call_path_decl: result_enum_ref.into(),
+            },
+            return_type: result_type,
+            span: ok_value_span.clone(),
+        };
+        typed_block.contents.push(ty::TyAstNode {
+            content: ty::TyAstNodeContent::ImplicitReturnExpression(ok_instantiation),
+            span: ok_value_span,
+        });
+
+        Ok(ty::TyExpression {
+            expression: ty::TyExpressionVariant::TryBlock(typed_block),
+            return_type: result_type,
+            span,
+        })
+    }
+
+    fn type_check_let_else_block(
+        handler: &Handler,
+        mut ctx: TypeCheckContext,
+        contents: CodeBlock,
+        span: Span,
+    ) -> Result<ty::TyExpression, ErrorEmitted> {
+        let type_engine = ctx.engines.te();
+        let decl_engine = ctx.engines.de();
+        let engines = ctx.engines();
+
+        // The block doesn't produce a value, so its type is only checked for being `Never`.
+        let ctx = ctx
+            .by_ref()
+            .with_help_text("")
+            .with_type_annotation(type_engine.insert(engines, TypeInfo::Unknown));
+        let mut typed_block = Self::type_check_code_block(handler, ctx, contents, span.clone())?;
+
+        let diverges = typed_block.deterministically_aborts(decl_engine, true)
+            || type_engine
+                .get(typed_block.return_type)
+                .is_uninhabited(type_engine, decl_engine);
+        if !diverges {
+            handler.emit_err(CompileError::LetElseBlockDoesNotDiverge { span: span.clone() });
+            return Ok(ty::TyExpression::error(span, engines));
+        }
+
+        // Like a `return`, the diverging block fits wherever a value of any type is expected,
+        // even when it ends in a call which reverts.
+        typed_block.return_type = type_engine.insert(engines, TypeInfo::Unknown);
+        Ok(typed_block)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn type_check_asm_expression(
         handler: &Handler,
@@ -1710,13 +2109,31 @@ impl ty::TyExpression {
                     match expr.kind {
                         ExpressionKind::Variable(name) => {
                             // check that the reassigned name exists
+                            if ctx.namespace.is_enclosing_fn_local(&name)
+                                && ctx
+                                    .namespace
+                                    .resolve_symbol(&Handler::default(), &name)
+                                    .is_err()
+                            {
+                                return Err(handler.emit_err(
+                                    CompileError::NestedFunctionCapturesLocal {
+                                        span: name.span(),
+                                        name,
+                                    },
+                                ));
+                            }
                             let unknown_decl =
                                 ctx.namespace.resolve_symbol(handler, &name).cloned()?;
                             let variable_decl = unknown_decl.expect_variable(handler).cloned()?;
                             if !variable_decl.mutability.is_mutable() {
-                                return Err(handler.emit_err(
-                                    CompileError::AssignmentToNonMutable { name, span },
-                                ));
+                                // The receiver of a method is made mutable with `ref mut self`,
+                                // rather than with `mut` as other variables are.
+                                let err = if name.as_str() == "self" {
+                                    CompileError::AssignmentToImmutableSelf { span }
+                                } else {
+                                    CompileError::AssignmentToNonMutable { name, span }
+                                };
+                                return Err(handler.emit_err(err));
                             }
                             break (name, variable_decl.body.return_type);
                         }
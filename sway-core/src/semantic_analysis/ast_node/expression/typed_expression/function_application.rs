@@ -32,23 +32,44 @@ pub(crate) fn instantiate_function_application(
     let arguments = arguments.unwrap_or_default();
 
     // 'purity' is that of the callee, 'opts.purity' of the caller.
-    if !ctx.purity().can_call(function_decl.purity) {
+    // Calls from the body of a pure function are checked once the body is type checked.
+    if !ctx.purity().can_call(function_decl.purity)
+        && (ctx.purity() != Purity::Pure || !ctx.in_function_body())
+    {
         handler.emit_err(CompileError::StorageAccessMismatch {
             attrs: promote_purity(ctx.purity(), function_decl.purity).to_attribute_syntax(),
             span: call_path_binding.span(),
         });
     }
 
-    // check that the number of parameters and the number of the arguments is the same
-    check_function_arguments_arity(
-        handler,
-        arguments.len(),
-        &function_decl,
-        &call_path_binding.inner,
-        false,
-    )?;
+    // The functions called by the initializer of a constant are evaluated along with it, and
+    // the compile time evaluation only supports a `return` at the end of their body.
+    if ctx.const_context() && !function_decl.body.early_return_statements().is_empty() {
+        return Err(handler.emit_err(CompileError::EarlyReturnInConstContext {
+            fn_name: function_decl.name.clone(),
+            span: call_path_binding.span(),
+        }));
+    }
+
+    let arguments = if arguments
+        .iter()
+        .any(|arg| matches!(arg.kind, ExpressionKind::NamedArgument(_)))
+    {
+        match_named_arguments(handler, arguments, &function_decl, &call_path_binding.inner)?
+    } else {
+        // check that the number of parameters and the number of the arguments is the same
+        check_function_arguments_arity(
+            handler,
+            arguments.len(),
+            &function_decl,
+            &call_path_binding.inner,
+            false,
+        )?;
+        arguments.into_iter().map(Some).collect()
+    };
 
-    let typed_arguments = type_check_arguments(handler, ctx.by_ref(), arguments)?;
+    let typed_arguments =
+        type_check_arguments(handler, ctx.by_ref(), arguments, &function_decl.parameters)?;
 
     let typed_arguments_with_names = unify_arguments_and_parameters(
         handler,
@@ -94,30 +115,109 @@ pub(crate) fn instantiate_function_application(
     Ok(exp)
 }
 
-/// Type checks the arguments.
+/// Orders the arguments of a call using named arguments by the parameters they are passed to.
+/// Positional arguments must come first and are passed to the leading parameters. Parameters
+/// which are not given an argument are left as `None` and must have a default value.
+fn match_named_arguments(
+    handler: &Handler,
+    arguments: Vec<parsed::Expression>,
+    function_decl: &ty::TyFunctionDecl,
+    call_path: &CallPath,
+) -> Result<Vec<Option<parsed::Expression>>, ErrorEmitted> {
+    let parameters = &function_decl.parameters;
+    if arguments.len() > parameters.len() {
+        return Err(handler.emit_err(CompileError::TooManyArgumentsForFunction {
+            span: call_path.span(),
+            method_name: function_decl.name.clone(),
+            dot_syntax_used: false,
+            expected: parameters.len(),
+            received: arguments.len(),
+        }));
+    }
+
+    let mut error_emitted = None;
+    let mut matched_arguments: Vec<Option<parsed::Expression>> = vec![None; parameters.len()];
+    let mut seen_named_argument = false;
+
+    for (position, arg) in arguments.into_iter().enumerate() {
+        match arg.kind {
+            ExpressionKind::NamedArgument(NamedArgumentExpression { name, value }) => {
+                seen_named_argument = true;
+                match parameters.iter().position(|param| param.name == name) {
+                    None => {
+                        error_emitted = Some(handler.emit_err(CompileError::UnknownArgumentName {
+                            fn_name: function_decl.name.clone(),
+                            name,
+                        }));
+                    }
+                    Some(index) if matched_arguments[index].is_some() => {
+                        error_emitted = Some(handler.emit_err(CompileError::DuplicateArgument {
+                            name,
+                            span: arg.span,
+                        }));
+                    }
+                    Some(index) => matched_arguments[index] = Some(*value),
+                }
+            }
+            _ if seen_named_argument => {
+                error_emitted = Some(handler.emit_err(
+                    CompileError::PositionalArgumentAfterNamedArgument { span: arg.span },
+                ));
+            }
+            _ => matched_arguments[position] = Some(arg),
+        }
+    }
+
+    for (arg, param) in matched_arguments.iter().zip(parameters.iter()) {
+        if arg.is_none() && param.default_value.is_none() {
+            error_emitted = Some(handler.emit_err(CompileError::MissingArgument {
+                fn_name: function_decl.name.clone(),
+                name: param.name.clone(),
+                span: call_path.span(),
+            }));
+        }
+    }
+
+    if let Some(err) = error_emitted {
+        Err(err)
+    } else {
+        Ok(matched_arguments)
+    }
+}
+
+/// Type checks the arguments. The parameters which are not given an argument, whether omitted
+/// from the end of a call or skipped by named arguments, take their default value.
 fn type_check_arguments(
     handler: &Handler,
     mut ctx: TypeCheckContext,
-    arguments: Vec<parsed::Expression>,
+    arguments: Vec<Option<parsed::Expression>>,
+    parameters: &[ty::TyFunctionParameter],
 ) -> Result<Vec<ty::TyExpression>, ErrorEmitted> {
     let type_engine = ctx.engines.te();
     let engines = ctx.engines();
 
     let mut error_emitted = None;
 
-    let typed_arguments = arguments
+    let mut typed_arguments: Vec<ty::TyExpression> = arguments
         .into_iter()
-        .map(|arg| {
-            let ctx = ctx
-                .by_ref()
-                .with_help_text("")
-                .with_type_annotation(type_engine.insert(engines, TypeInfo::Unknown));
-            ty::TyExpression::type_check(handler, ctx, arg.clone()).unwrap_or_else(|err| {
-                error_emitted = Some(err);
-                ty::TyExpression::error(arg.span(), engines)
-            })
+        .zip(parameters.iter())
+        .filter_map(|(arg, param)| match arg {
+            Some(arg) => {
+                let ctx = ctx
+                    .by_ref()
+                    .with_help_text("")
+                    .with_type_annotation(type_engine.insert(engines, TypeInfo::Unknown));
+                Some(
+                    ty::TyExpression::type_check(handler, ctx, arg.clone()).unwrap_or_else(|err| {
+                        error_emitted = Some(err);
+                        ty::TyExpression::error(arg.span(), engines)
+                    }),
+                )
+            }
+            None => param.default_value.clone(),
         })
         .collect();
+    typed_arguments.extend(default_arguments(parameters, typed_arguments.len()));
 
     if let Some(err) = error_emitted {
         Err(err)
@@ -191,32 +291,52 @@ pub(crate) fn check_function_arguments_arity(
     call_path: &CallPath,
     is_method_call_syntax_used: bool,
 ) -> Result<(), ErrorEmitted> {
+    // parameters with a default value can be omitted from the end of the arguments
+    let required = function_decl
+        .parameters
+        .iter()
+        .filter(|param| param.default_value.is_none())
+        .count();
     // if is_method_call_syntax_used then we have the guarantee
     // that at least the self argument is passed
-    let (expected, received) = if is_method_call_syntax_used {
-        (function_decl.parameters.len() - 1, arguments_len - 1)
+    let (expected, required, received) = if is_method_call_syntax_used {
+        (
+            function_decl.parameters.len() - 1,
+            required - 1,
+            arguments_len - 1,
+        )
     } else {
-        (function_decl.parameters.len(), arguments_len)
+        (function_decl.parameters.len(), required, arguments_len)
     };
-    match expected.cmp(&received) {
-        std::cmp::Ordering::Equal => Ok(()),
-        std::cmp::Ordering::Less => {
-            Err(handler.emit_err(CompileError::TooFewArgumentsForFunction {
-                span: call_path.span(),
-                method_name: function_decl.name.clone(),
-                dot_syntax_used: is_method_call_syntax_used,
-                expected,
-                received,
-            }))
-        }
-        std::cmp::Ordering::Greater => {
-            Err(handler.emit_err(CompileError::TooManyArgumentsForFunction {
-                span: call_path.span(),
-                method_name: function_decl.name.clone(),
-                dot_syntax_used: is_method_call_syntax_used,
-                expected,
-                received,
-            }))
-        }
+    if received > expected {
+        Err(handler.emit_err(CompileError::TooManyArgumentsForFunction {
+            span: call_path.span(),
+            method_name: function_decl.name.clone(),
+            dot_syntax_used: is_method_call_syntax_used,
+            expected,
+            received,
+        }))
+    } else if received < required {
+        Err(handler.emit_err(CompileError::TooFewArgumentsForFunction {
+            span: call_path.span(),
+            method_name: function_decl.name.clone(),
+            dot_syntax_used: is_method_call_syntax_used,
+            expected: required,
+            received,
+        }))
+    } else {
+        Ok(())
     }
 }
+
+/// Returns the default values of the parameters which come after the first `arguments_len`
+/// ones, to be used in place of the arguments omitted at a call site.
+pub(crate) fn default_arguments(
+    parameters: &[ty::TyFunctionParameter],
+    arguments_len: usize,
+) -> impl Iterator<Item = ty::TyExpression> + '_ {
+    parameters
+        .iter()
+        .skip(arguments_len)
+        .filter_map(|param| param.default_value.clone())
+}
@@ -4,11 +4,12 @@ use crate::{
     semantic_analysis::*,
     type_system::*,
 };
-use ast_node::typed_expression::check_function_arguments_arity;
+use ast_node::typed_expression::{check_function_arguments_arity, default_arguments};
 use std::collections::{HashMap, VecDeque};
 use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
+    warning::{CompileWarning, Warning},
 };
 use sway_types::{constants, integer_bits::IntegerBits};
 use sway_types::{constants::CONTRACT_CALL_COINS_PARAMETER_NAME, Spanned};
@@ -49,18 +50,37 @@ pub(crate) fn type_check_method_application(
     )?;
     let mut method = decl_engine.get_function(&decl_ref);
 
-    // check the method visibility
-    if span.source_id() != method.span.source_id() && method.visibility.is_private() {
-        return Err(handler.emit_err(CompileError::CallingPrivateLibraryMethod {
-            name: method.name.as_str().to_string(),
-            span,
+    // check the method visibility, methods declared by the packages depended on are not
+    // accessible when they are package visible
+    if span.source_id() != method.span.source_id() {
+        let within_package = method.visibility == Visibility::Package
+            && !method.span.source_id().map_or(false, |source_id| {
+                ctx.namespace.root().is_external_source(source_id)
+            });
+        if !method.visibility.is_accessible(within_package) {
+            return Err(handler.emit_err(CompileError::CallingPrivateLibraryMethod {
+                name: method.name.as_str().to_string(),
+                span,
+            }));
+        }
+    }
+
+    // The methods called by the initializer of a constant are evaluated along with it, see
+    // `instantiate_function_application`.
+    if ctx.const_context() && !method.body.early_return_statements().is_empty() {
+        return Err(handler.emit_err(CompileError::EarlyReturnInConstContext {
+            fn_name: method.name.clone(),
+            span: method_name_binding.inner.easy_name().span(),
         }));
     }
 
     // check the function storage purity
     if !method.is_contract_call {
         // 'method.purity' is that of the callee, 'opts.purity' of the caller.
-        if !ctx.purity().can_call(method.purity) {
+        // Calls from the body of a pure function are checked once the body is type checked.
+        if !ctx.purity().can_call(method.purity)
+            && (ctx.purity() != Purity::Pure || !ctx.in_function_body())
+        {
             handler.emit_err(CompileError::StorageAccessMismatch {
                 attrs: promote_purity(ctx.purity(), method.purity).to_attribute_syntax(),
                 span: method_name_binding.inner.easy_name().span(),
@@ -137,16 +157,26 @@ pub(crate) fn type_check_method_application(
                 ctx.namespace,
                 decl_engine,
                 coins_expr,
-            ) && !method
-                .attributes
-                .contains_key(&crate::transform::AttributeKind::Payable)
-            {
-                return Err(
-                    handler.emit_err(CompileError::CoinsPassedToNonPayableMethod {
-                        fn_name: method.name,
-                        span,
-                    }),
-                );
+            ) {
+                if !method
+                    .attributes
+                    .contains_key(&crate::transform::AttributeKind::Payable)
+                {
+                    return Err(
+                        handler.emit_err(CompileError::CoinsPassedToNonPayableMethod {
+                            fn_name: method.name,
+                            span,
+                        }),
+                    );
+                }
+                // the coins are forwarded from a contract method, which should then
+                // be payable too
+                if matches!(ctx.abi_mode(), AbiMode::ImplAbiFn(..)) && !ctx.payable() {
+                    handler.emit_warn(CompileWarning {
+                        span: span.clone(),
+                        warning_content: Warning::CoinsForwardedFromNonPayableMethod,
+                    });
+                }
             }
         }
     }
@@ -169,12 +199,7 @@ pub(crate) fn type_check_method_application(
                 }
             }
             is_method_call_syntax_used = true;
-            let is_first_param_self = method
-                .parameters
-                .get(0)
-                .map(|f| f.is_self())
-                .unwrap_or_default();
-            if !is_first_param_self {
+            if method.self_kind().is_none() {
                 return Err(
                     handler.emit_err(CompileError::AssociatedFunctionCalledAsMethod {
                         fn_name: method_name.clone(),
@@ -316,6 +341,8 @@ pub(crate) fn type_check_method_application(
         &call_path,
         is_method_call_syntax_used,
     )?;
+    let arguments_len = args_buf.len();
+    args_buf.extend(default_arguments(&method.parameters, arguments_len));
 
     // unify the types of the arguments with the types of the parameters from the function declaration
     let typed_arguments_with_names =
@@ -218,7 +218,9 @@ fn analyze_expression(
         | Continue
         | AbiName(_) => effects_of_expression(engines, expr),
         Reassignment(reassgn) => analyze_expression(engines, &reassgn.rhs, block_name, warnings),
-        CodeBlock(codeblock) => analyze_code_block(engines, codeblock, block_name, warnings),
+        CodeBlock(codeblock) | TryBlock(codeblock) => {
+            analyze_code_block(engines, codeblock, block_name, warnings)
+        }
         LazyOperator {
             lhs: left,
             rhs: right,
@@ -303,6 +305,7 @@ fn analyze_expression(
         StructFieldAccess { prefix: expr, .. }
         | TupleElemAccess { prefix: expr, .. }
         | Return(expr)
+        | TryBreak(expr)
         | EnumTag { exp: expr }
         | UnsafeDowncast { exp: expr, .. }
         | AbiCast { address: expr, .. } => analyze_expression(engines, expr, block_name, warnings),
@@ -535,7 +538,7 @@ fn effects_of_expression(engines: &Engines, expr: &ty::TyExpression) -> HashSet<
             contents: exprs,
         } => effects_of_expressions(engines, exprs),
         StructExpression { fields, .. } => effects_of_struct_expressions(engines, fields),
-        CodeBlock(codeblock) => effects_of_codeblock(engines, codeblock),
+        CodeBlock(codeblock) | TryBlock(codeblock) => effects_of_codeblock(engines, codeblock),
         MatchExp { desugared, .. } => effects_of_expression(engines, desugared),
         IfExp {
             condition,
@@ -555,7 +558,8 @@ fn effects_of_expression(engines: &Engines, expr: &ty::TyExpression) -> HashSet<
         | TupleElemAccess { prefix: expr, .. }
         | EnumTag { exp: expr }
         | UnsafeDowncast { exp: expr, .. }
-        | Return(expr) => effects_of_expression(engines, expr),
+        | Return(expr)
+        | TryBreak(expr) => effects_of_expression(engines, expr),
         EnumInstantiation { contents, .. } => match contents {
             Some(expr) => effects_of_expression(engines, expr),
             None => HashSet::new(),
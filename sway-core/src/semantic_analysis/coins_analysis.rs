@@ -55,6 +55,7 @@ pub fn possibly_nonzero_u64_expression(
         FunctionApplication { .. }
         | ArrayIndex { .. }
         | CodeBlock(_)
+        | TryBlock(_)
         | MatchExp { .. }
         | IfExp { .. }
         | AsmExpression { .. }
@@ -78,6 +79,7 @@ pub fn possibly_nonzero_u64_expression(
         | Break
         | Continue
         | Reassignment(_)
-        | Return(_) => true,
+        | Return(_)
+        | TryBreak(_) => true,
     }
 }
@@ -22,6 +22,9 @@ impl ty::TyModule {
             ..
         } = parsed;
 
+        // The span of the module tells the packages depending on it which sources it consists of.
+        ctx.namespace.span = Some(span.clone());
+
         // Type-check submodules first in order of declaration.
         let submodules_res = submodules
             .iter()
@@ -15,8 +15,9 @@ use super::TraitMap;
 use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
+    warning::{CompileWarning, Warning},
 };
-use sway_types::{span::Span, Spanned};
+use sway_types::{span::Span, style::to_snake_case, Spanned};
 
 use std::sync::Arc;
 
@@ -49,6 +50,11 @@ pub struct Items {
     pub(crate) use_aliases: UseAliases,
     /// If there is a storage declaration (which are only valid in contracts), store it here.
     pub(crate) declared_storage: Option<DeclRefStorage>,
+    /// The local variables of the enclosing functions, while type-checking a nested function.
+    ///
+    /// These are removed from `symbols` as nested functions cannot capture them, but are kept
+    /// around in order to report a more helpful error when they are referenced.
+    pub(crate) enclosing_fn_locals: im::HashSet<Ident>,
 }
 
 impl Items {
@@ -99,6 +105,32 @@ impl Items {
         Ok(())
     }
 
+    /// Removes the local declarations of the enclosing function from this scope, so that a
+    /// function nested within it cannot capture them.
+    pub(crate) fn hide_enclosing_fn_locals(&mut self) {
+        let locals = self
+            .symbols
+            .iter()
+            .filter(|(_, decl)| {
+                matches!(
+                    decl,
+                    ty::TyDecl::VariableDecl(_) | ty::TyDecl::GenericTypeForFunctionScope(_)
+                )
+            })
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        for name in locals {
+            self.symbols.remove(&name);
+            self.enclosing_fn_locals.insert(name);
+        }
+    }
+
+    /// Returns `true` if `name` is a local of an enclosing function, hidden by
+    /// `hide_enclosing_fn_locals`.
+    pub(crate) fn is_enclosing_fn_local(&self, name: &Ident) -> bool {
+        self.enclosing_fn_locals.contains(name)
+    }
+
     pub fn get_all_declared_symbols(&self) -> impl Iterator<Item = &Ident> {
         self.symbols().keys()
     }
@@ -174,10 +206,41 @@ impl Items {
             append_shadowing_error(decl, &item, const_shadowing_mode);
         }
 
+        if let ty::TyDecl::FunctionDecl(_) = item {
+            self.warn_similar_function_name(handler, &name);
+        }
+
         self.symbols.insert(name, item);
         Ok(())
     }
 
+    /// Warns if a function declared or imported by name in this scope is named like the
+    /// function `name`, save for the case of its letters or the underscores between words,
+    /// e.g. `myFunc` and `my_func`.
+    fn warn_similar_function_name(&self, handler: &Handler, name: &Ident) {
+        let normalized_name = to_snake_case(name.as_str());
+        let declared = self.symbols.iter();
+        let imported = self
+            .use_synonyms
+            .iter()
+            .filter(|(_, (_, glob_import, _, _))| *glob_import == GlobImport::No)
+            .map(|(other, (_, _, decl, _))| (other, decl));
+        let similar = declared.chain(imported).find(|(other, decl)| {
+            matches!(decl, ty::TyDecl::FunctionDecl(_))
+                && other.as_str() != name.as_str()
+                && to_snake_case(other.as_str()) == normalized_name
+        });
+        if let Some((other, _)) = similar {
+            handler.emit_warn(CompileWarning {
+                span: name.span(),
+                warning_content: Warning::SimilarFunctionNames {
+                    a: other.clone(),
+                    b: name.clone(),
+                },
+            });
+        }
+    }
+
     pub(crate) fn check_symbol(&self, name: &Ident) -> Result<&ty::TyDecl, CompileError> {
         self.symbols
             .get(name)
@@ -269,6 +332,12 @@ impl Items {
 
         let symbol = match self.symbols.get(base_name).cloned() {
             Some(s) => s,
+            None if self.is_enclosing_fn_local(base_name) => {
+                return Err(handler.emit_err(CompileError::NestedFunctionCapturesLocal {
+                    name: base_name.clone(),
+                    span: base_name.span(),
+                }));
+            }
             None => {
                 return Err(handler.emit_err(CompileError::UnknownVariable {
                     var_name: base_name.clone(),
@@ -6,6 +6,7 @@ mod root;
 mod submodule_namespace;
 mod trait_map;
 
+pub(crate) use items::GlobImport;
 pub use items::Items;
 pub use module::Module;
 pub use namespace::Namespace;
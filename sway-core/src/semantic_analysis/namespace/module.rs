@@ -22,7 +22,7 @@ use sway_ast::ItemConst;
 use sway_error::handler::Handler;
 use sway_error::{error::CompileError, handler::ErrorEmitted};
 use sway_parse::{lex, Parser};
-use sway_types::{span::Span, Spanned};
+use sway_types::{span::Span, SourceId, Spanned};
 use sway_utils::iter_prefixes;
 
 /// A single `Module` within a Sway project.
@@ -245,12 +245,13 @@ impl Module {
 
         let decl_engine = engines.de();
 
+        let src_in_package = self.is_in_package(src);
         let src_ns = self.check_submodule(handler, src)?;
 
         let implemented_traits = src_ns.implemented_traits.clone();
         let mut symbols_and_decls = vec![];
         for (symbol, decl) in src_ns.symbols.iter() {
-            if is_ancestor(src, dst) || decl.visibility(decl_engine).is_public() {
+            if is_ancestor(src, dst) || decl.visibility(decl_engine).is_accessible(src_in_package) {
                 symbols_and_decls.push((symbol.clone(), decl.clone()));
             }
         }
@@ -292,6 +293,7 @@ impl Module {
 
         let decl_engine = engines.de();
 
+        let src_in_package = self.is_in_package(src);
         let src_ns = self.check_submodule(handler, src)?;
 
         let implemented_traits = src_ns.implemented_traits.clone();
@@ -302,7 +304,7 @@ impl Module {
             .map(|(symbol, (_, _, decl, _))| (symbol.clone(), decl.clone()))
             .collect::<Vec<_>>();
         for (symbol, decl) in src_ns.symbols.iter() {
-            if is_ancestor(src, dst) || decl.visibility(decl_engine).is_public() {
+            if is_ancestor(src, dst) || decl.visibility(decl_engine).is_accessible(src_in_package) {
                 symbols_and_decls.push((symbol.clone(), decl.clone()));
             }
         }
@@ -390,11 +392,14 @@ impl Module {
 
         let decl_engine = engines.de();
 
+        let src_in_package = self.is_in_package(src);
         let src_ns = self.check_submodule(handler, src)?;
         let mut impls_to_insert = TraitMap::default();
         match src_ns.symbols.get(item).cloned() {
             Some(decl) => {
-                if !decl.visibility(decl_engine).is_public() && !is_ancestor(src, dst) {
+                if !decl.visibility(decl_engine).is_accessible(src_in_package)
+                    && !is_ancestor(src, dst)
+                {
                     handler.emit_err(CompileError::ImportPrivateSymbol {
                         name: item.clone(),
                         span: item.span(),
@@ -633,6 +638,32 @@ impl Module {
         Ok(())
     }
 
+    /// Whether the module at `path` belongs to the package of `self`, rather than to one of the
+    /// external packages it depends on.
+    pub(crate) fn is_in_package(&self, path: &Path) -> bool {
+        path.first()
+            .and_then(|name| self.submodule(&[name.clone()]))
+            .map_or(true, |module| !module.is_external)
+    }
+
+    /// Whether `source_id` is the source of a module of one of the external packages `self`
+    /// depends on.
+    pub(crate) fn is_external_source(&self, source_id: &SourceId) -> bool {
+        self.submodules
+            .values()
+            .filter(|module| module.is_external)
+            .any(|module| module.has_source(source_id))
+    }
+
+    /// Whether `source_id` is the source of `self` or of one of its submodules.
+    fn has_source(&self, source_id: &SourceId) -> bool {
+        self.span.as_ref().and_then(|span| span.source_id()) == Some(source_id)
+            || self
+                .submodules
+                .values()
+                .any(|module| module.has_source(source_id))
+    }
+
     fn check_module_privacy(
         &self,
         handler: &Handler,
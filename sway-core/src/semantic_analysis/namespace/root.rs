@@ -80,7 +80,15 @@ impl Root {
         }
 
         // check the visibility of the symbol itself
-        if !decl.visibility(engines.de()).is_public() {
+        let symbol_path: Vec<_> = mod_path
+            .iter()
+            .chain(&call_path.prefixes)
+            .cloned()
+            .collect();
+        if !decl
+            .visibility(engines.de())
+            .is_accessible(self.is_in_package(&symbol_path))
+        {
             handler.emit_err(CompileError::ImportPrivateSymbol {
                 name: call_path.suffix.clone(),
                 span: call_path.suffix.span(),
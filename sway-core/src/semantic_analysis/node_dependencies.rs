@@ -471,6 +471,9 @@ impl Dependencies {
                         deps.gather_from_expr(engines, arg)
                     })
             }
+            ExpressionKind::NamedArgument(NamedArgumentExpression { value, .. }) => {
+                self.gather_from_expr(engines, value)
+            }
             ExpressionKind::LazyOperator(LazyOperatorExpression { lhs, rhs, .. }) => self
                 .gather_from_expr(engines, lhs)
                 .gather_from_expr(engines, rhs),
@@ -493,7 +496,9 @@ impl Dependencies {
                 .gather_from_iter(branches.iter(), |deps, branch| {
                     deps.gather_from_match_branch(engines, branch)
                 }),
-            ExpressionKind::CodeBlock(contents) => self.gather_from_block(engines, contents),
+            ExpressionKind::CodeBlock(contents)
+            | ExpressionKind::TryBlock(contents)
+            | ExpressionKind::LetElseBlock(contents) => self.gather_from_block(engines, contents),
             ExpressionKind::Array(array_expression) => self
                 .gather_from_iter(array_expression.contents.iter(), |deps, expr| {
                     deps.gather_from_expr(engines, expr)
@@ -599,7 +604,9 @@ impl Dependencies {
             ExpressionKind::Reassignment(reassignment) => {
                 self.gather_from_expr(engines, &reassignment.rhs)
             }
-            ExpressionKind::Return(expr) => self.gather_from_expr(engines, expr),
+            ExpressionKind::Return(expr) | ExpressionKind::Try(expr) => {
+                self.gather_from_expr(engines, expr)
+            }
         }
     }
 
@@ -708,7 +715,13 @@ impl Dependencies {
             TypeInfo::Tuple(elems) => self.gather_from_iter(elems.iter(), |deps, elem| {
                 deps.gather_from_type_argument(engines, elem)
             }),
-            TypeInfo::Array(elem_type, _) => self.gather_from_type_argument(engines, elem_type),
+            TypeInfo::Array(elem_type, length) => {
+                let deps = match length.const_path() {
+                    Some(const_path) => self.gather_from_call_path(const_path, false, false),
+                    None => self,
+                };
+                deps.gather_from_type_argument(engines, elem_type)
+            }
             TypeInfo::Struct(decl_ref) => self.gather_from_iter(
                 decl_engine.get_struct(decl_ref).fields.iter(),
                 |deps, field| deps.gather_from_type_argument(engines, &field.type_argument),
@@ -5,26 +5,36 @@ use crate::{
         namespace::{self, Namespace},
         TypeCheckContext,
     },
-    Engines,
+    Engines, LintConfig,
 };
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_ir::{Context, Module};
+use sway_types::Ident;
 
 impl ty::TyProgram {
     /// Type-check the given parsed program to produce a typed program.
     ///
     /// The given `initial_namespace` acts as an initial state for each module within this program.
     /// It should contain a submodule for each library package dependency.
+    ///
+    /// The lints are reported as configured by `lint_config`. The names the declared functions have
+    /// in their selectors are rewritten by `name_rewriter`, if any.
     pub fn type_check(
         handler: &Handler,
         engines: &Engines,
         parsed: &ParseProgram,
         initial_namespace: namespace::Module,
         package_name: &str,
+        lint_config: LintConfig,
+        name_rewriter: Option<fn(&Ident) -> Ident>,
     ) -> Result<Self, ErrorEmitted> {
         let mut namespace = Namespace::init_root(initial_namespace);
-        let ctx =
-            TypeCheckContext::from_root(&mut namespace, engines).with_kind(parsed.kind.clone());
+        let mut ctx = TypeCheckContext::from_root(&mut namespace, engines)
+            .with_kind(parsed.kind.clone())
+            .with_lint_config(&lint_config);
+        if let Some(name_rewriter) = name_rewriter {
+            ctx = ctx.with_name_rewriter(name_rewriter);
+        }
         let ParseProgram { root, kind } = parsed;
         ty::TyModule::type_check(handler, ctx, root).and_then(|root| {
             let res = Self::validate_root(handler, engines, &root, kind.clone(), package_name);
@@ -9,7 +9,7 @@ use crate::{
     type_system::{
         EnforceTypeArguments, MonomorphizeHelper, SubstTypes, TypeArgument, TypeId, TypeInfo,
     },
-    CompileWarning,
+    CompileWarning, LintConfig,
 };
 use sway_error::{
     error::CompileError,
@@ -59,14 +59,35 @@ pub struct TypeCheckContext<'a> {
     /// Tracks the purity of the context, e.g. whether or not we should be allowed to write to
     /// storage.
     purity: Purity,
+    /// Whether or not we're within a `#[payable]` contract method.
+    ///
+    /// Forwarding coins from a contract method which isn't payable is warned about.
+    payable: bool,
     /// Provides the kind of the module.
     /// This is useful for example to throw an error when while loops are present in predicates.
     kind: TreeType,
 
-    /// Indicates when semantic analysis should disallow functions. (i.e.
-    /// disallowing functions from being defined inside of another function
-    /// body).
-    disallow_functions: bool,
+    /// Indicates when semantic analysis is within a function body, in which case any function
+    /// defined here is a nested function that must not capture the enclosing function's locals.
+    in_function_body: bool,
+    /// The declared return type of the function whose body is being checked, which the `?`
+    /// operator returns early with.
+    function_return_type: Option<TypeId>,
+    /// The type of the innermost `try` block being checked, which the `?` operator
+    /// short-circuits to instead of returning from the function.
+    try_block_type: Option<TypeId>,
+    /// Whether or not we're within the initializer of a constant, which is evaluated at compile
+    /// time along with the bodies of the functions it calls.
+    const_context: bool,
+    /// The configuration of the lints reported while checking the functions, like whether the
+    /// style lints are errors. The default configuration applies if there is none.
+    ///
+    /// It is borrowed rather than copied into every context, which are kept on the stack
+    /// throughout the recursive type checking of expressions.
+    lint_config: Option<&'a LintConfig>,
+    /// Rewrites the name of each function declaration before it is checked, e.g. to give the
+    /// functions expanded from a macro a deterministic suffix. It has to be pure.
+    name_rewriter: Option<fn(&Ident) -> Ident>,
 }
 
 impl<'a> TypeCheckContext<'a> {
@@ -93,8 +114,14 @@ impl<'a> TypeCheckContext<'a> {
             abi_mode: AbiMode::NonAbi,
             const_shadowing_mode: ConstShadowingMode::ItemStyle,
             purity: Purity::default(),
+            payable: false,
             kind: TreeType::Contract,
-            disallow_functions: false,
+            in_function_body: false,
+            function_return_type: None,
+            try_block_type: None,
+            const_context: false,
+            lint_config: None,
+            name_rewriter: None,
         }
     }
 
@@ -115,9 +142,15 @@ impl<'a> TypeCheckContext<'a> {
             const_shadowing_mode: self.const_shadowing_mode,
             help_text: self.help_text,
             purity: self.purity,
+            payable: self.payable,
             kind: self.kind.clone(),
             engines: self.engines,
-            disallow_functions: self.disallow_functions,
+            in_function_body: self.in_function_body,
+            function_return_type: self.function_return_type,
+            try_block_type: self.try_block_type,
+            const_context: self.const_context,
+            lint_config: self.lint_config,
+            name_rewriter: self.name_rewriter,
         }
     }
 
@@ -131,9 +164,15 @@ impl<'a> TypeCheckContext<'a> {
             const_shadowing_mode: self.const_shadowing_mode,
             help_text: self.help_text,
             purity: self.purity,
+            payable: self.payable,
             kind: self.kind,
             engines: self.engines,
-            disallow_functions: self.disallow_functions,
+            in_function_body: self.in_function_body,
+            function_return_type: self.function_return_type,
+            try_block_type: self.try_block_type,
+            const_context: self.const_context,
+            lint_config: self.lint_config,
+            name_rewriter: self.name_rewriter,
         }
     }
 
@@ -151,9 +190,16 @@ impl<'a> TypeCheckContext<'a> {
         // We're checking a submodule, so no need to pass through anything other than the
         // namespace. However, we will likely want to pass through the type engine and declaration
         // engine here once they're added.
-        let Self { namespace, .. } = self;
+        let Self {
+            namespace,
+            lint_config,
+            name_rewriter,
+            ..
+        } = self;
         let mut submod_ns = namespace.enter_submodule(mod_name, visibility, module_span);
-        let submod_ctx = TypeCheckContext::from_module_namespace(&mut submod_ns, self.engines);
+        let mut submod_ctx = TypeCheckContext::from_module_namespace(&mut submod_ns, self.engines);
+        submod_ctx.lint_config = lint_config;
+        submod_ctx.name_rewriter = name_rewriter;
         with_submod_ctx(submod_ctx)
     }
 
@@ -191,6 +237,11 @@ impl<'a> TypeCheckContext<'a> {
         Self { purity, ..self }
     }
 
+    /// Map this `TypeCheckContext` instance to a new one with the given payability.
+    pub(crate) fn with_payable(self, payable: bool) -> Self {
+        Self { payable, ..self }
+    }
+
     /// Map this `TypeCheckContext` instance to a new one with the given module kind.
     pub(crate) fn with_kind(self, kind: TreeType) -> Self {
         Self { kind, ..self }
@@ -202,19 +253,70 @@ impl<'a> TypeCheckContext<'a> {
     }
 
     /// Map this `TypeCheckContext` instance to a new one with
-    /// `disallow_functions` set to `true`.
-    pub(crate) fn disallow_functions(self) -> Self {
+    /// `in_function_body` set to `true`.
+    ///
+    /// A function body is never a const context on its own, even if the function is declared
+    /// within the initializer of a constant.
+    pub(crate) fn enter_function_body(self) -> Self {
+        Self {
+            in_function_body: true,
+            const_context: false,
+            ..self
+        }
+    }
+
+    /// Map this `TypeCheckContext` instance to a new one with the given
+    /// `function_return_type`.
+    pub(crate) fn with_function_return_type(self, function_return_type: TypeId) -> Self {
+        Self {
+            function_return_type: Some(function_return_type),
+            // The body of a function nested in a `try` block is outside of it.
+            try_block_type: None,
+            ..self
+        }
+    }
+
+    /// Map this `TypeCheckContext` instance to a new one with the given `try_block_type`.
+    pub(crate) fn with_try_block_type(self, try_block_type: TypeId) -> Self {
+        Self {
+            try_block_type: Some(try_block_type),
+            ..self
+        }
+    }
+
+    /// Map this `TypeCheckContext` instance to a new one with the given `lint_config`.
+    pub(crate) fn with_lint_config(self, lint_config: &'a LintConfig) -> Self {
+        Self {
+            lint_config: Some(lint_config),
+            ..self
+        }
+    }
+
+    /// Map this `TypeCheckContext` instance to a new one which renames the declared functions
+    /// in their selectors with the given `name_rewriter`.
+    ///
+    /// The rewritten names are stored as the `symbol_name` of the typed declarations and are
+    /// subject to the snake case check, while the namespace keeps the source names.
+    pub fn with_name_rewriter(self, name_rewriter: fn(&Ident) -> Ident) -> Self {
         Self {
-            disallow_functions: true,
+            name_rewriter: Some(name_rewriter),
+            ..self
+        }
+    }
+
+    /// Map this `TypeCheckContext` instance to a new one with `const_context` set to `true`.
+    pub(crate) fn with_const_context(self) -> Self {
+        Self {
+            const_context: true,
             ..self
         }
     }
 
     /// Map this `TypeCheckContext` instance to a new one with
-    /// `disallow_functions` set to `false`.
-    pub(crate) fn allow_functions(self) -> Self {
+    /// `in_function_body` set to `false`.
+    pub(crate) fn leave_function_body(self) -> Self {
         Self {
-            disallow_functions: false,
+            in_function_body: false,
             ..self
         }
     }
@@ -242,7 +344,10 @@ impl<'a> TypeCheckContext<'a> {
         self.purity
     }
 
-    #[allow(dead_code)]
+    pub(crate) fn payable(&self) -> bool {
+        self.payable
+    }
+
     pub(crate) fn kind(&self) -> TreeType {
         self.kind.clone()
     }
@@ -251,8 +356,29 @@ impl<'a> TypeCheckContext<'a> {
         self.self_type
     }
 
-    pub(crate) fn functions_disallowed(&self) -> bool {
-        self.disallow_functions
+    pub(crate) fn in_function_body(&self) -> bool {
+        self.in_function_body
+    }
+
+    pub(crate) fn function_return_type(&self) -> Option<TypeId> {
+        self.function_return_type
+    }
+
+    pub(crate) fn try_block_type(&self) -> Option<TypeId> {
+        self.try_block_type
+    }
+
+    pub(crate) fn const_context(&self) -> bool {
+        self.const_context
+    }
+
+    pub(crate) fn lint_config(&self) -> LintConfig {
+        self.lint_config.copied().unwrap_or_default()
+    }
+
+    /// Applies the name rewriter, if any, to the `name` of a function declaration.
+    pub(crate) fn rewrite_fn_name(&self, name: &Ident) -> Option<Ident> {
+        self.name_rewriter.map(|name_rewriter| name_rewriter(name))
     }
 
     // Provide some convenience functions around the inner context.
@@ -22,7 +22,9 @@
 
 use sway_ast::Literal;
 use sway_types::{
-    constants::{ALLOW_DEAD_CODE_NAME, CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME},
+    constants::{
+        CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME, REENTRANCY_GUARD_NAME, VALID_LINT_NAMES,
+    },
     Ident, Span, Spanned,
 };
 
@@ -60,6 +62,12 @@ pub enum AttributeKind {
     Inline,
     Test,
     Payable,
+    Selector,
+    AbiName,
+    AbiTransparent,
+    Reentrancy,
+    Fallback,
+    GasHint,
     Allow,
     Cfg,
 }
@@ -72,10 +80,16 @@ impl AttributeKind {
             AttributeKind::Doc => (0, None),
             AttributeKind::DocComment => (0, None),
             AttributeKind::Storage => (0, None),
-            AttributeKind::Inline => (0, None),
+            AttributeKind::Inline => (1, Some(1)),
             AttributeKind::Test => (0, None),
             AttributeKind::Payable => (0, None),
-            AttributeKind::Allow => (1, Some(1)),
+            AttributeKind::Selector => (1, Some(1)),
+            AttributeKind::AbiName => (1, Some(1)),
+            AttributeKind::AbiTransparent => (0, Some(0)),
+            AttributeKind::Reentrancy => (1, Some(1)),
+            AttributeKind::Fallback => (0, Some(0)),
+            AttributeKind::GasHint => (1, Some(1)),
+            AttributeKind::Allow => (1, None),
             AttributeKind::Cfg => (1, Some(1)),
         }
     }
@@ -89,7 +103,18 @@ impl AttributeKind {
             AttributeKind::Inline => None,
             AttributeKind::Test => None,
             AttributeKind::Payable => None,
-            AttributeKind::Allow => Some(vec![ALLOW_DEAD_CODE_NAME.to_string()]),
+            AttributeKind::Selector => None,
+            AttributeKind::AbiName => None,
+            AttributeKind::AbiTransparent => None,
+            AttributeKind::Reentrancy => Some(vec![REENTRANCY_GUARD_NAME.to_string()]),
+            AttributeKind::Fallback => None,
+            AttributeKind::GasHint => None,
+            AttributeKind::Allow => Some(
+                VALID_LINT_NAMES
+                    .iter()
+                    .map(|lint_name| lint_name.to_string())
+                    .collect(),
+            ),
             AttributeKind::Cfg => Some(vec![
                 CFG_TARGET_ARG_NAME.to_string(),
                 CFG_PROGRAM_TYPE_ARG_NAME.to_string(),
@@ -110,19 +135,45 @@ impl AttributesMap {
 
     /// Returns the first attribute by span, or None if there are no attributes.
     pub fn first(&self) -> Option<(&AttributeKind, &Attribute)> {
-        let mut first: Option<(&AttributeKind, &Attribute)> = None;
-        for (kind, attrs) in self.iter() {
-            for attr in attrs {
-                if let Some((_, first_attr)) = first {
-                    if attr.span.start() < first_attr.span.start() {
-                        first = Some((kind, attr));
-                    }
-                } else {
-                    first = Some((kind, attr));
-                }
-            }
-        }
-        first
+        self.in_order().into_iter().next()
+    }
+
+    /// Returns all the attributes in the order in which they are declared.
+    ///
+    /// The attributes of a single `#[..]` share its span, so they are ordered by the
+    /// positions of their names.
+    pub fn in_order(&self) -> Vec<(&AttributeKind, &Attribute)> {
+        let mut attrs: Vec<_> = self
+            .iter()
+            .flat_map(|(kind, attrs)| attrs.iter().map(move |attr| (kind, attr)))
+            .collect();
+        attrs.sort_by_key(|(_, attr)| (attr.span.start(), attr.name.span().start()));
+        attrs
+    }
+
+    /// Returns the selector name given by the last `#[selector("..")]` attribute, if it is a
+    /// string.
+    pub fn selector_override(&self) -> Option<&str> {
+        self.get(&AttributeKind::Selector)?
+            .last()?
+            .args
+            .first()?
+            .name
+            .as_str()
+            .strip_prefix('"')?
+            .strip_suffix('"')
+    }
+
+    /// Returns the name given by the last `#[abi_name("..")]` attribute, if it is a string.
+    pub fn abi_name_override(&self) -> Option<&str> {
+        self.get(&AttributeKind::AbiName)?
+            .last()?
+            .args
+            .first()?
+            .name
+            .as_str()
+            .strip_prefix('"')?
+            .strip_suffix('"')
     }
 
     pub fn inner(&self) -> &HashMap<AttributeKind, Vec<Attribute>> {
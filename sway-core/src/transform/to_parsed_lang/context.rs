@@ -14,6 +14,16 @@ pub struct Context {
     /// Unique suffix used to generate unique names for vars returned from `match` expressions
     match_expression_return_var_unique_suffix: usize,
 
+    /// Unique suffix used to generate unique names for the values of `?` expressions
+    try_var_unique_suffix: usize,
+
+    /// Unique suffix used to generate unique names for the values of `let ... else` statements
+    let_else_var_unique_suffix: usize,
+
+    /// Unique suffix used to generate unique names for the type parameters of `impl Trait`
+    /// arguments
+    impl_trait_param_unique_suffix: usize,
+
     /// The build target
     build_target: BuildTarget,
 
@@ -59,6 +69,26 @@ impl Context {
         self.match_expression_return_var_unique_suffix
     }
 
+    /// Returns a unique suffix used to generate a unique name for the value of a `?` expression
+    pub fn next_try_var_unique_suffix(&mut self) -> usize {
+        self.try_var_unique_suffix += 1;
+        self.try_var_unique_suffix
+    }
+
+    /// Returns a unique suffix used to generate a unique name for the value of a `let ... else`
+    /// statement
+    pub fn next_let_else_var_unique_suffix(&mut self) -> usize {
+        self.let_else_var_unique_suffix += 1;
+        self.let_else_var_unique_suffix
+    }
+
+    /// Returns a unique suffix used to generate a unique name for the type parameter of an
+    /// `impl Trait` argument
+    pub fn next_impl_trait_param_unique_suffix(&mut self) -> usize {
+        self.impl_trait_param_unique_suffix += 1;
+        self.impl_trait_param_unique_suffix
+    }
+
     /// Returns the build target
     pub fn build_target(&self) -> BuildTarget {
         self.build_target
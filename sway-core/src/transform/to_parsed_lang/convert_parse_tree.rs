@@ -1,4 +1,5 @@
 use crate::{
+    engine_threading::PartialEqWithEngines,
     language::{parsed::*, *},
     transform::{attribute::*, to_parsed_lang::context::Context},
     type_system::*,
@@ -9,38 +10,39 @@ use itertools::Itertools;
 use sway_ast::{
     attribute::Annotated,
     expr::{LoopControlFlow, ReassignmentOp, ReassignmentOpVariant},
+    keywords::{ElseToken, Token},
     ty::TyTupleDescriptor,
     AbiCastArgs, AngleBrackets, AsmBlock, Assignable, AttributeDecl, Braces, CodeBlockContents,
     CommaToken, DoubleColonToken, Expr, ExprArrayDescriptor, ExprStructField, ExprTupleDescriptor,
-    FnArg, FnArgs, FnSignature, GenericArgs, GenericParams, IfCondition, IfExpr, Instruction,
-    Intrinsic, Item, ItemAbi, ItemConfigurable, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemKind,
-    ItemStorage, ItemStruct, ItemTrait, ItemTraitItem, ItemTypeAlias, ItemUse, LitInt, LitIntType,
-    MatchBranchKind, Module, ModuleKind, Parens, PathExpr, PathExprSegment, PathType,
-    PathTypeSegment, Pattern, PatternStructField, PubToken, Punctuated, QualifiedPathRoot,
-    Statement, StatementLet, Submodule, Traits, Ty, TypeField, UseTree, WhereClause,
+    FnArg, FnArgs, FnSignature, GenericArgs, GenericParam, GenericParams, IfCondition, IfExpr,
+    ImplToken, Instruction, Intrinsic, Item, ItemAbi, ItemConfigurable, ItemConst, ItemEnum,
+    ItemFn, ItemImpl, ItemKind, ItemStorage, ItemStruct, ItemTrait, ItemTraitItem, ItemTypeAlias,
+    ItemUse, LitInt, LitIntType, MatchBranchKind, Module, ModuleKind, Parens, PathExpr,
+    PathExprSegment, PathType, PathTypeSegment, Pattern, PatternStructField, PubToken, Punctuated,
+    QualifiedPathRoot, Statement, StatementLet, Submodule, Traits, Ty, TypeField, UseTree,
+    WhereClause,
 };
 use sway_error::convert_parse_tree_error::ConvertParseTreeError;
+use sway_error::error::CompileError;
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_error::warning::{CompileWarning, Warning};
 use sway_types::{
     constants::{
-        ALLOW_ATTRIBUTE_NAME, CFG_ATTRIBUTE_NAME, CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME,
-        DESTRUCTURE_PREFIX, DOC_ATTRIBUTE_NAME, DOC_COMMENT_ATTRIBUTE_NAME, INLINE_ATTRIBUTE_NAME,
-        MATCH_RETURN_VAR_NAME_PREFIX, PAYABLE_ATTRIBUTE_NAME, STORAGE_PURITY_ATTRIBUTE_NAME,
-        STORAGE_PURITY_READ_NAME, STORAGE_PURITY_WRITE_NAME, TEST_ATTRIBUTE_NAME,
-        TUPLE_NAME_PREFIX, VALID_ATTRIBUTE_NAMES,
+        ABI_NAME_ATTRIBUTE_NAME, ABI_TRANSPARENT_ATTRIBUTE_NAME, ALLOW_ATTRIBUTE_NAME,
+        CFG_ATTRIBUTE_NAME, CFG_ATTR_ATTRIBUTE_NAME, CFG_PROGRAM_TYPE_ARG_NAME,
+        CFG_TARGET_ARG_NAME, DESTRUCTURE_PREFIX, DOC_ATTRIBUTE_NAME, DOC_COMMENT_ATTRIBUTE_NAME,
+        FALLBACK_ATTRIBUTE_NAME, GAS_HINT_ATTRIBUTE_NAME, IMPL_TRAIT_PARAM_NAME_PREFIX,
+        INLINE_ATTRIBUTE_NAME, LET_ELSE_VAR_NAME_PREFIX, MATCH_RETURN_VAR_NAME_PREFIX,
+        PAYABLE_ATTRIBUTE_NAME, REENTRANCY_ATTRIBUTE_NAME, SELECTOR_ATTRIBUTE_NAME,
+        STORAGE_PURITY_ATTRIBUTE_NAME, STORAGE_PURITY_READ_NAME, STORAGE_PURITY_WRITE_NAME,
+        TEST_ATTRIBUTE_NAME, TRY_VAR_NAME_PREFIX, TUPLE_NAME_PREFIX, VALID_ATTRIBUTE_NAMES,
     },
     integer_bits::IntegerBits,
 };
 use sway_types::{Ident, Span, Spanned};
 
 use std::{
-    collections::{HashMap, HashSet},
-    convert::TryFrom,
-    iter,
-    mem::MaybeUninit,
-    str::FromStr,
-    sync::Arc,
+    collections::HashMap, convert::TryFrom, iter, mem::MaybeUninit, str::FromStr, sync::Arc,
 };
 
 pub fn convert_parse_tree(
@@ -460,13 +462,26 @@ fn item_fn_to_function_declaration(
     context: &mut Context,
     handler: &Handler,
     engines: &Engines,
-    item_fn: ItemFn,
+    mut item_fn: ItemFn,
     attributes: AttributesMap,
     parent_generic_params_opt: Option<GenericParams>,
     parent_where_clause_opt: Option<WhereClause>,
 ) -> Result<FunctionDeclaration, ErrorEmitted> {
     let span = item_fn.span();
+    let impl_trait_type_parameters = impl_trait_args_to_type_parameters(
+        context,
+        handler,
+        engines,
+        &mut item_fn.fn_signature.arguments.inner,
+    )?;
     let return_type = match item_fn.fn_signature.return_type_opt {
+        Some((
+            _right_arrow,
+            Ty::ImplTrait {
+                impl_token,
+                trait_bounds,
+            },
+        )) => impl_trait_to_type_argument(context, handler, engines, impl_token, *trait_bounds)?,
         Some((_right_arrow, ty)) => ty_to_type_argument(context, handler, engines, ty)?,
         None => {
             let type_id = engines.te().insert(engines, TypeInfo::Tuple(Vec::new()));
@@ -478,11 +493,15 @@ fn item_fn_to_function_declaration(
             }
         }
     };
+    let is_const = item_fn.fn_signature.const_token.is_some();
     Ok(FunctionDeclaration {
         purity: get_attributed_purity(context, handler, &attributes)?,
         attributes,
         name: item_fn.fn_signature.name,
-        visibility: pub_token_opt_to_visibility(item_fn.fn_signature.visibility),
+        visibility: match item_fn.fn_signature.package_scope_opt {
+            Some(_) => Visibility::Package,
+            None => pub_token_opt_to_visibility(item_fn.fn_signature.visibility),
+        },
         body: braced_code_block_contents_to_code_block(context, handler, engines, item_fn.body)?,
         parameters: fn_args_to_function_parameters(
             context,
@@ -500,7 +519,10 @@ fn item_fn_to_function_declaration(
             parent_generic_params_opt,
             item_fn.fn_signature.where_clause_opt.clone(),
             parent_where_clause_opt,
-        )?,
+        )?
+        .into_iter()
+        .chain(impl_trait_type_parameters)
+        .collect(),
         where_clause: item_fn
             .fn_signature
             .where_clause_opt
@@ -509,6 +531,7 @@ fn item_fn_to_function_declaration(
             })
             .transpose()?
             .unwrap_or(vec![]),
+        is_const,
     })
 }
 
@@ -527,18 +550,33 @@ fn get_attributed_purity(
     };
     match attributes.get(&AttributeKind::Storage) {
         Some(attrs) if !attrs.is_empty() => {
+            // A `storage` attribute must name at least one storage access.
+            if let Some(attr) = attrs.iter().find(|attr| attr.args.is_empty()) {
+                return Err(handler.emit_err(CompileError::InvalidStorageAttribute {
+                    span: attr.span.clone(),
+                }));
+            }
+            let mut seen_args: Vec<&Ident> = vec![];
             for arg in attrs.iter().flat_map(|attr| &attr.args) {
                 match arg.name.as_str() {
                     STORAGE_PURITY_READ_NAME => add_impurity(Purity::Reads, Purity::Writes),
                     STORAGE_PURITY_WRITE_NAME => add_impurity(Purity::Writes, Purity::Reads),
                     _otherwise => {
-                        let error = ConvertParseTreeError::InvalidAttributeArgument {
-                            attribute: "storage".to_owned(),
-                            span: arg.span(),
-                        };
-                        return Err(handler.emit_err(error.into()));
+                        return Err(handler
+                            .emit_err(CompileError::InvalidStorageAttribute { span: arg.span() }));
                     }
                 }
+                // Repeating an access is harmless, but likely a mistake.
+                if seen_args.contains(&&arg.name) {
+                    handler.emit_warn(CompileWarning {
+                        span: arg.span(),
+                        warning_content: Warning::DuplicateStorageAttributeArgument {
+                            arg_name: arg.name.clone(),
+                        },
+                    });
+                } else {
+                    seen_args.push(&arg.name);
+                }
             }
             Ok(purity)
         }
@@ -1073,33 +1111,41 @@ fn generic_params_opt_to_type_parameters_with_parent(
             .parameters
             .into_inner()
             .into_iter()
-            .map(|ident| {
+            .map(|GenericParam { name, bounds_opt }| {
                 let custom_type = type_engine.insert(
                     engines,
                     TypeInfo::Custom {
-                        call_path: ident.clone().into(),
+                        call_path: name.clone().into(),
                         type_arguments: None,
                     },
                 );
-                TypeParameter {
+                let inline_bounds = bounds_opt.map(|(_colon_token, bounds)| (name.clone(), bounds));
+                let param = TypeParameter {
                     type_id: custom_type,
                     initial_type_id: custom_type,
-                    name_ident: ident,
+                    name_ident: name,
                     trait_constraints: Vec::new(),
                     trait_constraints_span: Span::dummy(),
                     is_from_parent,
-                }
+                };
+                (param, inline_bounds)
             })
-            .collect::<Vec<_>>(),
-        None => Vec::new(),
+            .unzip::<_, _, Vec<_>, Vec<_>>(),
+        None => (Vec::new(), Vec::new()),
     };
 
-    let mut params = generics_to_params(generic_params_opt, false);
-    let parent_params = generics_to_params(parent_generic_params_opt, true);
+    let (mut params, inline_trait_constraints) = generics_to_params(generic_params_opt, false);
+    let (parent_params, parent_inline_trait_constraints) =
+        generics_to_params(parent_generic_params_opt, true);
 
+    // Inline bounds (`<T: A>`) and `where` bounds on the same parameter are merged into a single
+    // set of trait constraints.
     let mut errors = Vec::new();
-    for (ty_name, bounds) in trait_constraints
+    for (ty_name, bounds) in inline_trait_constraints
         .into_iter()
+        .flatten()
+        .chain(trait_constraints)
+        .chain(parent_inline_trait_constraints.into_iter().flatten())
         .chain(parent_trait_constraints)
     {
         let param_to_edit = if let Some(o) = params
@@ -1121,13 +1167,29 @@ fn generic_params_opt_to_type_parameters_with_parent(
             continue;
         };
 
-        param_to_edit.trait_constraints_span = Span::join(ty_name.span(), bounds.span());
+        param_to_edit.trait_constraints_span = if param_to_edit.trait_constraints.is_empty() {
+            Span::join(ty_name.span(), bounds.span())
+        } else {
+            Span::join(param_to_edit.trait_constraints_span.clone(), bounds.span())
+        };
 
-        param_to_edit
-            .trait_constraints
-            .extend(traits_to_trait_constraints(
-                context, handler, engines, bounds,
-            )?);
+        for trait_constraint in traits_to_trait_constraints(context, handler, engines, bounds)? {
+            if param_to_edit
+                .trait_constraints
+                .iter()
+                .any(|existing| existing.eq(&trait_constraint, engines))
+            {
+                handler.emit_warn(CompileWarning {
+                    span: trait_constraint.span(),
+                    warning_content: Warning::RedundantTraitBound {
+                        type_parameter: param_to_edit.name_ident.clone(),
+                        trait_name: trait_constraint.trait_name.suffix.to_string(),
+                    },
+                });
+                continue;
+            }
+            param_to_edit.trait_constraints.push(trait_constraint);
+        }
     }
     if let Some(errors) = emit_all(handler, errors) {
         return Err(errors);
@@ -1224,6 +1286,8 @@ fn fn_args_to_function_parameters(
                     span: self_token.span(),
                     call_path_tree: None,
                 },
+                default_value: None,
+                attributes: AttributesMap::default(),
             }];
             if let Some((_comma_token, args)) = args_opt {
                 for arg in args {
@@ -1236,18 +1300,6 @@ fn fn_args_to_function_parameters(
         }
     };
 
-    let mut unique_params = HashSet::<Ident>::default();
-    for fn_param in &function_parameters {
-        let already_used = !unique_params.insert(fn_param.name.clone());
-        if already_used {
-            let error = ConvertParseTreeError::DuplicateParameterIdentifier {
-                name: fn_param.name.clone(),
-                span: fn_param.name.span(),
-            };
-            return Err(handler.emit_err(error.into()));
-        }
-    }
-
     Ok(function_parameters)
 }
 
@@ -1288,7 +1340,7 @@ fn ty_to_type_info(
             let ty_array_descriptor = bracketed_ty_array_descriptor.into_inner();
             TypeInfo::Array(
                 ty_to_type_argument(context, handler, engines, *ty_array_descriptor.ty)?,
-                expr_to_length(context, handler, *ty_array_descriptor.length)?,
+                expr_to_array_length(context, handler, *ty_array_descriptor.length)?,
             )
         }
         Ty::Str { length, .. } => {
@@ -1303,6 +1355,44 @@ fn ty_to_type_info(
             let type_argument = ty_to_type_argument(context, handler, engines, *ty.into_inner())?;
             TypeInfo::Slice(type_argument)
         }
+        // `!` stands for the empty `Never` enum of the core library, which can be coerced to
+        // any other type.
+        Ty::Never { bang_token } => {
+            let span = bang_token.span();
+            TypeInfo::Custom {
+                call_path: CallPath {
+                    prefixes: vec![
+                        Ident::new_with_override("core".into(), span.clone()),
+                        Ident::new_with_override("never".into(), span.clone()),
+                    ],
+                    suffix: Ident::new_with_override("Never".into(), span),
+                    is_absolute: true,
+                },
+                type_arguments: None,
+            }
+        }
+        Ty::ImplTrait {
+            impl_token,
+            trait_bounds,
+        } => {
+            let span = Span::join(impl_token.span(), trait_bounds.span());
+            return Err(handler.emit_err(CompileError::ImplTraitNotAllowedHere { span }));
+        }
+        // The parser only accepts `u64, bool` as a return type, where it means `(u64, bool)`.
+        Ty::UnparenthesizedTuple {
+            head,
+            comma_token,
+            tail,
+        } => TypeInfo::Tuple(ty_tuple_descriptor_to_type_arguments(
+            context,
+            handler,
+            engines,
+            TyTupleDescriptor::Cons {
+                head,
+                comma_token,
+                tail,
+            },
+        )?),
     };
     Ok(type_info)
 }
@@ -1386,6 +1476,103 @@ fn ty_to_type_argument(
     Ok(type_argument)
 }
 
+/// Converts the `impl Trait` return type of a function to a placeholder bounded by the traits.
+/// The placeholder is replaced by the type of the returned values when the body of the
+/// function is type checked.
+fn impl_trait_to_type_argument(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    impl_token: ImplToken,
+    trait_bounds: Traits,
+) -> Result<TypeArgument, ErrorEmitted> {
+    let type_engine = engines.te();
+    let span = Span::join(impl_token.span(), trait_bounds.span());
+    let trait_constraints_span = trait_bounds.span();
+    let trait_constraints = traits_to_trait_constraints(context, handler, engines, trait_bounds)?;
+    let unknown_type = type_engine.insert(engines, TypeInfo::Unknown);
+    let type_parameter = TypeParameter {
+        type_id: unknown_type,
+        initial_type_id: unknown_type,
+        name_ident: Ident::new(span.clone()),
+        trait_constraints,
+        trait_constraints_span,
+        is_from_parent: false,
+    };
+    let type_id = type_engine.insert(engines, TypeInfo::Placeholder(type_parameter));
+    Ok(TypeArgument {
+        type_id,
+        initial_type_id: type_id,
+        call_path_tree: None,
+        span,
+    })
+}
+
+/// Replaces every `impl Trait` argument type in `fn_args` with a fresh, compiler generated type
+/// parameter constrained by the same trait bounds, and returns those type parameters.
+fn impl_trait_args_to_type_parameters(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    fn_args: &mut FnArgs,
+) -> Result<Vec<TypeParameter>, ErrorEmitted> {
+    let args = match fn_args {
+        FnArgs::Static(args) => Some(args),
+        FnArgs::NonStatic { args_opt, .. } => args_opt.as_mut().map(|(_comma_token, args)| args),
+    };
+    let args = match args {
+        Some(args) => args,
+        None => return Ok(Vec::new()),
+    };
+    let mut type_parameters = Vec::new();
+    for fn_arg in args
+        .value_separator_pairs
+        .iter_mut()
+        .map(|(fn_arg, _comma_token)| fn_arg)
+        .chain(args.final_value_opt.iter_mut().map(|fn_arg| &mut **fn_arg))
+    {
+        let trait_bounds = match &fn_arg.ty {
+            Ty::ImplTrait { trait_bounds, .. } => (**trait_bounds).clone(),
+            _ => continue,
+        };
+        let name_ident = Ident::new_with_override(
+            format!(
+                "{}{}",
+                IMPL_TRAIT_PARAM_NAME_PREFIX,
+                context.next_impl_trait_param_unique_suffix()
+            ),
+            fn_arg.ty.span(),
+        );
+        let trait_constraints_span = trait_bounds.span();
+        let trait_constraints =
+            traits_to_trait_constraints(context, handler, engines, trait_bounds)?;
+        let custom_type = engines.te().insert(
+            engines,
+            TypeInfo::Custom {
+                call_path: name_ident.clone().into(),
+                type_arguments: None,
+            },
+        );
+        type_parameters.push(TypeParameter {
+            type_id: custom_type,
+            initial_type_id: custom_type,
+            name_ident: name_ident.clone(),
+            trait_constraints,
+            trait_constraints_span,
+            is_from_parent: false,
+        });
+        fn_arg.ty = Ty::Path(PathType {
+            root_opt: None,
+            prefix: PathTypeSegment {
+                name: name_ident,
+                generics_opt: None,
+            },
+            suffix: Vec::new(),
+        });
+    }
+    Ok(type_parameters)
+}
+
 fn fn_signature_to_trait_fn(
     context: &mut Context,
     handler: &Handler,
@@ -1871,71 +2058,7 @@ fn expr_to_expression(
         Expr::If(if_expr) => if_expr_to_expression(context, handler, engines, if_expr)?,
         Expr::Match {
             value, branches, ..
-        } => {
-            let value = expr_to_expression(context, handler, engines, *value)?;
-            let var_decl_span = value.span();
-
-            // Generate a deterministic name for the variable returned by the match expression.
-            let match_return_var_name = format!(
-                "{}{}",
-                MATCH_RETURN_VAR_NAME_PREFIX,
-                context.next_match_expression_return_var_unique_suffix(),
-            );
-            let var_decl_name =
-                Ident::new_with_override(match_return_var_name, var_decl_span.clone());
-
-            let var_decl_exp = Expression {
-                kind: ExpressionKind::Variable(var_decl_name.clone()),
-                span: var_decl_span,
-            };
-            let branches = {
-                branches
-                    .into_inner()
-                    .into_iter()
-                    .map(|match_branch| {
-                        match_branch_to_match_branch(context, handler, engines, match_branch)
-                    })
-                    .collect::<Result<_, _>>()?
-            };
-            Expression {
-                kind: ExpressionKind::CodeBlock(CodeBlock {
-                    contents: vec![
-                        AstNode {
-                            content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
-                                VariableDeclaration {
-                                    type_ascription: {
-                                        let type_id =
-                                            engines.te().insert(engines, TypeInfo::Unknown);
-                                        TypeArgument {
-                                            type_id,
-                                            initial_type_id: type_id,
-                                            span: var_decl_name.span(),
-                                            call_path_tree: None,
-                                        }
-                                    },
-                                    name: var_decl_name,
-                                    is_mutable: false,
-                                    body: value,
-                                },
-                            )),
-                            span: span.clone(),
-                        },
-                        AstNode {
-                            content: AstNodeContent::ImplicitReturnExpression(Expression {
-                                kind: ExpressionKind::Match(MatchExpression {
-                                    value: Box::new(var_decl_exp),
-                                    branches,
-                                }),
-                                span: span.clone(),
-                            }),
-                            span: span.clone(),
-                        },
-                    ],
-                    whole_block_span: span.clone(),
-                }),
-                span,
-            }
-        }
+        } => match_expr_to_expression(context, handler, engines, *value, branches, span)?,
         Expr::While {
             condition, block, ..
         } => Expression {
@@ -1945,10 +2068,16 @@ fn expr_to_expression(
             }),
             span,
         },
+        Expr::TryBlock { block, .. } => {
+            try_block_to_expression(context, handler, engines, block, span)?
+        }
         Expr::FuncApp { func, args } => {
             let kind = expr_func_app_to_expression_kind(context, handler, engines, func, args)?;
             Expression { kind, span }
         }
+        Expr::NamedArg { name, value, .. } => {
+            named_arg_to_expression(context, handler, engines, name, *value, span)?
+        }
         Expr::Index { target, arg } => Expression {
             kind: ExpressionKind::ArrayIndex(ArrayIndexExpression {
                 prefix: Box::new(expr_to_expression(context, handler, engines, *target)?),
@@ -2040,6 +2169,7 @@ fn expr_to_expression(
             }),
             span,
         },
+        Expr::Try { expr, .. } => try_expr_to_expression(context, handler, engines, *expr, span)?,
         Expr::Ref { ref_token, .. } => {
             let error = ConvertParseTreeError::RefExprNotYetSupported {
                 span: ref_token.span(),
@@ -2275,6 +2405,170 @@ fn expr_to_expression(
     Ok(expression)
 }
 
+fn match_expr_to_expression(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    value: Expr,
+    branches: Braces<Vec<sway_ast::MatchBranch>>,
+    span: Span,
+) -> Result<Expression, ErrorEmitted> {
+    let value = expr_to_expression(context, handler, engines, value)?;
+    let var_decl_span = value.span();
+
+    // Generate a deterministic name for the variable returned by the match expression.
+    let match_return_var_name = format!(
+        "{}{}",
+        MATCH_RETURN_VAR_NAME_PREFIX,
+        context.next_match_expression_return_var_unique_suffix(),
+    );
+    let var_decl_name = Ident::new_with_override(match_return_var_name, var_decl_span.clone());
+
+    let var_decl_exp = Expression {
+        kind: ExpressionKind::Variable(var_decl_name.clone()),
+        span: var_decl_span,
+    };
+    let branches = {
+        branches
+            .into_inner()
+            .into_iter()
+            .map(|match_branch| {
+                match_branch_to_match_branch(context, handler, engines, match_branch)
+            })
+            .collect::<Result<_, _>>()?
+    };
+    Ok(Expression {
+        kind: ExpressionKind::CodeBlock(CodeBlock {
+            contents: vec![
+                AstNode {
+                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                        VariableDeclaration {
+                            type_ascription: {
+                                let type_id = engines.te().insert(engines, TypeInfo::Unknown);
+                                TypeArgument {
+                                    type_id,
+                                    initial_type_id: type_id,
+                                    span: var_decl_name.span(),
+                                    call_path_tree: None,
+                                }
+                            },
+                            name: var_decl_name,
+                            is_mutable: false,
+                            body: value,
+                        },
+                    )),
+                    span: span.clone(),
+                },
+                AstNode {
+                    content: AstNodeContent::ImplicitReturnExpression(Expression {
+                        kind: ExpressionKind::Match(MatchExpression {
+                            value: Box::new(var_decl_exp),
+                            branches,
+                        }),
+                        span: span.clone(),
+                    }),
+                    span: span.clone(),
+                },
+            ],
+            whole_block_span: span.clone(),
+        }),
+        span,
+    })
+}
+
+fn try_block_to_expression(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    block: Braces<CodeBlockContents>,
+    span: Span,
+) -> Result<Expression, ErrorEmitted> {
+    Ok(Expression {
+        kind: ExpressionKind::TryBlock(braced_code_block_contents_to_code_block(
+            context, handler, engines, block,
+        )?),
+        span,
+    })
+}
+
+fn named_arg_to_expression(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    name: Ident,
+    value: Expr,
+    span: Span,
+) -> Result<Expression, ErrorEmitted> {
+    Ok(Expression {
+        kind: ExpressionKind::NamedArgument(NamedArgumentExpression {
+            name,
+            value: Box::new(expr_to_expression(context, handler, engines, value)?),
+        }),
+        span,
+    })
+}
+
+/// Desugars `expr?` into a block which binds the value of `expr` to a variable and returns the
+/// result of trying it.
+fn try_expr_to_expression(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    expr: Expr,
+    span: Span,
+) -> Result<Expression, ErrorEmitted> {
+    let value = expr_to_expression(context, handler, engines, expr)?;
+    let var_decl_span = value.span();
+
+    // The value is bound to a variable, so that it is evaluated only once even though it
+    // is inspected several times.
+    let try_var_name = format!(
+        "{}{}",
+        TRY_VAR_NAME_PREFIX,
+        context.next_try_var_unique_suffix(),
+    );
+    let var_decl_name = Ident::new_with_override(try_var_name, var_decl_span.clone());
+
+    let var_decl_exp = Expression {
+        kind: ExpressionKind::Variable(var_decl_name.clone()),
+        span: var_decl_span,
+    };
+    Ok(Expression {
+        kind: ExpressionKind::CodeBlock(CodeBlock {
+            contents: vec![
+                AstNode {
+                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                        VariableDeclaration {
+                            type_ascription: {
+                                let type_id = engines.te().insert(engines, TypeInfo::Unknown);
+                                TypeArgument {
+                                    type_id,
+                                    initial_type_id: type_id,
+                                    span: var_decl_name.span(),
+                                    call_path_tree: None,
+                                }
+                            },
+                            name: var_decl_name,
+                            is_mutable: false,
+                            body: value,
+                        },
+                    )),
+                    span: span.clone(),
+                },
+                AstNode {
+                    content: AstNodeContent::ImplicitReturnExpression(Expression {
+                        kind: ExpressionKind::Try(Box::new(var_decl_exp)),
+                        span: span.clone(),
+                    }),
+                    span: span.clone(),
+                },
+            ],
+            whole_block_span: span.clone(),
+        }),
+        span,
+    })
+}
+
 fn op_call(
     name: &'static str,
     op_span: Span,
@@ -2384,6 +2678,7 @@ fn fn_arg_to_function_parameter(
     engines: &Engines,
     fn_arg: FnArg,
 ) -> Result<FunctionParameter, ErrorEmitted> {
+    let attributes = fn_arg_attrs_to_map(context, handler, &fn_arg.attribute_list)?;
     let pat_span = fn_arg.pattern.span();
     let (reference, mutable, name) = match fn_arg.pattern {
         Pattern::Wildcard { .. } => {
@@ -2434,10 +2729,36 @@ fn fn_arg_to_function_parameter(
         is_mutable: mutable.is_some(),
         mutability_span,
         type_argument: ty_to_type_argument(context, handler, engines, fn_arg.ty)?,
+        default_value: match fn_arg.default_opt {
+            Some((_eq_token, expr)) => Some(expr_to_expression(context, handler, engines, expr)?),
+            None => None,
+        },
+        attributes,
     };
     Ok(function_parameter)
 }
 
+/// Converts the attributes of a function argument. Parameters can only be documented, so any
+/// attribute other than a doc comment or `#[doc(..)]` is an error.
+fn fn_arg_attrs_to_map(
+    context: &mut Context,
+    handler: &Handler,
+    attribute_list: &[AttributeDecl],
+) -> Result<AttributesMap, ErrorEmitted> {
+    for attr_decl in attribute_list {
+        for attr in attr_decl.attribute.get().into_iter() {
+            let name = attr.name.as_str();
+            if name != DOC_ATTRIBUTE_NAME && name != DOC_COMMENT_ATTRIBUTE_NAME {
+                handler.emit_err(CompileError::InvalidAttributeOnParameter {
+                    attribute: attr.name.clone(),
+                    span: attr_decl.span(),
+                });
+            }
+        }
+    }
+    item_attrs_to_map(context, handler, attribute_list)
+}
+
 fn expr_to_length(
     context: &mut Context,
     handler: &Handler,
@@ -2447,6 +2768,23 @@ fn expr_to_length(
     Ok(Length::new(expr_to_usize(context, handler, expr)?, span))
 }
 
+/// Like [expr_to_length], but the length of an array may also be given by a constant, which
+/// is evaluated when the array type is resolved.
+fn expr_to_array_length(
+    context: &mut Context,
+    handler: &Handler,
+    expr: Expr,
+) -> Result<Length, ErrorEmitted> {
+    let span = expr.span();
+    match expr {
+        Expr::Path(path_expr) => Ok(Length::new_const(
+            path_expr_to_call_path(context, handler, path_expr)?,
+            span,
+        )),
+        expr => expr_to_length(context, handler, expr),
+    }
+}
+
 fn expr_to_usize(
     _context: &mut Context,
     handler: &Handler,
@@ -3445,17 +3783,189 @@ fn statement_let_to_ast_nodes(
         };
         Ok(ast_nodes)
     }
+    #[allow(clippy::too_many_arguments)]
+    fn unfold_let_else(
+        context: &mut Context,
+        handler: &Handler,
+        engines: &Engines,
+        pattern: Pattern,
+        ty_opt: Option<Ty>,
+        expression: Expression,
+        (else_token, else_block): (ElseToken, Braces<CodeBlockContents>),
+        span: Span,
+    ) -> Result<Vec<AstNode>, ErrorEmitted> {
+        // `let PAT: TY = EXPR else { BLOCK };` is desugared into:
+        //
+        //     let __let_else_var_name_N: TY = EXPR;
+        //     let (a, b, ..) = match __let_else_var_name_N {
+        //         PAT => (a, b, ..),
+        //         _ => { BLOCK },
+        //     };
+        //
+        // where `a, b, ..` are the variables bound by `PAT`. The else block has to diverge, which
+        // is checked along with its type, and lets the `match` take the type of the bindings, so
+        // that the code after the statement sees them with their narrowed types.
+        let value_name = Ident::new_with_override(
+            format!(
+                "{}{}",
+                LET_ELSE_VAR_NAME_PREFIX,
+                context.next_let_else_var_unique_suffix()
+            ),
+            expression.span.clone(),
+        );
+        let mut ast_nodes = unfold(
+            context,
+            handler,
+            engines,
+            Pattern::Var {
+                reference: None,
+                mutable: None,
+                name: value_name.clone(),
+            },
+            ty_opt,
+            expression,
+            span.clone(),
+        )?;
+
+        let bindings = pattern_bindings(&pattern);
+        let binding_exprs = bindings
+            .iter()
+            .map(|binding| {
+                let name = match binding {
+                    Pattern::Var { name, .. } | Pattern::AmbiguousSingleIdent(name) => name.clone(),
+                    _ => unreachable!("bindings are variable patterns"),
+                };
+                Expression {
+                    span: name.span(),
+                    kind: ExpressionKind::Variable(name),
+                }
+            })
+            .collect::<Vec<_>>();
+        let (bindings_pattern, bindings_expr) = if bindings.len() == 1 {
+            (
+                bindings.into_iter().next().unwrap(),
+                binding_exprs.into_iter().next().unwrap(),
+            )
+        } else {
+            let tuple_pattern = Pattern::Tuple(Parens::new(
+                Punctuated {
+                    value_separator_pairs: bindings
+                        .into_iter()
+                        .map(|binding| (binding, CommaToken::new(span.clone())))
+                        .collect(),
+                    final_value_opt: None,
+                },
+                span.clone(),
+            ));
+            let expr = Expression {
+                kind: ExpressionKind::Tuple(binding_exprs),
+                span: span.clone(),
+            };
+            (tuple_pattern, expr)
+        };
+
+        let pattern_span = pattern.span();
+        let else_block_span = Span::join(else_token.span(), else_block.span());
+        let branches = vec![
+            MatchBranch {
+                scrutinee: pattern_to_scrutinee(context, handler, pattern)?,
+                result: bindings_expr,
+                span: pattern_span,
+            },
+            MatchBranch {
+                scrutinee: Scrutinee::CatchAll {
+                    span: else_token.span(),
+                },
+                result: Expression {
+                    kind: ExpressionKind::LetElseBlock(braced_code_block_contents_to_code_block(
+                        context, handler, engines, else_block,
+                    )?),
+                    span: else_block_span.clone(),
+                },
+                span: else_block_span,
+            },
+        ];
+        let match_expression = Expression {
+            kind: ExpressionKind::Match(MatchExpression {
+                value: Box::new(Expression {
+                    span: value_name.span(),
+                    kind: ExpressionKind::Variable(value_name),
+                }),
+                branches,
+            }),
+            span: span.clone(),
+        };
+        ast_nodes.extend(unfold(
+            context,
+            handler,
+            engines,
+            bindings_pattern,
+            None,
+            match_expression,
+            span,
+        )?);
+        Ok(ast_nodes)
+    }
     let span = statement_let.span();
     let initial_expression = expr_to_expression(context, handler, engines, statement_let.expr)?;
-    unfold(
-        context,
-        handler,
-        engines,
-        statement_let.pattern,
-        statement_let.ty_opt.map(|(_colon_token, ty)| ty),
-        initial_expression,
-        span,
-    )
+    let ty_opt = statement_let.ty_opt.map(|(_colon_token, ty)| ty);
+    match statement_let.else_opt {
+        Some(else_opt) => unfold_let_else(
+            context,
+            handler,
+            engines,
+            statement_let.pattern,
+            ty_opt,
+            initial_expression,
+            else_opt,
+            span,
+        ),
+        None => unfold(
+            context,
+            handler,
+            engines,
+            statement_let.pattern,
+            ty_opt,
+            initial_expression,
+            span,
+        ),
+    }
+}
+
+/// Returns the variable patterns of the variables bound by `pattern`, in the order of appearance.
+fn pattern_bindings(pattern: &Pattern) -> Vec<Pattern> {
+    match pattern {
+        Pattern::Var { .. } | Pattern::AmbiguousSingleIdent(..) => vec![pattern.clone()],
+        // Both sides of an or-pattern have to bind the same variables.
+        Pattern::Or { lhs, .. } => pattern_bindings(lhs),
+        Pattern::Constructor { args, .. } => {
+            args.get().into_iter().flat_map(pattern_bindings).collect()
+        }
+        Pattern::Struct { fields, .. } => fields
+            .get()
+            .into_iter()
+            .flat_map(|field| match field {
+                PatternStructField::Field {
+                    pattern_opt: Some((_colon_token, pattern)),
+                    ..
+                } => pattern_bindings(pattern),
+                PatternStructField::Field {
+                    field_name,
+                    pattern_opt: None,
+                } => vec![Pattern::AmbiguousSingleIdent(field_name.clone())],
+                PatternStructField::Rest { .. } => vec![],
+            })
+            .collect(),
+        Pattern::Tuple(pat_tuple) => pat_tuple
+            .get()
+            .into_iter()
+            .flat_map(pattern_bindings)
+            .collect(),
+        Pattern::Wildcard { .. }
+        | Pattern::Literal(..)
+        | Pattern::Constant(..)
+        | Pattern::Error(..) => vec![],
+    }
 }
 
 fn submodule_to_include_statement(dependency: &Submodule) -> IncludeStatement {
@@ -3659,6 +4169,11 @@ fn ty_to_type_parameter(
         Ty::Str { .. } => panic!("str types are not allowed in this position"),
         Ty::Ptr { .. } => panic!("__ptr types are not allowed in this position"),
         Ty::Slice { .. } => panic!("__slice types are not allowed in this position"),
+        Ty::Never { .. } => panic!("never types are not allowed in this position"),
+        Ty::ImplTrait { .. } => panic!("impl trait types are not allowed in this position"),
+        Ty::UnparenthesizedTuple { .. } => {
+            panic!("tuple types are not allowed in this position")
+        }
     };
     let custom_type = type_engine.insert(
         engines,
@@ -3998,27 +4513,39 @@ where
 }
 
 fn item_attrs_to_map(
-    _context: &mut Context,
+    context: &mut Context,
     handler: &Handler,
     attribute_list: &[AttributeDecl],
 ) -> Result<AttributesMap, ErrorEmitted> {
     let mut attrs_map: HashMap<_, Vec<Attribute>> = HashMap::new();
+    // The attributes of the `cfg_attr`s whose predicate doesn't hold. They are validated like the
+    // others, but not applied.
+    let mut unapplied_attrs = Vec::new();
     for attr_decl in attribute_list {
         let attrs = attr_decl.attribute.get().into_iter();
         for attr in attrs {
-            let name = attr.name.as_str();
+            let (attr_name, attr_args, is_applied) =
+                if attr.name.as_str() == CFG_ATTR_ATTRIBUTE_NAME {
+                    match cfg_attr_eval(context, handler, attr)? {
+                        Some((inner_attr, is_applied)) => {
+                            (&inner_attr.name, inner_attr.args.as_ref(), is_applied)
+                        }
+                        None => continue,
+                    }
+                } else {
+                    (&attr.name, attr.args.as_ref(), true)
+                };
+            let name = attr_name.as_str();
             if !VALID_ATTRIBUTE_NAMES.contains(&name) {
                 handler.emit_warn(CompileWarning {
                     span: attr_decl.span().clone(),
                     warning_content: Warning::UnrecognizedAttribute {
-                        attrib_name: attr.name.clone(),
+                        attrib_name: attr_name.clone(),
                     },
                 })
             }
 
-            let args = attr
-                .args
-                .as_ref()
+            let args = attr_args
                 .map(|parens| {
                     parens
                         .get()
@@ -4034,7 +4561,7 @@ fn item_attrs_to_map(
                 .unwrap_or_else(Vec::new);
 
             let attribute = Attribute {
-                name: attr.name.clone(),
+                name: attr_name.clone(),
                 args,
                 span: attr_decl.span(),
             };
@@ -4046,10 +4573,20 @@ fn item_attrs_to_map(
                 INLINE_ATTRIBUTE_NAME => Some(AttributeKind::Inline),
                 TEST_ATTRIBUTE_NAME => Some(AttributeKind::Test),
                 PAYABLE_ATTRIBUTE_NAME => Some(AttributeKind::Payable),
+                SELECTOR_ATTRIBUTE_NAME => Some(AttributeKind::Selector),
+                ABI_NAME_ATTRIBUTE_NAME => Some(AttributeKind::AbiName),
+                ABI_TRANSPARENT_ATTRIBUTE_NAME => Some(AttributeKind::AbiTransparent),
+                REENTRANCY_ATTRIBUTE_NAME => Some(AttributeKind::Reentrancy),
+                FALLBACK_ATTRIBUTE_NAME => Some(AttributeKind::Fallback),
+                GAS_HINT_ATTRIBUTE_NAME => Some(AttributeKind::GasHint),
                 ALLOW_ATTRIBUTE_NAME => Some(AttributeKind::Allow),
                 CFG_ATTRIBUTE_NAME => Some(AttributeKind::Cfg),
                 _ => None,
             } {
+                if !is_applied {
+                    unapplied_attrs.push((attr_kind, attribute));
+                    continue;
+                }
                 match attrs_map.get_mut(&attr_kind) {
                     Some(old_args) => {
                         old_args.push(attribute);
@@ -4063,7 +4600,14 @@ fn item_attrs_to_map(
     }
 
     // Check attribute arguments
-    for (attribute_kind, attributes) in &attrs_map {
+    let unapplied_attrs = unapplied_attrs
+        .iter()
+        .map(|(attribute_kind, attribute)| (attribute_kind, std::slice::from_ref(attribute)));
+    for (attribute_kind, attributes) in attrs_map
+        .iter()
+        .map(|(attribute_kind, attributes)| (attribute_kind, attributes.as_slice()))
+        .chain(unapplied_attrs)
+    {
         for attribute in attributes {
             // check attribute arguments length
             let (expected_min_len, expected_max_len) =
@@ -4103,6 +4647,63 @@ fn item_attrs_to_map(
     Ok(AttributesMap::new(Arc::new(attrs_map)))
 }
 
+/// Splits a `#[cfg_attr(predicate, attribute)]` into its `attribute` and whether its
+/// `predicate`, which is the argument of a `#[cfg(predicate)]`, holds. A malformed `cfg_attr`
+/// is reported and ignored.
+fn cfg_attr_eval<'a>(
+    context: &mut Context,
+    handler: &Handler,
+    cfg_attr: &'a sway_ast::attribute::Attribute,
+) -> Result<Option<(&'a sway_ast::attribute::AttributeArg, bool)>, ErrorEmitted> {
+    let args = cfg_attr
+        .args
+        .as_ref()
+        .map(|parens| parens.get().into_iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+    let (predicate, inner_attr) = match args.as_slice() {
+        [predicate, inner_attr] => (*predicate, *inner_attr),
+        _ => {
+            handler.emit_warn(CompileWarning {
+                span: cfg_attr.name.span(),
+                warning_content: Warning::AttributeExpectedNumberOfArguments {
+                    attrib_name: cfg_attr.name.clone(),
+                    received_args: args.len(),
+                    expected_min_len: 2,
+                    expected_max_len: Some(2),
+                },
+            });
+            return Ok(None);
+        }
+    };
+
+    let possible_values = AttributeKind::Cfg
+        .expected_args_values(0)
+        .unwrap_or_default();
+    if !possible_values.iter().any(|v| v == predicate.name.as_str()) {
+        handler.emit_warn(CompileWarning {
+            span: cfg_attr.name.span(),
+            warning_content: Warning::UnexpectedAttributeArgumentValue {
+                attrib_name: cfg_attr.name.clone(),
+                received_value: predicate.name.as_str().to_string(),
+                expected_values: possible_values,
+            },
+        });
+        return Ok(Some((inner_attr, false)));
+    }
+
+    let cfg = Attribute {
+        name: Ident::new_with_override(CFG_ATTRIBUTE_NAME.to_string(), cfg_attr.name.span()),
+        args: vec![AttributeArg {
+            name: predicate.name.clone(),
+            value: predicate.value.clone(),
+            span: predicate.span(),
+        }],
+        span: cfg_attr.span(),
+    };
+    let cfg_map = AttributesMap::new(Arc::new(HashMap::from([(AttributeKind::Cfg, vec![cfg])])));
+    Ok(Some((inner_attr, cfg_eval(context, handler, &cfg_map)?)))
+}
+
 fn error_if_self_param_is_not_allowed(
     _context: &mut Context,
     handler: &Handler,
@@ -1,20 +1,42 @@
 use sway_types::{span::Span, Spanned};
 
+use crate::language::CallPath;
+
 /// Describes a fixed length for types that needs it such as arrays and strings
 #[derive(Debug, Clone, Hash)]
 pub struct Length {
     val: usize,
     span: Span,
+    /// The constant which gives the length of an array, until it is resolved into `val`.
+    const_path: Option<Box<CallPath>>,
 }
 
 impl Length {
     pub fn new(val: usize, span: Span) -> Self {
-        Length { val, span }
+        Length {
+            val,
+            span,
+            const_path: None,
+        }
+    }
+
+    /// Creates the length of an array given by the constant `const_path`. Its value is set when
+    /// the array type is resolved.
+    pub fn new_const(const_path: CallPath, span: Span) -> Self {
+        Length {
+            val: 0,
+            span,
+            const_path: Some(Box::new(const_path)),
+        }
     }
 
     pub fn val(&self) -> usize {
         self.val
     }
+
+    pub fn const_path(&self) -> Option<&CallPath> {
+        self.const_path.as_deref()
+    }
 }
 
 impl Spanned for Length {
@@ -180,9 +180,16 @@ impl TypeParameter {
             ..
         } = type_parameter;
 
-        // Type check the trait constraints.
+        // Type check the trait constraints. Every bound of a `+`-separated list is checked, so
+        // that each invalid one is reported and not only the first.
+        let mut error_emitted = None;
         for trait_constraint in trait_constraints.iter_mut() {
-            trait_constraint.type_check(handler, ctx.by_ref())?;
+            if let Err(err) = trait_constraint.type_check(handler, ctx.by_ref()) {
+                error_emitted = Some(err);
+            }
+        }
+        if let Some(err) = error_emitted {
+            return Err(err);
         }
 
         // TODO: add check here to see if the type parameter has a valid name and does not have type parameters
@@ -197,12 +204,17 @@ impl TypeParameter {
 
         // Insert the trait constraints into the namespace.
         for trait_constraint in trait_constraints.iter() {
-            TraitConstraint::insert_into_namespace(
+            if let Err(err) = TraitConstraint::insert_into_namespace(
                 handler,
                 ctx.by_ref(),
                 type_id,
                 trait_constraint,
-            )?;
+            ) {
+                error_emitted = Some(err);
+            }
+        }
+        if let Some(err) = error_emitted {
+            return Err(err);
         }
 
         // When type parameter is from parent then it was already inserted.
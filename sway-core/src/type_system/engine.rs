@@ -7,11 +7,18 @@ use sway_types::integer_bits::IntegerBits;
 
 use crate::concurrent_slab::ListDisplay;
 use crate::{
-    concurrent_slab::ConcurrentSlab, decl_engine::*, engine_threading::*, language::ty,
-    namespace::Path, type_system::priv_prelude::*, Namespace,
+    concurrent_slab::ConcurrentSlab,
+    decl_engine::*,
+    engine_threading::*,
+    ir_generation::const_eval::evaluate_constant_expression,
+    language::{ty, CallPath},
+    namespace::{self, Path},
+    type_system::priv_prelude::*,
+    Namespace,
 };
 
 use sway_error::{error::CompileError, type_error::TypeError, warning::CompileWarning};
+use sway_ir::ConstantValue;
 use sway_types::{span::Span, Ident, Spanned};
 
 #[derive(Debug, Default)]
@@ -506,6 +513,22 @@ impl TypeEngine {
                     )
                     .unwrap_or_else(|_| self.insert(engines, TypeInfo::ErrorRecovery));
 
+                let n = match n.const_path() {
+                    Some(const_path) => {
+                        match self.resolve_const_length(
+                            handler,
+                            engines,
+                            const_path,
+                            n.span(),
+                            namespace,
+                            module_path,
+                        ) {
+                            Ok(n) => n,
+                            Err(_) => return Ok(self.insert(engines, TypeInfo::ErrorRecovery)),
+                        }
+                    }
+                    None => n,
+                };
                 let type_id = self.insert(engines, TypeInfo::Array(elem_ty, n));
 
                 // take any trait methods that apply to this type and copy them to the new type
@@ -541,6 +564,38 @@ impl TypeEngine {
         Ok(type_id)
     }
 
+    /// Evaluates the constant `const_path` which gives the length of an array type.
+    fn resolve_const_length(
+        &self,
+        handler: &Handler,
+        engines: &Engines,
+        const_path: &CallPath,
+        span: Span,
+        namespace: &Namespace,
+        mod_path: &Path,
+    ) -> Result<Length, ErrorEmitted> {
+        let decl_engine = engines.de();
+        let decl = namespace
+            .root()
+            .resolve_call_path_with_visibility_check(handler, engines, mod_path, const_path)?
+            .clone();
+        let val = match decl {
+            ty::TyDecl::ConstantDecl(ty::ConstantDecl { decl_id, .. }) => const_decl_to_length(
+                engines,
+                &namespace.root().module[mod_path],
+                &decl_engine.get_constant(&decl_id),
+            ),
+            _ => None,
+        };
+        match val {
+            Some(val) => Ok(Length::new(val, span)),
+            None => Err(handler.emit_err(CompileError::NonConstArrayLength {
+                name: const_path.to_string(),
+                span,
+            })),
+        }
+    }
+
     /// Replace any instances of the [TypeInfo::SelfType] variant with
     /// `self_type` in `type_id`, then resolve `type_id`.
     #[allow(clippy::too_many_arguments)]
@@ -585,6 +640,22 @@ impl TypeEngine {
     }
 }
 
+/// The value of the constant `decl` as a length, if it is a non-configurable constant whose
+/// value evaluates to an unsigned integer.
+fn const_decl_to_length(
+    engines: &Engines,
+    module_ns: &namespace::Module,
+    decl: &ty::TyConstantDecl,
+) -> Option<usize> {
+    if decl.is_configurable {
+        return None;
+    }
+    match evaluate_constant_expression(engines, module_ns, decl.value.as_ref()?) {
+        Ok(ConstantValue::Uint(val)) => usize::try_from(val).ok(),
+        _ => None,
+    }
+}
+
 fn normalize_err(
     (w, e): (Vec<CompileWarning>, Vec<TypeError>),
 ) -> (Vec<CompileWarning>, Vec<CompileError>) {
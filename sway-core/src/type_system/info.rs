@@ -306,6 +306,7 @@ impl PartialEqWithEngines for TypeInfo {
                     .get(l0.type_id)
                     .eq(&type_engine.get(r0.type_id), engines)
                     && l1.val() == r1.val()
+                    && l1.const_path() == r1.const_path()
             }
             (TypeInfo::Storage { fields: l_fields }, TypeInfo::Storage { fields: r_fields }) => {
                 l_fields.eq(r_fields, engines)
@@ -627,10 +628,12 @@ impl TypeInfo {
                     let names = fields
                         .iter()
                         .map(|field_type| {
-                            type_engine
-                                .to_typeinfo(field_type.type_id, error_msg_span)
-                                .expect("unreachable?")
-                                .to_selector_name(handler, engines, error_msg_span)
+                            let ty =
+                                match type_engine.to_typeinfo(field_type.type_id, error_msg_span) {
+                                    Err(e) => return Err(handler.emit_err(e.into())),
+                                    Ok(ty) => ty,
+                                };
+                            ty.to_selector_name(handler, engines, error_msg_span)
                         })
                         .collect::<Vec<Result<String, _>>>();
                     let mut buf = vec![];
@@ -645,6 +648,13 @@ impl TypeInfo {
             B256 => "b256".into(),
             Struct(decl_ref) => {
                 let decl = decl_engine.get_struct(decl_ref);
+                // A transparent newtype is encoded as the type it wraps.
+                if let Some(field) = decl.abi_transparent_field() {
+                    let ty = type_engine
+                        .to_typeinfo(field.type_argument.type_id, error_msg_span)
+                        .map_err(|e| handler.emit_err(e.into()))?;
+                    return ty.to_selector_name(handler, engines, error_msg_span);
+                }
                 let field_names = {
                     let names = decl
                         .fields
@@ -749,25 +759,22 @@ impl TypeInfo {
                 }
             }
             Array(elem_ty, length) => {
-                let name = type_engine.get(elem_ty.type_id).to_selector_name(
-                    handler,
-                    engines,
-                    error_msg_span,
-                );
-                let name = match name {
-                    Ok(name) => name,
-                    Err(e) => return Err(e),
+                let elem_ty = match type_engine.to_typeinfo(elem_ty.type_id, error_msg_span) {
+                    Err(e) => return Err(handler.emit_err(e.into())),
+                    Ok(ty) => ty,
                 };
+                let name = elem_ty.to_selector_name(handler, engines, error_msg_span)?;
                 format!("a[{};{}]", name, length.val())
             }
             RawUntypedPtr => "rawptr".to_string(),
             RawUntypedSlice => "rawslice".to_string(),
+            // An alias is encoded as the type it stands for, so that introducing an alias doesn't
+            // change the ABI.
             Alias { ty, .. } => {
-                let name =
-                    type_engine
-                        .get(ty.type_id)
-                        .to_selector_name(handler, engines, error_msg_span);
-                name?
+                let ty = type_engine
+                    .to_typeinfo(ty.type_id, error_msg_span)
+                    .map_err(|e| handler.emit_err(e.into()))?;
+                ty.to_selector_name(handler, engines, error_msg_span)?
             }
             _ => {
                 return Err(handler.emit_err(CompileError::InvalidAbiType {
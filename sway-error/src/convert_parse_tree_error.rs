@@ -91,8 +91,6 @@ pub enum ConvertParseTreeError {
     MultipleConfigurableBlocksInModule { span: Span },
     #[error("struct field \"{name}\" already declared")]
     DuplicateStructField { name: Ident, span: Span },
-    #[error("identifier \"{name}\" bound more than once in this parameter list")]
-    DuplicateParameterIdentifier { name: Ident, span: Span },
     #[error("self parameter is not allowed for {fn_kind}")]
     SelfParameterNotAllowedForFn { fn_kind: String, span: Span },
     #[error("test functions are only allowed at module level")]
@@ -172,7 +170,6 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::DuplicateConfigurable { span, .. } => span.clone(),
             ConvertParseTreeError::MultipleConfigurableBlocksInModule { span } => span.clone(),
             ConvertParseTreeError::DuplicateStructField { span, .. } => span.clone(),
-            ConvertParseTreeError::DuplicateParameterIdentifier { span, .. } => span.clone(),
             ConvertParseTreeError::SelfParameterNotAllowedForFn { span, .. } => span.clone(),
             ConvertParseTreeError::TestFnOnlyAllowedAtModuleLevel { span } => span.clone(),
             ConvertParseTreeError::SelfImplForContract { span, .. } => span.clone(),
@@ -29,6 +29,11 @@ impl fmt::Display for InterfaceName {
 pub enum CompileError {
     #[error("Variable \"{var_name}\" does not exist in this scope.")]
     UnknownVariable { var_name: Ident, span: Span },
+    #[error(
+        "Variable \"{name}\" belongs to an enclosing function and cannot be captured by a nested \
+         function. Pass it to the nested function as a parameter instead."
+    )]
+    NestedFunctionCapturesLocal { name: Ident, span: Span },
     #[error("Identifier \"{name}\" was used as a variable, but it is actually a {what_it_is}.")]
     NotAVariable {
         name: Ident,
@@ -73,6 +78,8 @@ pub enum CompileError {
     MultipleDefinitionsOfConstant { name: Ident, span: Span },
     #[error("Assignment to immutable variable. Variable {name} is not declared as mutable.")]
     AssignmentToNonMutable { name: Ident, span: Span },
+    #[error("Assignment through immutable \"self\". Declare the receiver as \"ref mut self\" to mutate it.")]
+    AssignmentToImmutableSelf { span: Span },
     #[error(
         "Cannot call method \"{method_name}\" on variable \"{variable_name}\" because \
             \"{variable_name}\" is not declared as mutable."
@@ -86,10 +93,39 @@ pub enum CompileError {
         "This parameter was declared as mutable, which is not supported yet, did you mean to use ref mut?"
     )]
     MutableParameterNotSupported { param_name: Ident, span: Span },
+    #[error(
+        "\"mut self\" is not a valid receiver. Use \"ref mut self\" to mutate the receiver, or \
+         \"self\" to take it by value."
+    )]
+    MutableSelfWithoutRef { span: Span },
+    #[error(
+        "The length of an array must be given by an integer constant, but \"{name}\" is not one."
+    )]
+    NonConstArrayLength { name: String, span: Span },
+    #[error(
+        "Test function \"{name}\" can't be run. A test must be a free function which takes no \
+         parameters and returns \"()\"."
+    )]
+    InvalidTestSignature { name: Ident, span: Span },
+    #[error("Parameter \"{name}\" is declared more than once in this function's parameter list.")]
+    DuplicateParameterName {
+        name: Ident,
+        first_span: Span,
+        duplicate_span: Span,
+    },
+    #[error("Parameter \"{name}\" must have a default value, since it follows a parameter with a default value.")]
+    RequiredParameterAfterDefaultParameter { name: Ident, span: Span },
+    #[error("Default parameter values are only allowed for functions that have a body.")]
+    DefaultParameterValueNotAllowed { span: Span },
     #[error("Cannot pass immutable argument to mutable parameter.")]
     ImmutableArgumentToMutableParameter { span: Span },
     #[error("ref mut or mut parameter is not allowed for contract ABI function.")]
     RefMutableNotAllowedInContractAbi { param_name: Ident, span: Span },
+    #[error(
+        "Parameter \"{param_name}\" cannot be a `ref` parameter, as the arguments of contract ABI \
+        methods are passed by value."
+    )]
+    RefParameterInAbiMethod { param_name: Ident, span: Span },
     #[error(
         "Cannot call associated function \"{fn_name}\" as a method. Use associated function \
         syntax instead."
@@ -381,8 +417,41 @@ pub enum CompileError {
     },
     #[error("The function \"{method_name}\" was called without parentheses. Try adding ().")]
     MissingParenthesesForFunction { span: Span, method_name: Ident },
+    #[error("Function \"{fn_name}\" has no parameter named \"{name}\".")]
+    UnknownArgumentName { fn_name: Ident, name: Ident },
+    #[error("Parameter \"{name}\" has already been given an argument.")]
+    DuplicateArgument { name: Ident, span: Span },
+    #[error("Missing an argument for parameter \"{name}\" of function \"{fn_name}\".")]
+    MissingArgument {
+        fn_name: Ident,
+        name: Ident,
+        span: Span,
+    },
+    #[error("Positional arguments must come before named arguments.")]
+    PositionalArgumentAfterNamedArgument { span: Span },
+    #[error("Named arguments are only allowed in function calls.")]
+    NamedArgumentOutsideOfFunctionCall { span: Span },
     #[error("This type is invalid in a function selector. A contract ABI function selector must be a known sized type, not generic.")]
     InvalidAbiType { span: Span },
+    #[error("`impl Trait` types are only allowed as the return type of a function with a body.")]
+    ImplTraitNotAllowedHere { span: Span },
+    #[error(
+        "ABI methods cannot return `impl Trait` types, as the returned values must be encodable."
+    )]
+    OpaqueReturnTypeInAbi { span: Span },
+    #[error("Trait \"{name}\" of this `impl Trait` type cannot be found in the current scope.")]
+    UnknownTraitInImplTrait { name: Ident, span: Span },
+    #[error(
+        "Trait \"{trait_name}\" cannot be the type of a parameter, as traits are not sized types.\n\
+         help: {help}"
+    )]
+    BareTraitParameter {
+        trait_name: Ident,
+        help: String,
+        span: Span,
+    },
+    #[error("ABI method \"{name}\" cannot be generic. The selector of a contract call is computed from the concrete types of its parameters and return value.")]
+    GenericAbiMethod { name: Ident, span: Span },
     #[error("This is a {actually_is}, not an ABI. An ABI cast requires a valid ABI to cast the address to.")]
     NotAnAbi {
         span: Span,
@@ -417,6 +486,66 @@ pub enum CompileError {
         should_be: String,
         provided: String,
     },
+    #[error(
+        "Mismatched return type: expected `{expected}`, found `{found}`.\n\
+         help: Return statement must return the declared function return type."
+    )]
+    ReturnTypeMismatch {
+        expected: String,
+        found: String,
+        span: Span,
+    },
+    #[error(
+        "Missing return value: expected `{expected}`.\n\
+         help: A bare \"return;\" returns `()`, but the function returns `{expected}`."
+    )]
+    MissingReturnValue { expected: String, span: Span },
+    #[error(
+        "Function \"{fn_name}\" returns \"{ty}\", which has no values, but its body does not \
+         always revert. Only functions which never return are allowed to have such a return type."
+    )]
+    CannotReturnUninhabitedType {
+        fn_name: Ident,
+        ty: String,
+        span: Span,
+    },
+    #[error(
+        "Function \"{fn_name}\" is evaluated at compile time here, but it returns early. Only a \
+         \"return\" at the end of the function body is supported when evaluating a constant."
+    )]
+    EarlyReturnInConstContext { fn_name: Ident, span: Span },
+    #[error(
+        "Function name \"{name}\" is not idiomatic. Function names should be snake_case, like \
+         \"{}\".",
+        sway_types::style::to_snake_case(.name.as_str())
+    )]
+    NonSnakeCaseFunctionName { name: Ident },
+    #[error(
+        "Function name \"{name}\" is reserved for the compiler intrinsic of the same name, which \
+         it would shadow."
+    )]
+    FunctionNameShadowsIntrinsic { name: Ident },
+    #[error(
+        "Parameter \"{name}\" of ABI method \"{fn_name}\" has the unit type \"()\". A unit \
+         parameter carries no data, and is not allowed in a contract call."
+    )]
+    UnitParameterInAbiMethod {
+        fn_name: Ident,
+        name: Ident,
+        span: Span,
+    },
+    #[error("Parameter {index} (\"{name}\") is invalid: {source}")]
+    InvalidFunctionParameter {
+        index: usize,
+        name: Ident,
+        source: Box<CompileError>,
+    },
+    #[error("\"{selector}\" is not a valid function selector name.")]
+    MalformedSelectorName { selector: String, span: Span },
+    #[error(
+        "\"{name}\" is not a valid ABI name. The name must be an identifier given as a string."
+    )]
+    InvalidAbiName { name: String, span: Span },
     #[error("Function {fn_name} is recursive, which is unsupported at this time.")]
     RecursiveCall { fn_name: Ident, span: Span },
     #[error(
@@ -427,6 +556,11 @@ pub enum CompileError {
         call_chain: String, // Pretty list of symbols, e.g., "a, b and c".
         span: Span,
     },
+    #[error(
+        "Function \"{name}\" calls itself, so it can't be inlined. Remove \"#[inline(always)]\" \
+         or the recursive call."
+    )]
+    RecursiveAlwaysInline { name: Ident, span: Span },
     #[error("Type {name} is recursive, which is unsupported at this time.")]
     RecursiveType { name: Ident, span: Span },
     #[error("Type {name} is recursive via {type_chain}, which is unsupported at this time.")]
@@ -482,6 +616,25 @@ pub enum CompileError {
         adding \"#[{STORAGE_PURITY_ATTRIBUTE_NAME}({attrs})]\" to the function declaration."
     )]
     StorageAccessMismatch { attrs: String, span: Span },
+    #[error(
+        "The const function \"{fn_name}\" cannot {operation}, as it must be evaluable at \
+        compile time."
+    )]
+    NonConstOperationInConstFn {
+        fn_name: Ident,
+        operation: String,
+        span: Span,
+    },
+    #[error(
+        "Pure function \"{caller}\" cannot call \"{callee}\", which accesses storage. Try giving \
+        \"{caller}\" access by adding \"#[{STORAGE_PURITY_ATTRIBUTE_NAME}({attrs})]\" to its declaration."
+    )]
+    StorageAccessInPureFunction {
+        callee: Ident,
+        caller: Ident,
+        attrs: String,
+        span: Span,
+    },
     #[error(
         "The function \"{fn_name}\" in {interface_name} is pure, but this \
         implementation is not.  The \"{STORAGE_PURITY_ATTRIBUTE_NAME}\" annotation must be \
@@ -641,6 +794,63 @@ pub enum CompileError {
         missing_impl_attribute: bool,
         span: Span,
     },
+    #[error(
+        "Selector attribute mismatch. The \"{fn_name}\" method implementation must have the same \
+         #[selector] attribute as its signature in {interface_name}."
+    )]
+    TraitImplSelectorMismatch {
+        fn_name: Ident,
+        interface_name: InterfaceName,
+        span: Span,
+    },
+    #[error(
+        "ABI name attribute mismatch. The \"{fn_name}\" method implementation must have the same \
+         #[abi_name] attribute as its signature in {interface_name}."
+    )]
+    TraitImplAbiNameMismatch {
+        fn_name: Ident,
+        interface_name: InterfaceName,
+        span: Span,
+    },
+    #[error(
+        "The \"{attribute}\" attribute is not allowed here. It can only be used on {allowed_on}."
+    )]
+    InvalidAttributeOnItem {
+        attribute: String,
+        allowed_on: String,
+        span: Span,
+    },
+    #[error(
+        "The \"{attribute}\" attribute is not allowed on function parameters. Parameters can only \
+        have doc comments and \"doc\" attributes."
+    )]
+    InvalidAttributeOnParameter { attribute: Ident, span: Span },
+    #[error(
+        "Struct \"{name}\" has {field_count} fields, but only a struct with a single field can be \
+        \"abi_transparent\"."
+    )]
+    AbiTransparentStructFieldCount {
+        name: Ident,
+        field_count: usize,
+        span: Span,
+    },
+    #[error("Fallback method \"{name}\" cannot have parameters.")]
+    FallbackWithParameters { name: Ident, span: Span },
+    #[error("A contract can only have one fallback method, but \"{first}\" is already one.")]
+    MultipleFallbacks { first: Ident, span: Span },
+    #[error("The \"{attribute}\" attribute can only be used once.")]
+    DuplicateAttribute { attribute: String, span: Span },
+    #[error("The gas hint \"{hint}\" is not a positive integer.")]
+    InvalidGasHint { hint: String, span: Span },
+    #[error("The \"gas_hint\" attribute takes exactly one argument, but {count} were given.")]
+    GasHintArgumentCount { count: usize, span: Span },
+    #[error("The \"{b}\" attribute cannot be used together with the \"{a}\" attribute.")]
+    ConflictingAttributes { a: String, b: String, span: Span },
+    #[error(
+        "Invalid \"storage\" attribute. It must list the storage accesses of the function, \
+         \"read\" and/or \"write\"."
+    )]
+    InvalidStorageAttribute { span: Span },
     #[error("Configurable constants are not allowed in libraries.")]
     ConfigurableInLibrary { span: Span },
     #[error("Multiple applicable items in scope. {}", {
@@ -669,6 +879,30 @@ pub enum CompileError {
     },
     #[error("Cannot call ABI supertrait's method as a contract method: \"{fn_name}\"")]
     AbiSupertraitMethodCallAsContractCall { fn_name: Ident, span: Span },
+    #[error(
+        "The `?` operator can only be used in a function that returns `Result` or `Option`, \
+         but this function returns \"{return_type}\"."
+    )]
+    TryOperatorInvalidReturnType { return_type: String, span: Span },
+    #[error(
+        "The `?` operator cannot propagate a value of type \"{found}\" out of a function or \
+         `try` block returning \"{expected}\"."
+    )]
+    TryOperatorTypeMismatch {
+        expected: String,
+        found: String,
+        span: Span,
+    },
+    #[error(
+        "A `try` block evaluates to a `Result`, but there is no `Result` enum with `Ok` and \
+         `Err` variants in scope."
+    )]
+    TryBlockWithoutResult { span: Span },
+    #[error(
+        "The `else` block of a `let` statement must diverge, e.g. by returning or reverting, since \
+         the variables of the pattern are not bound when it runs."
+    )]
+    LetElseBlockDoesNotDiverge { span: Span },
 }
 
 impl std::convert::From<TypeError> for CompileError {
@@ -682,6 +916,7 @@ impl Spanned for CompileError {
         use CompileError::*;
         match self {
             UnknownVariable { span, .. } => span.clone(),
+            NestedFunctionCapturesLocal { span, .. } => span.clone(),
             NotAVariable { span, .. } => span.clone(),
             Unimplemented(_, span) => span.clone(),
             UnimplementedWithHelp(_, _, span) => span.clone(),
@@ -696,9 +931,17 @@ impl Spanned for CompileError {
             MultipleDefinitionsOfName { span, .. } => span.clone(),
             MultipleDefinitionsOfConstant { span, .. } => span.clone(),
             AssignmentToNonMutable { span, .. } => span.clone(),
+            AssignmentToImmutableSelf { span } => span.clone(),
             MutableParameterNotSupported { span, .. } => span.clone(),
+            MutableSelfWithoutRef { span } => span.clone(),
+            NonConstArrayLength { span, .. } => span.clone(),
+            InvalidTestSignature { span, .. } => span.clone(),
+            DuplicateParameterName { duplicate_span, .. } => duplicate_span.clone(),
+            RequiredParameterAfterDefaultParameter { span, .. } => span.clone(),
+            DefaultParameterValueNotAllowed { span } => span.clone(),
             ImmutableArgumentToMutableParameter { span } => span.clone(),
             RefMutableNotAllowedInContractAbi { span, .. } => span.clone(),
+            RefParameterInAbiMethod { span, .. } => span.clone(),
             MethodRequiresMutableSelf { span, .. } => span.clone(),
             AssociatedFunctionCalledAsMethod { span, .. } => span.clone(),
             TypeParameterNotInTypeScope { span, .. } => span.clone(),
@@ -758,7 +1001,17 @@ impl Spanned for CompileError {
             TooManyArgumentsForFunction { span, .. } => span.clone(),
             TooFewArgumentsForFunction { span, .. } => span.clone(),
             MissingParenthesesForFunction { span, .. } => span.clone(),
+            UnknownArgumentName { name, .. } => name.span(),
+            DuplicateArgument { span, .. } => span.clone(),
+            MissingArgument { span, .. } => span.clone(),
+            PositionalArgumentAfterNamedArgument { span } => span.clone(),
+            NamedArgumentOutsideOfFunctionCall { span } => span.clone(),
             InvalidAbiType { span, .. } => span.clone(),
+            ImplTraitNotAllowedHere { span } => span.clone(),
+            OpaqueReturnTypeInAbi { span } => span.clone(),
+            UnknownTraitInImplTrait { span, .. } => span.clone(),
+            BareTraitParameter { span, .. } => span.clone(),
+            GenericAbiMethod { span, .. } => span.clone(),
             NotAnAbi { span, .. } => span.clone(),
             ImplAbiForNonContract { span, .. } => span.clone(),
             ConflictingImplsForTraitAndType {
@@ -767,8 +1020,19 @@ impl Spanned for CompileError {
             DuplicateDeclDefinedForType { span, .. } => span.clone(),
             IncorrectNumberOfInterfaceSurfaceFunctionParameters { span, .. } => span.clone(),
             ArgumentParameterTypeMismatch { span, .. } => span.clone(),
+            ReturnTypeMismatch { span, .. } => span.clone(),
+            MissingReturnValue { span, .. } => span.clone(),
+            CannotReturnUninhabitedType { span, .. } => span.clone(),
+            EarlyReturnInConstContext { span, .. } => span.clone(),
+            NonSnakeCaseFunctionName { name } => name.span(),
+            FunctionNameShadowsIntrinsic { name } => name.span(),
+            UnitParameterInAbiMethod { span, .. } => span.clone(),
+            InvalidFunctionParameter { source, .. } => source.span(),
+            MalformedSelectorName { span, .. } => span.clone(),
+            InvalidAbiName { span, .. } => span.clone(),
             RecursiveCall { span, .. } => span.clone(),
             RecursiveCallChain { span, .. } => span.clone(),
+            RecursiveAlwaysInline { span, .. } => span.clone(),
             RecursiveType { span, .. } => span.clone(),
             RecursiveTypeChain { span, .. } => span.clone(),
             GMFromExternalContext { span, .. } => span.clone(),
@@ -787,6 +1051,8 @@ impl Spanned for CompileError {
             MatchVariableNotBoundInAllPatterns { span, .. } => span.clone(),
             NotAnEnum { span, .. } => span.clone(),
             StorageAccessMismatch { span, .. } => span.clone(),
+            NonConstOperationInConstFn { span, .. } => span.clone(),
+            StorageAccessInPureFunction { span, .. } => span.clone(),
             TraitDeclPureImplImpure { span, .. } => span.clone(),
             TraitImplPurityMismatch { span, .. } => span.clone(),
             DeclIsNotAnEnum { span, .. } => span.clone(),
@@ -840,6 +1106,18 @@ impl Spanned for CompileError {
             DisallowedIntrinsicInPredicate { span, .. } => span.clone(),
             CoinsPassedToNonPayableMethod { span, .. } => span.clone(),
             TraitImplPayabilityMismatch { span, .. } => span.clone(),
+            TraitImplSelectorMismatch { span, .. } => span.clone(),
+            TraitImplAbiNameMismatch { span, .. } => span.clone(),
+            InvalidAttributeOnItem { span, .. } => span.clone(),
+            InvalidAttributeOnParameter { span, .. } => span.clone(),
+            AbiTransparentStructFieldCount { span, .. } => span.clone(),
+            FallbackWithParameters { span, .. } => span.clone(),
+            MultipleFallbacks { span, .. } => span.clone(),
+            DuplicateAttribute { span, .. } => span.clone(),
+            InvalidGasHint { span, .. } => span.clone(),
+            GasHintArgumentCount { span, .. } => span.clone(),
+            ConflictingAttributes { span, .. } => span.clone(),
+            InvalidStorageAttribute { span } => span.clone(),
             ConfigurableInLibrary { span } => span.clone(),
             MultipleApplicableItemsInScope { span, .. } => span.clone(),
             CannotBeEvaluatedToConst { span } => span.clone(),
@@ -847,6 +1125,10 @@ impl Spanned for CompileError {
             AbiShadowsSuperAbiMethod { span, .. } => span.clone(),
             ConflictingSuperAbiMethods { span, .. } => span.clone(),
             AbiSupertraitMethodCallAsContractCall { span, .. } => span.clone(),
+            TryOperatorInvalidReturnType { span, .. } => span.clone(),
+            TryOperatorTypeMismatch { span, .. } => span.clone(),
+            TryBlockWithoutResult { span } => span.clone(),
+            LetElseBlockDoesNotDiverge { span } => span.clone(),
         }
     }
 }
@@ -52,6 +52,8 @@ pub enum ParseErrorKind {
     ExpectedOpenBracket,
     #[error("Expected a literal.")]
     ExpectedLiteral,
+    #[error("Expected a string literal.")]
+    ExpectedStringLiteral,
     #[error("Expected a module kind (script, contract, predicate or library).")]
     ExpectedModuleKind,
     #[error("Expected `{}`.", kinds.iter().map(PunctKind::as_char).collect::<String>())]
@@ -70,8 +72,14 @@ pub enum ParseErrorKind {
     UnexpectedRestPattern,
     #[error("Identifiers cannot be a reserved keyword.")]
     ReservedKeywordIdentifier,
+    #[error(
+        "Const generic parameters are not supported yet. Only type parameters are allowed here."
+    )]
+    ConstGenericParameter,
     #[error("Unnecessary visibility qualifier, `{}` is implied here.", visibility)]
     UnnecessaryVisibilityQualifier { visibility: Ident },
+    #[error("Only functions can be declared `pub(package)`.")]
+    PackageVisibilityOnNonFunction,
     #[error("Expected a doc comment.")]
     ExpectedDocComment,
     #[error("Top of file doc comments are reserved for module level documentation.\nTry using the `//!` syntax.")]
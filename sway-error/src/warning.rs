@@ -48,6 +48,8 @@ pub enum Warning {
     },
     NonSnakeCaseFunctionName {
         name: Ident,
+        /// The snake_case form of `name`, which it can be replaced with.
+        suggestion: String,
     },
     NonScreamingSnakeCaseConstName {
         name: Ident,
@@ -55,6 +57,50 @@ pub enum Warning {
     UnusedReturnValue {
         r#type: String,
     },
+    UnusedFunctionParameter {
+        name: Ident,
+    },
+    ParameterPassedThrough {
+        name: Ident,
+    },
+    MethodDoesNotUseSelf {
+        name: Ident,
+    },
+    ReturningStorageFromPureFunction {
+        r#type: String,
+    },
+    UnusedTypeParameter {
+        name: Ident,
+    },
+    RedundantTraitBound {
+        type_parameter: Ident,
+        trait_name: String,
+    },
+    TooManyParameters {
+        count: usize,
+        limit: usize,
+    },
+    TooManyTypeParameters {
+        count: usize,
+        limit: usize,
+    },
+    HighComplexity {
+        value: u32,
+        limit: u32,
+    },
+    DeepGenericNesting {
+        depth: usize,
+        limit: usize,
+    },
+    MultipleBooleanParameters {
+        count: usize,
+    },
+    LeakedPrivateTypeInPublicApi {
+        ty: String,
+    },
+    RecursiveFunction {
+        name: Ident,
+    },
     SimilarMethodFound {
         lib: Ident,
         module: Ident,
@@ -63,6 +109,15 @@ pub enum Warning {
     ShadowsOtherSymbol {
         name: Ident,
     },
+    ParameterShadowsTypeParameter {
+        name: Ident,
+        type_parameter_span: Span,
+    },
+    FunctionShadowsImport {
+        name: Ident,
+        trait_name: Ident,
+        method: Ident,
+    },
     OverridingTraitImplementation,
     DeadDeclaration,
     DeadEnumDeclaration,
@@ -70,6 +125,7 @@ pub enum Warning {
     DeadStructDeclaration,
     DeadTrait,
     UnreachableCode,
+    RedundantTailReturn,
     DeadEnumVariant {
         variant_name: Ident,
     },
@@ -97,6 +153,24 @@ pub enum Warning {
         received_value: String,
         expected_values: Vec<String>,
     },
+    InlineAttributeWithoutBody,
+    SelectorArityMismatch {
+        selector: String,
+        selector_parameters: usize,
+        parameters: usize,
+    },
+    SimilarFunctionNames {
+        a: Ident,
+        b: Ident,
+    },
+    DuplicateStorageAttributeArgument {
+        arg_name: Ident,
+    },
+    CoinsForwardedFromNonPayableMethod,
+    PurityOverAnnotated {
+        declared_attrib: String,
+        needed_attrib: String,
+    },
     EffectAfterInteraction {
         effect: String,
         effect_in_suggestion: String,
@@ -156,12 +230,12 @@ impl fmt::Display for Warning {
                 variant_name,
                 to_upper_camel_case(variant_name.as_str())
             ),
-            NonSnakeCaseFunctionName { name } => {
+            NonSnakeCaseFunctionName { name, suggestion } => {
                 write!(f,
                 "Function name \"{}\" is not idiomatic. Function names should be snake_case, like \
                  \"{}\".",
                 name,
-                to_snake_case(name.as_str())
+                suggestion
             )
             }
             NonScreamingSnakeCaseConstName { name } => {
@@ -178,6 +252,73 @@ impl fmt::Display for Warning {
                 "This returns a value of type {type}, which is not assigned to anything and is \
                  ignored."
             ),
+            ParameterPassedThrough { name } => write!(
+                f,
+                "Function parameter \"{name}\" is only ever returned unchanged. A function which \
+                 passes its argument through is often a redundant wrapper."
+            ),
+            MethodDoesNotUseSelf { name } => write!(
+                f,
+                "Method \"{name}\" never uses \"self\", and could be an associated function \
+                 instead. If this is intentional, prefix the method name with an underscore."
+            ),
+            UnusedFunctionParameter { name } => write!(
+                f,
+                "Function parameter \"{name}\" is never used. If this is intentional, prefix it \
+                 with an underscore: \"_{name}\"."
+            ),
+            UnusedTypeParameter { name } => write!(
+                f,
+                "Type parameter \"{name}\" is never used in the signature or the body of the \
+                 function."
+            ),
+            RedundantTraitBound {
+                type_parameter,
+                trait_name,
+            } => write!(
+                f,
+                "Type parameter \"{type_parameter}\" is already bound by trait \"{trait_name}\". \
+                 This bound is redundant."
+            ),
+            TooManyParameters { count, limit } => write!(
+                f,
+                "This function has {count} parameters, which is more than the limit of {limit}."
+            ),
+            TooManyTypeParameters { count, limit } => write!(
+                f,
+                "This function has {count} type parameters, which is more than the limit of \
+                 {limit}."
+            ),
+            HighComplexity { value, limit } => write!(
+                f,
+                "This function has a cyclomatic complexity of {value}, which is more than the \
+                 limit of {limit}."
+            ),
+            DeepGenericNesting { depth, limit } => write!(
+                f,
+                "This type nests generic types {depth} levels deep, which is more than the limit \
+                 of {limit}. Every instantiation of it is monomorphized separately."
+            ),
+            MultipleBooleanParameters { count } => write!(
+                f,
+                "This function takes {count} boolean parameters, which are easily mixed up at \
+                 the call site. Consider passing a struct of options instead."
+            ),
+            LeakedPrivateTypeInPublicApi { ty } => write!(
+                f,
+                "This public function exposes the private type \"{ty}\", which can't be named \
+                 outside of the module declaring it."
+            ),
+            ReturningStorageFromPureFunction { r#type } => write!(
+                f,
+                "This pure function returns a value of type {type}, which refers to contract \
+                 storage."
+            ),
+            RecursiveFunction { name } => write!(
+                f,
+                "Function \"{name}\" calls itself. Recursion can exhaust the bounded call stack \
+                 of the FuelVM."
+            ),
             SimilarMethodFound { lib, module, name } => write!(
                 f,
                 "A method with the same name was found for type {name} in dependency \"{lib}::{module}\". \
@@ -187,6 +328,27 @@ impl fmt::Display for Warning {
                 f,
                 "This shadows another symbol in this scope with the same name \"{name}\"."
             ),
+            ParameterShadowsTypeParameter {
+                name,
+                type_parameter_span,
+            } => {
+                let (start, _) = type_parameter_span.line_col();
+                write!(
+                    f,
+                    "Parameter \"{name}\" shadows the type parameter of the same name declared \
+                     at line {}, column {}.",
+                    start.line, start.col,
+                )
+            }
+            FunctionShadowsImport {
+                name,
+                trait_name,
+                method,
+            } => write!(
+                f,
+                "Function \"{name}\" has the same name as the method \"{method}\" of the imported \
+                 trait \"{trait_name}\". Consider renaming the function to avoid confusing the two."
+            ),
             OverridingTraitImplementation => write!(
                 f,
                 "This trait implementation overrides another one that was previously defined."
@@ -196,6 +358,11 @@ impl fmt::Display for Warning {
             DeadStructDeclaration => write!(f, "This struct is never used."),
             DeadFunctionDeclaration => write!(f, "This function is never called."),
             UnreachableCode => write!(f, "This code is unreachable."),
+            RedundantTailReturn => write!(
+                f,
+                "This `return` is redundant. The value can be returned implicitly by removing \
+                 `return` and the trailing semicolon."
+            ),
             DeadEnumVariant { variant_name } => {
                 write!(f, "Enum variant {variant_name} is never constructed.")
             }
@@ -235,6 +402,40 @@ impl fmt::Display for Warning {
                 "Unexpected attribute value: \"{received_value}\" for attribute: \"{attrib_name}\" expected value {}",
                 expected_values.iter().map(|v| format!("\"{v}\"")).collect::<Vec<_>>().join(" or ")
             ),
+            SimilarFunctionNames { a, b } => write!(
+                f,
+                "Function \"{b}\" is named like the function \"{a}\", except for letter case or underscores. \
+                 Consider renaming one of them, as they are easily confused."
+            ),
+            SelectorArityMismatch { selector, selector_parameters, parameters } => write!(
+                f,
+                "The selector \"{selector}\" takes {selector_parameters} parameter(s), but the method takes {parameters}."
+            ),
+            InlineAttributeWithoutBody => write!(
+                f,
+                "The \"inline\" attribute has no effect on a function without a body."
+            ),
+            DuplicateStorageAttributeArgument { arg_name } => write!(
+                f,
+                "\"{arg_name}\" is already listed in the \"storage\" attribute of this function."
+            ),
+            CoinsForwardedFromNonPayableMethod => write!(
+                f,
+                "Possibly non-zero amount of coins forwarded from a contract method that is not marked as \"payable\". \
+                 Consider adding the #[payable] attribute to the calling method."
+            ),
+            PurityOverAnnotated { declared_attrib, needed_attrib } => if needed_attrib.is_empty() {
+                write!(
+                    f,
+                    "This function is annotated with #[storage({declared_attrib})] but never accesses storage. \
+                     The storage attribute can be removed."
+                )
+            } else {
+                write!(
+                    f,
+                    "This function is annotated with #[storage({declared_attrib})] but only needs #[storage({needed_attrib})]."
+                )
+            },
             EffectAfterInteraction {effect, effect_in_suggestion, block_name} =>
                 write!(f, "{effect} after external contract interaction in function or method \"{block_name}\". \
                           Consider {effect_in_suggestion} before calling another contract"),
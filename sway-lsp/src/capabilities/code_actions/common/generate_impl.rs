@@ -56,23 +56,19 @@ pub(crate) trait GenerateImplCodeAction<'a, T: Spanned>: CodeAction<'a, T> {
 
     /// Returns a [String] of a an attribute map, optionally excluding comments.
     fn attribute_string(&self, attr_map: &AttributesMap, include_comments: bool) -> String {
-        let attr_string = attr_map
+        let mut attr_spans = vec![];
+        for (kind, attr) in attr_map.in_order() {
+            if *kind == AttributeKind::DocComment && !include_comments {
+                continue;
+            }
+            // The attributes declared within the same `#[..]` share its span.
+            if attr_spans.last() != Some(&attr.span) {
+                attr_spans.push(attr.span.clone());
+            }
+        }
+        let attr_string = attr_spans
             .iter()
-            .map(|(kind, attrs)| {
-                attrs
-                    .iter()
-                    .filter_map(|attr| match kind {
-                        AttributeKind::DocComment { .. } => {
-                            if include_comments {
-                                return Some(format!("{}{}", TAB, attr.span.as_str()));
-                            }
-                            None
-                        }
-                        _ => Some(format!("{}{}", TAB, attr.span.as_str())),
-                    })
-                    .collect::<Vec<String>>()
-                    .join("\n")
-            })
+            .map(|span| format!("{}{}", TAB, span.as_str()))
             .collect::<Vec<String>>()
             .join("\n");
         let attribute_padding = match attr_string.len() > 1 {
@@ -55,6 +55,7 @@ fn get_warning_diagnostic_tags(warning: &Warning) -> Option<Vec<DiagnosticTag>>
         | Warning::DeadStructDeclaration
         | Warning::DeadTrait
         | Warning::MatchExpressionUnreachableArm
+        | Warning::PurityOverAnnotated { .. }
         | Warning::UnreachableCode
         | Warning::UnusedReturnValue { .. } => Some(vec![DiagnosticTag::UNNECESSARY]),
         _ => None,
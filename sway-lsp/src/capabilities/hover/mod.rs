@@ -76,6 +76,7 @@ fn visibility_as_str(visibility: &Visibility) -> &'static str {
     match visibility {
         Visibility::Private => "",
         Visibility::Public => "pub ",
+        Visibility::Package => "pub(package) ",
     }
 }
 
@@ -150,12 +150,19 @@ impl Parse for Expr {
                 condition.parse(ctx);
                 block.get().parse(ctx);
             }
+            Expr::TryBlock { try_token, block } => {
+                insert_keyword(ctx, try_token.span());
+                block.get().parse(ctx);
+            }
             Expr::FuncApp { func, args } => {
                 func.parse(ctx);
                 for expr in args.get().into_iter() {
                     expr.parse(ctx);
                 }
             }
+            Expr::NamedArg { value, .. } => {
+                value.parse(ctx);
+            }
             Expr::Index { target, arg } => {
                 target.parse(ctx);
                 arg.get().parse(ctx);
@@ -182,6 +189,9 @@ impl Parse for Expr {
             Expr::TupleFieldProjection { target, .. } => {
                 target.parse(ctx);
             }
+            Expr::Try { expr, .. } => {
+                expr.parse(ctx);
+            }
             Expr::Ref { ref_token, expr } => {
                 insert_keyword(ctx, ref_token.span());
                 expr.parse(ctx);
@@ -462,6 +472,12 @@ impl Parse for Ty {
                 insert_keyword(ctx, str_token.span());
                 length.get().parse(ctx);
             }
+            Ty::UnparenthesizedTuple { head, tail, .. } => {
+                head.parse(ctx);
+                for ty in tail.into_iter() {
+                    ty.parse(ctx);
+                }
+            }
             _ => {}
         }
     }
@@ -472,6 +488,12 @@ impl Parse for FnSignature {
         if let Some(visibility) = &self.visibility {
             insert_keyword(ctx, visibility.span());
         }
+        if let Some(package_scope) = &self.package_scope_opt {
+            insert_keyword(ctx, package_scope.get().span());
+        }
+        if let Some(const_token) = &self.const_token {
+            insert_keyword(ctx, const_token.span());
+        }
         insert_keyword(ctx, self.fn_token.span());
 
         self.arguments.get().parse(ctx);
@@ -515,6 +537,9 @@ impl Parse for FnArg {
     fn parse(&self, ctx: &ParseContext) {
         self.pattern.parse(ctx);
         self.ty.parse(ctx);
+        if let Some((_eq_token, expr)) = &self.default_opt {
+            expr.parse(ctx);
+        }
     }
 }
 
@@ -553,6 +578,10 @@ impl Parse for StatementLet {
             ty.parse(ctx);
         }
         self.expr.parse(ctx);
+        if let Some((else_token, block)) = &self.else_opt {
+            insert_keyword(ctx, else_token.span());
+            block.get().parse(ctx);
+        }
     }
 }
 
@@ -20,12 +20,12 @@ use sway_core::{
             FunctionApplicationExpression, FunctionDeclaration, FunctionParameter, IfExpression,
             ImplItem, ImplSelf, ImplTrait, ImportType, IntrinsicFunctionExpression,
             LazyOperatorExpression, MatchExpression, MethodApplicationExpression, MethodName,
-            ParseModule, ParseProgram, ParseSubmodule, ReassignmentExpression, ReassignmentTarget,
-            Scrutinee, StorageAccessExpression, StorageDeclaration, StorageField,
-            StructDeclaration, StructExpression, StructExpressionField, StructField,
-            StructScrutineeField, SubfieldExpression, Supertrait, TraitDeclaration, TraitFn,
-            TraitItem, TupleIndexExpression, TypeAliasDeclaration, UseStatement,
-            VariableDeclaration, WhileLoopExpression,
+            NamedArgumentExpression, ParseModule, ParseProgram, ParseSubmodule,
+            ReassignmentExpression, ReassignmentTarget, Scrutinee, StorageAccessExpression,
+            StorageDeclaration, StorageField, StructDeclaration, StructExpression,
+            StructExpressionField, StructField, StructScrutineeField, SubfieldExpression,
+            Supertrait, TraitDeclaration, TraitFn, TraitItem, TupleIndexExpression,
+            TypeAliasDeclaration, UseStatement, VariableDeclaration, WhileLoopExpression,
         },
         CallPathTree, Literal,
     },
@@ -33,7 +33,9 @@ use sway_core::{
     type_system::{TypeArgument, TypeParameter},
     TraitConstraint, TypeInfo,
 };
-use sway_types::constants::{DESTRUCTURE_PREFIX, MATCH_RETURN_VAR_NAME_PREFIX, TUPLE_NAME_PREFIX};
+use sway_types::constants::{
+    DESTRUCTURE_PREFIX, MATCH_RETURN_VAR_NAME_PREFIX, TRY_VAR_NAME_PREFIX, TUPLE_NAME_PREFIX,
+};
 use sway_types::{Ident, Span, Spanned};
 
 pub struct ParsedTree<'a> {
@@ -186,6 +188,9 @@ impl Parse for Expression {
             ExpressionKind::FunctionApplication(function_application_expression) => {
                 function_application_expression.parse(ctx);
             }
+            ExpressionKind::NamedArgument(NamedArgumentExpression { value, .. }) => {
+                value.parse(ctx);
+            }
             ExpressionKind::LazyOperator(LazyOperatorExpression { lhs, rhs, .. }) => {
                 lhs.parse(ctx);
                 rhs.parse(ctx);
@@ -193,6 +198,7 @@ impl Parse for Expression {
             ExpressionKind::Variable(name) => {
                 if !name.as_str().contains(TUPLE_NAME_PREFIX)
                     && !name.as_str().contains(MATCH_RETURN_VAR_NAME_PREFIX)
+                    && !name.as_str().contains(TRY_VAR_NAME_PREFIX)
                 {
                     let symbol_kind = if name.as_str().contains(DESTRUCTURE_PREFIX) {
                         SymbolKind::Struct
@@ -228,7 +234,9 @@ impl Parse for Expression {
             ExpressionKind::Struct(struct_expression) => {
                 struct_expression.parse(ctx);
             }
-            ExpressionKind::CodeBlock(code_block) => {
+            ExpressionKind::CodeBlock(code_block)
+            | ExpressionKind::TryBlock(code_block)
+            | ExpressionKind::LetElseBlock(code_block) => {
                 code_block.contents.iter().for_each(|node| node.parse(ctx));
             }
             ExpressionKind::If(IfExpression {
@@ -321,7 +329,7 @@ impl Parse for Expression {
             ExpressionKind::Reassignment(reassignment) => {
                 reassignment.parse(ctx);
             }
-            ExpressionKind::Return(expr) => {
+            ExpressionKind::Return(expr) | ExpressionKind::Try(expr) => {
                 expr.parse(ctx);
             }
             // We are collecting these tokens in the lexed phase.
@@ -662,9 +670,11 @@ impl Parse for FunctionApplicationExpression {
 impl Parse for VariableDeclaration {
     fn parse(&self, ctx: &ParseContext) {
         // Don't collect tokens if the ident's name contains __tuple_ || __match_return_var_name_
+        // || __try_var_name_
         // The individual elements are handled in the subsequent VariableDeclaration's
         if !self.name.as_str().contains(TUPLE_NAME_PREFIX)
             && !self.name.as_str().contains(MATCH_RETURN_VAR_NAME_PREFIX)
+            && !self.name.as_str().contains(TRY_VAR_NAME_PREFIX)
         {
             let symbol_kind = if self.name.as_str().contains(DESTRUCTURE_PREFIX) {
                 SymbolKind::Struct
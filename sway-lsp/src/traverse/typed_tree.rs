@@ -348,7 +348,8 @@ impl Parse for ty::TyExpression {
                     field.value.parse(ctx);
                 });
             }
-            ty::TyExpressionVariant::CodeBlock(code_block) => {
+            ty::TyExpressionVariant::CodeBlock(code_block)
+            | ty::TyExpressionVariant::TryBlock(code_block) => {
                 code_block.contents.iter().for_each(|node| node.parse(ctx));
             }
             ty::TyExpressionVariant::FunctionParameter { .. } => {}
@@ -574,7 +575,9 @@ impl Parse for ty::TyExpression {
             ty::TyExpressionVariant::Reassignment(reassignment) => {
                 reassignment.parse(ctx);
             }
-            ty::TyExpressionVariant::Return(exp) => exp.parse(ctx),
+            ty::TyExpressionVariant::Return(exp) | ty::TyExpressionVariant::TryBreak(exp) => {
+                exp.parse(ctx)
+            }
         }
     }
 }
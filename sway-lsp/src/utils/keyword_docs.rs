@@ -755,6 +755,21 @@ impl KeywordDocs {
             mod type_keyword {}
         };
 
+        let try_keyword: ItemMod = parse_quote! {
+            /// Group fallible operations in a block.
+            ///
+            /// Within a `try` block, the `?` operator short-circuits to the end of the block
+            /// instead of returning from the function. The block evaluates to a `Result`: the
+            /// first `Err` propagated by a `?`, or `Ok` of the block's value otherwise.
+            ///
+            /// ```sway
+            /// let sum: Result<u64, Error> = try {
+            ///     parse(a)? + parse(b)?
+            /// };
+            /// ```
+            mod try_keyword {}
+        };
+
         let mut keyword_docs = HashMap::new();
 
         let keywords = vec![
@@ -794,6 +809,7 @@ impl KeywordDocs {
             deref_keyword,
             configurable_keyword,
             type_keyword,
+            try_keyword,
         ];
 
         keywords.iter().for_each(|keyword| {
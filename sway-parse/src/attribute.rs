@@ -6,9 +6,10 @@ use sway_ast::brackets::{Parens, SquareBrackets};
 use sway_ast::keywords::{EqToken, HashBangToken, HashToken, StorageToken, Token};
 use sway_ast::punctuated::Punctuated;
 use sway_ast::token::{DocComment, DocStyle};
+use sway_ast::Literal;
 use sway_error::parser_error::ParseErrorKind;
 use sway_types::constants::DOC_COMMENT_ATTRIBUTE_NAME;
-use sway_types::Ident;
+use sway_types::{Ident, Spanned};
 
 impl Peek for DocComment {
     fn peek(peeker: Peeker<'_>) -> Option<DocComment> {
@@ -27,37 +28,7 @@ impl Parse for DocComment {
 
 impl<T: Parse> Parse for Annotated<T> {
     fn parse(parser: &mut Parser) -> ParseResult<Self> {
-        // Parse the attribute list.
-        let mut attribute_list = Vec::new();
-        while let Some(DocComment {
-            doc_style: DocStyle::Outer,
-            ..
-        }) = parser.peek()
-        {
-            let doc_comment = parser.parse::<DocComment>()?;
-            // TODO: Use a Literal instead of an Ident when Attribute args
-            // start supporting them and remove `Ident::new_no_trim`.
-            let name = Ident::new_no_trim(doc_comment.content_span.clone());
-            attribute_list.push(AttributeDecl {
-                hash_kind: AttributeHashKind::Outer(HashToken::new(doc_comment.span.clone())),
-                attribute: SquareBrackets::new(
-                    Punctuated::single(Attribute {
-                        name: Ident::new_with_override(
-                            DOC_COMMENT_ATTRIBUTE_NAME.to_string(),
-                            doc_comment.span.clone(),
-                        ),
-                        args: Some(Parens::new(
-                            Punctuated::single(AttributeArg { name, value: None }),
-                            doc_comment.content_span,
-                        )),
-                    }),
-                    doc_comment.span,
-                ),
-            });
-        }
-        while let Some(attr) = parser.guarded_parse::<HashToken, _>()? {
-            attribute_list.push(attr);
-        }
+        let attribute_list = parse_attribute_list(parser)?;
 
         // Parse the `T` value.
         let value = parser.parse()?;
@@ -69,6 +40,46 @@ impl<T: Parse> Parse for Annotated<T> {
     }
 }
 
+/// Parses the outer doc comments and attributes preceding an item, a field or a function
+/// argument. The doc comments become `doc-comment` attributes.
+pub(crate) fn parse_attribute_list(parser: &mut Parser) -> ParseResult<Vec<AttributeDecl>> {
+    let mut attribute_list = Vec::new();
+    while let Some(DocComment {
+        doc_style: DocStyle::Outer,
+        ..
+    }) = parser.peek()
+    {
+        let doc_comment = parser.parse::<DocComment>()?;
+        // TODO: Use a Literal instead of an Ident when Attribute args
+        // start supporting them and remove `Ident::new_no_trim`.
+        let name = Ident::new_no_trim(doc_comment.content_span.clone());
+        attribute_list.push(AttributeDecl {
+            hash_kind: AttributeHashKind::Outer(HashToken::new(doc_comment.span.clone())),
+            attribute: SquareBrackets::new(
+                Punctuated::single(Attribute {
+                    name: Ident::new_with_override(
+                        DOC_COMMENT_ATTRIBUTE_NAME.to_string(),
+                        doc_comment.span.clone(),
+                    ),
+                    args: Some(Parens::new(
+                        Punctuated::single(AttributeArg {
+                            name,
+                            value: None,
+                            args: None,
+                        }),
+                        doc_comment.content_span,
+                    )),
+                }),
+                doc_comment.span,
+            ),
+        });
+    }
+    while let Some(attr) = parser.guarded_parse::<HashToken, _>()? {
+        attribute_list.push(attr);
+    }
+    Ok(attribute_list)
+}
+
 impl Parse for AttributeDecl {
     fn parse(parser: &mut Parser) -> ParseResult<Self> {
         Ok(AttributeDecl {
@@ -92,6 +103,17 @@ impl Parse for AttributeHashKind {
 
 impl Parse for AttributeArg {
     fn parse(parser: &mut Parser) -> ParseResult<Self> {
+        // A string or integer argument, like the ones of `#[selector("foo(u64)")]` and
+        // `#[gas_hint(100)]`, becomes the name of the argument, quotes included.
+        if let Some(lit @ (Literal::String(_) | Literal::Int(_))) = parser.peek::<Literal>() {
+            parser.take::<Literal>();
+            let name = Ident::new_no_trim(lit.span());
+            return Ok(AttributeArg {
+                name,
+                value: None,
+                args: None,
+            });
+        }
         let name = parser.parse()?;
         match parser.take::<EqToken>() {
             Some(_) => {
@@ -99,9 +121,15 @@ impl Parse for AttributeArg {
                 Ok(AttributeArg {
                     name,
                     value: Some(value),
+                    args: None,
                 })
             }
-            None => Ok(AttributeArg { name, value: None }),
+            // A nested attribute, like the `inline(always)` of `#[cfg_attr(.., inline(always))]`.
+            None => Ok(AttributeArg {
+                name,
+                value: None,
+                args: Parens::try_parse(parser)?,
+            }),
         }
     }
 }
@@ -113,6 +141,23 @@ impl Parse for Attribute {
         } else {
             parser.parse()?
         };
+        // `#[doc = "..."]` is a shorthand for `#[doc("...")]`.
+        if parser.take::<EqToken>().is_some() {
+            let lit = match parser.take::<Literal>() {
+                Some(Literal::String(lit)) => lit,
+                _ => return Err(parser.emit_error(ParseErrorKind::ExpectedStringLiteral)),
+            };
+            let arg = AttributeArg {
+                name: Ident::new_no_trim(lit.span.clone()),
+                value: None,
+                args: None,
+            };
+            let args = Parens::new(Punctuated::single(arg), lit.span);
+            return Ok(Attribute {
+                name,
+                args: Some(args),
+            });
+        }
         let args = Parens::try_parse(parser)?;
         Ok(Attribute { name, args })
     }
@@ -169,6 +214,7 @@ mod tests {
                             span: (85, 108),
                           ),
                           value: None,
+                          args: None,
                         )),
                       ),
                       span: (85, 108),
@@ -199,6 +245,7 @@ mod tests {
                             span: (131, 135),
                           ),
                           value: None,
+                          args: None,
                         )),
                       ),
                       span: (130, 136),
@@ -212,6 +259,8 @@ mod tests {
           value: ItemFn(
             fn_signature: FnSignature(
               visibility: None,
+              package_scope_opt: None,
+              const_token: None,
               fn_token: FnToken(
                 span: (150, 152),
               ),
@@ -264,6 +313,7 @@ mod tests {
                     span: (18, 22),
                   ),
                   value: None,
+                  args: None,
                 ), CommaToken(
                   span: (22, 23),
                 )),
@@ -276,6 +326,7 @@ mod tests {
                     span: (31, 38),
                     parsed: "value",
                   ))),
+                  args: None,
                 ), CommaToken(
                   span: (38, 39),
                 )),
@@ -286,6 +337,7 @@ mod tests {
                   span: (40, 44),
                 ),
                 value: None,
+                args: None,
               )),
             ),
             span: (17, 45),
@@ -293,4 +345,24 @@ mod tests {
         )
         "###);
     }
+
+    #[test]
+    fn parse_nested_attribute_arg() {
+        let attr = parse::<Attribute>(r#"cfg_attr(target = "fuel", inline(always))"#);
+        let args = attr.args.expect("expected arguments");
+        let args = args.get().into_iter().collect::<Vec<_>>();
+        assert_eq!(args.len(), 2);
+        assert!(args[0].args.is_none());
+        assert_eq!(args[1].name.as_str(), "inline");
+        assert_eq!(args[1].span().as_str(), "inline(always)");
+        let nested = args[1].args.as_ref().expect("expected nested arguments");
+        assert_eq!(
+            nested
+                .get()
+                .into_iter()
+                .map(|arg| arg.name.as_str())
+                .collect::<Vec<_>>(),
+            ["always"]
+        );
+    }
 }
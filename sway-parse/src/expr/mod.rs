@@ -3,9 +3,9 @@ use crate::{Parse, ParseBracket, ParseResult, ParseToEnd, Parser, ParserConsumed
 use sway_ast::brackets::{Braces, Parens, SquareBrackets};
 use sway_ast::expr::{LoopControlFlow, ReassignmentOp, ReassignmentOpVariant};
 use sway_ast::keywords::{
-    AbiToken, AddEqToken, AsmToken, CommaToken, ConfigurableToken, ConstToken, DivEqToken,
-    DoubleColonToken, EnumToken, EqToken, FalseToken, FnToken, IfToken, ImplToken, LetToken,
-    OpenAngleBracketToken, PubToken, SemicolonToken, ShlEqToken, ShrEqToken, StarEqToken,
+    AbiToken, AddEqToken, AsmToken, ColonToken, CommaToken, ConfigurableToken, ConstToken,
+    DivEqToken, DoubleColonToken, EnumToken, EqToken, FalseToken, FnToken, IfToken, ImplToken,
+    LetToken, OpenAngleBracketToken, PubToken, SemicolonToken, ShlEqToken, ShrEqToken, StarEqToken,
     StorageToken, StructToken, SubEqToken, Token, TraitToken, TrueToken, TypeToken, UseToken,
 };
 use sway_ast::literal::{LitBool, LitBoolType};
@@ -107,6 +107,11 @@ impl Parse for StatementLet {
         let on_err = |_| Expr::Error([eq_token.span()].into());
         let expr = parser.parse().unwrap_or_else(on_err);
 
+        let else_opt = match parser.take() {
+            Some(else_token) => Some((else_token, parser.parse()?)),
+            None => None,
+        };
+
         // Recover on missing semicolon.
         let semicolon_token = parser
             .parse()
@@ -118,6 +123,7 @@ impl Parse for StatementLet {
             ty_opt,
             eq_token,
             expr,
+            else_opt,
             semicolon_token,
         })
     }
@@ -515,6 +521,13 @@ fn parse_projection(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr>
             expr = Expr::Index { target, arg };
             continue;
         }
+        if let Some(question_mark_token) = parser.take() {
+            expr = Expr::Try {
+                expr: Box::new(expr),
+                question_mark_token,
+            };
+            continue;
+        }
         if let Some(dot_token) = parser.take() {
             let target = Box::new(expr);
 
@@ -609,13 +622,51 @@ fn parse_func_app(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     if expr.is_control_flow() && ctx.at_start_of_statement {
         return Ok(expr);
     }
-    while let Some(args) = Parens::try_parse(parser)? {
+    while let Some((parser, span)) = parser.enter_delimited(Delimiter::Parenthesis) {
+        let (args, _consumed) = parse_func_app_args(parser)?;
         let func = Box::new(expr);
-        expr = Expr::FuncApp { func, args };
+        expr = Expr::FuncApp {
+            func,
+            args: Parens::new(args, span),
+        };
     }
     Ok(expr)
 }
 
+/// Parses the arguments of a function call, each of which may be labeled with the name of its
+/// parameter, e.g. `transfer(to: addr, amount: 100)`.
+fn parse_func_app_args<'a>(
+    mut parser: Parser<'a, '_>,
+) -> ParseResult<(Punctuated<Expr, CommaToken>, ParserConsumed<'a>)> {
+    let mut value_separator_pairs = Vec::new();
+    loop {
+        if let Some(consumed) = parser.check_empty() {
+            let punctuated = Punctuated {
+                value_separator_pairs,
+                final_value_opt: None,
+            };
+            return Ok((punctuated, consumed));
+        }
+        let value = match parser.peek::<(Ident, ColonToken)>() {
+            Some(_) => Expr::NamedArg {
+                name: parser.parse()?,
+                colon_token: parser.parse()?,
+                value: Box::new(parser.parse()?),
+            },
+            None => parser.parse()?,
+        };
+        if let Some(consumed) = parser.check_empty() {
+            let punctuated = Punctuated {
+                value_separator_pairs,
+                final_value_opt: Some(Box::new(value)),
+            };
+            return Ok((punctuated, consumed));
+        }
+        let separator = parser.parse()?;
+        value_separator_pairs.push((value, separator));
+    }
+}
+
 fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     if let Some(code_block_inner) = Braces::try_parse(parser)? {
         return Ok(Expr::Block(code_block_inner));
@@ -703,6 +754,10 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
             block,
         });
     }
+    if let Some(try_token) = parser.take() {
+        let block = parser.parse()?;
+        return Ok(Expr::TryBlock { try_token, block });
+    }
     if parser.peek::<OpenAngleBracketToken>().is_some()
         || parser.peek::<DoubleColonToken>().is_some()
         || parser.peek::<Ident>().is_some()
@@ -812,3 +867,80 @@ impl Parse for MatchBranchKind {
         Ok(MatchBranchKind::Expr { expr, comma_token })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse;
+
+    #[test]
+    fn parse_func_app_named_args() {
+        let expr = parse::<Expr>("transfer(recipient, amount: 100, to: x::y)");
+        let args = match expr {
+            Expr::FuncApp { args, .. } => args.into_inner().into_iter().collect::<Vec<_>>(),
+            _ => panic!("expected function application"),
+        };
+        assert_eq!(args.len(), 3);
+        assert!(matches!(args[0], Expr::Path(_)));
+        let named = args[1..]
+            .iter()
+            .map(|arg| match arg {
+                Expr::NamedArg { name, value, .. } => {
+                    (name.as_str(), value.span().as_str().to_string())
+                }
+                _ => panic!("expected named argument"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            named,
+            [("amount", "100".to_string()), ("to", "x::y".to_string())]
+        );
+    }
+
+    #[test]
+    fn parse_try_operator() {
+        let expr = parse::<Expr>("foo()?.bar?");
+        let (target, question_mark_token) = match expr {
+            Expr::Try {
+                expr,
+                question_mark_token,
+            } => (expr, question_mark_token),
+            _ => panic!("expected the `?` operator"),
+        };
+        assert_eq!(question_mark_token.span().as_str(), "?");
+        let inner = match *target {
+            Expr::FieldProjection { target, .. } => target,
+            _ => panic!("expected field projection"),
+        };
+        assert!(matches!(*inner, Expr::Try { .. }));
+        assert_eq!(inner.span().as_str(), "foo()?");
+    }
+
+    #[test]
+    fn parse_try_block() {
+        let expr = parse::<Expr>("try { foo()?; bar()? }");
+        let block = match expr {
+            Expr::TryBlock { block, .. } => block,
+            _ => panic!("expected try block"),
+        };
+        let contents = block.into_inner();
+        assert_eq!(contents.statements.len(), 1);
+        assert!(matches!(
+            contents.final_expr_opt.as_deref(),
+            Some(Expr::Try { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_let_else() {
+        let statement_let =
+            parse::<StatementLet>("let Option::Some(x) = opt else { return Option::None; };");
+        assert_eq!(statement_let.expr.span().as_str(), "opt");
+        let (else_token, block) = statement_let.else_opt.expect("expected an else block");
+        assert_eq!(else_token.span().as_str(), "else");
+        assert_eq!(block.get().statements.len(), 1);
+
+        let statement_let = parse::<StatementLet>("let x = opt;");
+        assert!(statement_let.else_opt.is_none());
+    }
+}
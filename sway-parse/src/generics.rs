@@ -1,24 +1,51 @@
 use crate::{Parse, ParseResult, Parser};
 
-use sway_ast::keywords::CommaToken;
+use sway_ast::keywords::{ColonToken, CommaToken, ConstToken};
 use sway_ast::punctuated::Punctuated;
-use sway_ast::{AngleBrackets, GenericArgs, GenericParams};
+use sway_ast::{AngleBrackets, GenericArgs, GenericParam, GenericParams};
+use sway_error::parser_error::ParseErrorKind;
+use sway_types::Spanned;
 
 impl Parse for GenericParams {
     fn parse(parser: &mut Parser) -> ParseResult<GenericParams> {
-        parse_angle_comma(parser).map(|parameters| GenericParams { parameters })
+        parse_angle_comma_with(parser, |parser| {
+            // Only type parameters are supported, so `<const N: u64>` gets a dedicated error
+            // rather than complaining about `const` being a reserved keyword.
+            if let Some(const_token) = parser.take::<ConstToken>() {
+                return Err(parser.emit_error_with_span(
+                    ParseErrorKind::ConstGenericParameter,
+                    const_token.span(),
+                ));
+            }
+            parser.parse()
+        })
+        .map(|parameters| GenericParams { parameters })
+    }
+}
+
+impl Parse for GenericParam {
+    fn parse(parser: &mut Parser) -> ParseResult<GenericParam> {
+        let name = parser.parse()?;
+        let bounds_opt = match parser.take::<ColonToken>() {
+            Some(colon_token) => Some((colon_token, parser.parse()?)),
+            None => None,
+        };
+        Ok(GenericParam { name, bounds_opt })
     }
 }
 
 impl Parse for GenericArgs {
     fn parse(parser: &mut Parser) -> ParseResult<GenericArgs> {
-        parse_angle_comma(parser).map(|parameters| GenericArgs { parameters })
+        parse_angle_comma_with(parser, |parser| parser.parse())
+            .map(|parameters| GenericArgs { parameters })
     }
 }
 
-/// Parse a list of `T`s delimited by `<` and `>` and separated by `,`.
-fn parse_angle_comma<T: Parse>(
+/// Parse a list of `T`s delimited by `<` and `>` and separated by `,`, using `parse_value` to
+/// parse each `T`.
+fn parse_angle_comma_with<T>(
     parser: &mut Parser,
+    parse_value: impl Fn(&mut Parser) -> ParseResult<T>,
 ) -> ParseResult<AngleBrackets<Punctuated<T, CommaToken>>> {
     let open_angle_bracket_token = parser.parse()?;
     let mut value_separator_pairs = Vec::new();
@@ -26,7 +53,7 @@ fn parse_angle_comma<T: Parse>(
         if let Some(close_angle_bracket_token) = parser.take() {
             break (None, close_angle_bracket_token);
         };
-        let ident = parser.parse()?;
+        let ident = parse_value(parser)?;
         if let Some(close_angle_bracket_token) = parser.take() {
             break (Some(Box::new(ident)), close_angle_bracket_token);
         };
@@ -7,7 +7,10 @@ use sway_error::parser_error::ParseErrorKind;
 
 impl Parse for ItemImplItem {
     fn parse(parser: &mut Parser) -> ParseResult<ItemImplItem> {
-        if parser.peek::<PubToken>().is_some() || parser.peek::<FnToken>().is_some() {
+        if parser.peek::<PubToken>().is_some()
+            || parser.peek::<FnToken>().is_some()
+            || parser.peek::<(ConstToken, FnToken)>().is_some()
+        {
             let fn_decl = parser.parse()?;
             Ok(ItemImplItem::Fn(fn_decl))
         } else if let Some(_const_keyword) = parser.peek::<ConstToken>() {
@@ -1,15 +1,18 @@
-use crate::{Parse, ParseResult, ParseToEnd, Parser, ParserConsumed};
+use crate::attribute::parse_attribute_list;
+use crate::{Parse, ParseBracket, ParseResult, ParseToEnd, Parser, ParserConsumed};
 
+use sway_ast::brackets::Parens;
 use sway_ast::keywords::{
-    AbiToken, ClassToken, ConfigurableToken, ConstToken, EnumToken, FnToken, ImplToken, ModToken,
-    MutToken, OpenAngleBracketToken, RefToken, SelfToken, SemicolonToken, StorageToken,
-    StructToken, TraitToken, TypeToken, UseToken, WhereToken,
+    AbiToken, ClassToken, CommaToken, ConfigurableToken, ConstToken, EnumToken, FnToken, ImplToken,
+    Keyword, ModToken, MutToken, OpenAngleBracketToken, PackageToken, RefToken, SelfToken,
+    SemicolonToken, StorageToken, StructToken, TraitToken, TypeToken, UseToken, WhereToken,
 };
 use sway_ast::{
     FnArg, FnArgs, FnSignature, ItemConst, ItemEnum, ItemFn, ItemKind, ItemStruct, ItemTrait,
-    ItemTypeAlias, ItemUse, Submodule, TypeField,
+    ItemTypeAlias, ItemUse, Punctuated, Submodule, Ty, TypeField,
 };
 use sway_error::parser_error::ParseErrorKind;
+use sway_types::Spanned;
 
 mod item_abi;
 mod item_configurable;
@@ -30,6 +33,10 @@ impl Parse for ItemKind {
         // and then validate in an "AST validation" step which kinds that should have `pub`s.
 
         let mut visibility = parser.take();
+        let mut package_scope_opt = match visibility {
+            Some(_) => parse_package_scope(parser)?,
+            None => None,
+        };
 
         let kind = if let Some(mut item) = parser.guarded_parse::<ModToken, Submodule>()? {
             item.visibility = visibility.take();
@@ -48,6 +55,11 @@ impl Parse for ItemKind {
             ItemKind::Enum(item)
         } else if let Some(mut item) = parser.guarded_parse::<FnToken, ItemFn>()? {
             item.fn_signature.visibility = visibility.take();
+            item.fn_signature.package_scope_opt = package_scope_opt.take();
+            ItemKind::Fn(item)
+        } else if let Some(mut item) = parser.guarded_parse::<(ConstToken, FnToken), ItemFn>()? {
+            item.fn_signature.visibility = visibility.take();
+            item.fn_signature.package_scope_opt = package_scope_opt.take();
             ItemKind::Fn(item)
         } else if let Some(mut item) = parser.guarded_parse::<TraitToken, ItemTrait>()? {
             item.visibility = visibility.take();
@@ -73,6 +85,12 @@ impl Parse for ItemKind {
 
         // Ban visibility qualifiers that haven't been consumed, but do so with recovery.
         let _ = parser.ban_visibility_qualifier(&visibility);
+        if let Some(package_scope) = package_scope_opt {
+            parser.emit_error_with_span(
+                ParseErrorKind::PackageVisibilityOnNonFunction,
+                package_scope.span(),
+            );
+        }
 
         Ok(kind)
     }
@@ -140,24 +158,39 @@ impl ParseToEnd for FnArgs {
 impl Parse for FnArg {
     fn parse(parser: &mut Parser) -> ParseResult<FnArg> {
         Ok(FnArg {
+            attribute_list: parse_attribute_list(parser)?,
             pattern: parser.parse()?,
             colon_token: parser.parse()?,
             ty: parser.parse()?,
+            default_opt: match parser.take() {
+                Some(eq_token) => {
+                    let expr = parser.parse()?;
+                    Some((eq_token, expr))
+                }
+                None => None,
+            },
         })
     }
 }
 
 impl Parse for FnSignature {
     fn parse(parser: &mut Parser) -> ParseResult<FnSignature> {
+        let visibility = parser.take();
+        let package_scope_opt = match visibility {
+            Some(_) => parse_package_scope(parser)?,
+            None => None,
+        };
         Ok(FnSignature {
-            visibility: parser.take(),
+            visibility,
+            package_scope_opt,
+            const_token: parser.take(),
             fn_token: parser.parse()?,
             name: parser.parse()?,
             generics: parser.guarded_parse::<OpenAngleBracketToken, _>()?,
             arguments: parser.parse()?,
             return_type_opt: match parser.take() {
                 Some(right_arrow_token) => {
-                    let ty = parser.parse()?;
+                    let ty = parse_return_type(parser)?;
                     Some((right_arrow_token, ty))
                 }
                 None => None,
@@ -167,6 +200,40 @@ impl Parse for FnSignature {
     }
 }
 
+/// Parses the `(package)` which may follow `pub`, restricting the visibility to the package.
+fn parse_package_scope(parser: &mut Parser) -> ParseResult<Option<Parens<PackageToken>>> {
+    Parens::try_parse_all_inner(parser, |mut parser| {
+        parser.emit_error(ParseErrorKind::ExpectedKeyword {
+            word: PackageToken::AS_STR,
+        })
+    })
+}
+
+/// Parses the return type of a function, where a comma separated list of types without
+/// parentheses, e.g. `-> u64, bool`, stands for the tuple of those types.
+fn parse_return_type(parser: &mut Parser) -> ParseResult<Ty> {
+    let head: Ty = parser.parse()?;
+    let comma_token: CommaToken = match parser.take() {
+        Some(comma_token) => comma_token,
+        None => return Ok(head),
+    };
+    let mut last: Ty = parser.parse()?;
+    let mut value_separator_pairs = vec![];
+    while let Some(comma_token) = parser.take() {
+        let next = parser.parse()?;
+        value_separator_pairs.push((std::mem::replace(&mut last, next), comma_token));
+    }
+    let tail = Punctuated {
+        value_separator_pairs,
+        final_value_opt: Some(Box::new(last)),
+    };
+    Ok(Ty::UnparenthesizedTuple {
+        head: Box::new(head),
+        comma_token,
+        tail,
+    })
+}
+
 // -------------------------------------------------------------------------------------------------
 
 #[cfg(test)]
@@ -174,6 +241,7 @@ mod tests {
     use super::*;
     use crate::test_utils::parse;
     use sway_ast::{AttributeDecl, Item, ItemTraitItem};
+    use sway_types::Spanned;
 
     // Attribute name and its list of parameters
     type ParameterizedAttr<'a> = (&'a str, Option<Vec<&'a str>>);
@@ -199,6 +267,99 @@ mod tests {
             .collect()
     }
 
+    #[test]
+    fn parse_fn_arg_default_value() {
+        let item = parse::<Item>(
+            r#"
+            fn f(a: u64, b: u64 = 42) {}
+            "#,
+        );
+        let fn_args = match item.value {
+            ItemKind::Fn(item_fn) => item_fn.fn_signature.arguments.into_inner(),
+            _ => panic!("expected function"),
+        };
+        let args = match fn_args {
+            FnArgs::Static(args) => args.into_iter().collect::<Vec<_>>(),
+            _ => panic!("expected static function arguments"),
+        };
+        assert!(args[0].default_opt.is_none());
+        let (_eq_token, default_value) = args[1].default_opt.as_ref().unwrap();
+        assert_eq!(default_value.span().as_str(), "42");
+    }
+
+    #[test]
+    fn parse_fn_arg_attributes() {
+        let item = parse::<Item>(
+            r#"
+            fn f(
+                /// The first.
+                a: u64,
+                #[doc = "The second."]
+                b: u64,
+                c: u64,
+            ) {}
+            "#,
+        );
+        let fn_args = match item.value {
+            ItemKind::Fn(item_fn) => item_fn.fn_signature.arguments.into_inner(),
+            _ => panic!("expected function"),
+        };
+        let args = match fn_args {
+            FnArgs::Static(args) => args.into_iter().collect::<Vec<_>>(),
+            _ => panic!("expected static function arguments"),
+        };
+        let attr_names = args
+            .iter()
+            .map(|arg| {
+                arg.attribute_list
+                    .iter()
+                    .flat_map(|attr_decl| attr_decl.attribute.get().into_iter())
+                    .map(|attr| attr.name.as_str().to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            attr_names,
+            vec![vec!["doc-comment"], vec!["doc"], Vec::<&str>::new()]
+        );
+    }
+
+    #[test]
+    fn parse_unparenthesized_tuple_return_type() {
+        let item = parse::<Item>(
+            r#"
+            fn f() -> u64, (bool, u8), b256 {}
+            "#,
+        );
+        let return_type = match item.value {
+            ItemKind::Fn(item_fn) => item_fn.fn_signature.return_type_opt.unwrap().1,
+            _ => panic!("expected function"),
+        };
+        assert_eq!(return_type.span().as_str(), "u64, (bool, u8), b256");
+        let tys = match return_type {
+            Ty::UnparenthesizedTuple { head, tail, .. } => {
+                std::iter::once(*head).chain(tail).collect::<Vec<_>>()
+            }
+            _ => panic!("expected unparenthesized tuple return type"),
+        };
+        let tys = tys.iter().map(|ty| ty.span().as_str().to_string());
+        assert_eq!(tys.collect::<Vec<_>>(), ["u64", "(bool, u8)", "b256"]);
+    }
+
+    #[test]
+    fn parse_single_return_type() {
+        let item = parse::<Item>(
+            r#"
+            fn f() -> u64 {}
+            "#,
+        );
+        let return_type = match item.value {
+            ItemKind::Fn(item_fn) => item_fn.fn_signature.return_type_opt.unwrap().1,
+            _ => panic!("expected function"),
+        };
+        assert!(matches!(return_type, Ty::Path(_)));
+    }
+
     #[test]
     fn parse_doc_comment() {
         let item = parse::<Item>(
@@ -615,4 +776,74 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn parse_generic_params_inline_bounds() {
+        let item = parse::<Item>(
+            r#"
+            fn f<T: Eq + Ord, U>(t: T, u: U) where T: Hash {}
+            "#,
+        );
+
+        let item_fn = match item.value {
+            ItemKind::Fn(item_fn) => item_fn,
+            _ => panic!("Parsed item is not a function."),
+        };
+        let generics = item_fn
+            .fn_signature
+            .generics
+            .expect("expected generic params");
+        let params = generics
+            .parameters
+            .into_inner()
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(params.len(), 2);
+        assert_eq!(params[0].name.as_str(), "T");
+        let (_, bounds) = params[0]
+            .bounds_opt
+            .as_ref()
+            .expect("expected inline bounds");
+        assert_eq!(bounds.span().as_str(), "Eq + Ord");
+        assert_eq!(params[1].name.as_str(), "U");
+        assert!(params[1].bounds_opt.is_none());
+        assert!(item_fn.fn_signature.where_clause_opt.is_some());
+    }
+
+    #[test]
+    fn parse_const_fn() {
+        let item = parse::<Item>("pub const fn f() -> u64 { 0 }");
+        let item_fn = match item.value {
+            ItemKind::Fn(item_fn) => item_fn,
+            _ => panic!("Parsed item is not a function."),
+        };
+        assert!(item_fn.fn_signature.visibility.is_some());
+        assert!(item_fn.fn_signature.const_token.is_some());
+        assert_eq!(
+            item_fn.fn_signature.span().as_str(),
+            "pub const fn f() -> u64"
+        );
+
+        let item = parse::<Item>("const X: u64 = 0;");
+        assert!(matches!(item.value, ItemKind::Const(_)));
+    }
+
+    #[test]
+    fn parse_pub_package_fn() {
+        let item = parse::<Item>("pub(package) fn f() {}");
+        let item_fn = match item.value {
+            ItemKind::Fn(item_fn) => item_fn,
+            _ => panic!("Parsed item is not a function."),
+        };
+        assert!(item_fn.fn_signature.visibility.is_some());
+        assert!(item_fn.fn_signature.package_scope_opt.is_some());
+        assert_eq!(item_fn.fn_signature.span().as_str(), "pub(package) fn f()");
+
+        let item = parse::<Item>("pub fn f() {}");
+        let item_fn = match item.value {
+            ItemKind::Fn(item_fn) => item_fn,
+            _ => panic!("Parsed item is not a function."),
+        };
+        assert!(item_fn.fn_signature.package_scope_opt.is_none());
+    }
 }
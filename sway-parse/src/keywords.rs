@@ -73,7 +73,9 @@ keyword_impls! {
     ConfigurableToken,
     TypeToken,
     PtrToken,
-    SliceToken
+    SliceToken,
+    PackageToken,
+    TryToken
 }
 
 fn peek_token<T: Token>(peeker: Peeker<'_>) -> Option<T> {
@@ -151,7 +153,8 @@ token_impls! {
     DoublePipeToken,
     UnderscoreToken,
     HashToken,
-    HashBangToken
+    HashBangToken,
+    QuestionMarkToken
 }
 
 // Keep this in sync with the list in `sway-ast/keywords.rs` defined by define_keyword!
@@ -192,4 +195,5 @@ pub const RESERVED_KEYWORDS: phf::Set<&'static str> = phf::phf_set! {
     "continue",
     "configurable",
     "type",
+    "try",
 };
@@ -47,7 +47,11 @@ impl ParseToEnd for Annotated<Module> {
                                 doc_comment.span.clone(),
                             ),
                             args: Some(Parens::new(
-                                Punctuated::single(AttributeArg { name, value: None }),
+                                Punctuated::single(AttributeArg {
+                                    name,
+                                    value: None,
+                                    args: None,
+                                }),
                                 doc_comment.content_span,
                             )),
                         }),
@@ -107,6 +111,8 @@ mod tests {
                 value: Fn(ItemFn(
                   fn_signature: FnSignature(
                     visibility: None,
+                    package_scope_opt: None,
+                    const_token: None,
                     fn_token: FnToken(
                       span: (42, 44),
                     ),
@@ -43,6 +43,16 @@ impl Parse for Ty {
         if let Some(underscore_token) = parser.take() {
             return Ok(Ty::Infer { underscore_token });
         }
+        if let Some(bang_token) = parser.take() {
+            return Ok(Ty::Never { bang_token });
+        }
+        if let Some(impl_token) = parser.take() {
+            let trait_bounds = Box::new(parser.parse()?);
+            return Ok(Ty::ImplTrait {
+                impl_token,
+                trait_bounds,
+            });
+        }
         if let Some(ptr_token) = parser.take() {
             let ty = SquareBrackets::parse_all_inner(parser, |mut parser| {
                 parser.emit_error(ParseErrorKind::UnexpectedTokenAfterPtrType)
@@ -113,4 +123,24 @@ mod tests {
         );
         assert_matches!(item, Ty::Slice { .. });
     }
+
+    #[test]
+    fn parse_never() {
+        let item = parse::<Ty>(
+            r#"
+            !
+            "#,
+        );
+        assert_matches!(item, Ty::Never { .. });
+    }
+
+    #[test]
+    fn parse_impl_trait() {
+        let item = parse::<Ty>(
+            r#"
+            impl Iterator + Eq
+            "#,
+        );
+        assert_matches!(item, Ty::ImplTrait { trait_bounds, .. } if trait_bounds.suffixes.len() == 1);
+    }
 }
@@ -30,6 +30,8 @@ fn noop_script_file() {
             value: Fn(ItemFn(
               fn_signature: FnSignature(
                 visibility: None,
+                package_scope_opt: None,
+                const_token: None,
                 fn_token: FnToken(
                   span: (28, 30),
                 ),
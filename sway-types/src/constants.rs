@@ -27,6 +27,15 @@ pub const DESTRUCTURE_PREFIX: &str = "__destructure_";
 /// The default prefix for the compiler generated names of match
 pub const MATCH_RETURN_VAR_NAME_PREFIX: &str = "__match_return_var_name_";
 
+/// The default prefix for the compiler generated names of the values of `?` expressions
+pub const TRY_VAR_NAME_PREFIX: &str = "__try_var_name_";
+
+/// The default prefix for the compiler generated names of the values of `let ... else` statements
+pub const LET_ELSE_VAR_NAME_PREFIX: &str = "__let_else_var_name_";
+
+/// The default prefix for the compiler generated type parameters of `impl Trait` arguments
+pub const IMPL_TRAIT_PARAM_NAME_PREFIX: &str = "__impl_trait_param_";
+
 /// The valid attribute strings related to storage and purity.
 pub const STORAGE_PURITY_ATTRIBUTE_NAME: &str = "storage";
 pub const STORAGE_PURITY_READ_NAME: &str = "read";
@@ -49,14 +58,43 @@ pub const TEST_ATTRIBUTE_NAME: &str = "test";
 /// The valid attribute string used for payable functions.
 pub const PAYABLE_ATTRIBUTE_NAME: &str = "payable";
 
+/// The valid attribute string used to override the selector of an ABI method.
+pub const SELECTOR_ATTRIBUTE_NAME: &str = "selector";
+
+/// The valid attribute string used to override the name in the selector of an ABI method.
+pub const ABI_NAME_ATTRIBUTE_NAME: &str = "abi_name";
+
+/// The valid attribute string used for structs encoded in function selectors as their only field.
+pub const ABI_TRANSPARENT_ATTRIBUTE_NAME: &str = "abi_transparent";
+
+/// The valid attribute string used for the fallback method of a contract.
+pub const FALLBACK_ATTRIBUTE_NAME: &str = "fallback";
+
+/// The valid attribute string used to annotate the expected gas cost of a function.
+pub const GAS_HINT_ATTRIBUTE_NAME: &str = "gas_hint";
+
+/// The valid attribute strings related to reentrancy.
+pub const REENTRANCY_ATTRIBUTE_NAME: &str = "reentrancy";
+pub const REENTRANCY_GUARD_NAME: &str = "guard";
+
 /// The valid attribute strings related to allow.
 pub const ALLOW_ATTRIBUTE_NAME: &str = "allow";
 pub const ALLOW_DEAD_CODE_NAME: &str = "dead_code";
+pub const ALLOW_NON_SNAKE_CASE_NAME: &str = "non_snake_case";
+pub const ALLOW_UNUSED_VARIABLES_NAME: &str = "unused_variables";
+
+/// The list of lints which can be allowed.
+pub const VALID_LINT_NAMES: &[&str] = &[
+    ALLOW_DEAD_CODE_NAME,
+    ALLOW_NON_SNAKE_CASE_NAME,
+    ALLOW_UNUSED_VARIABLES_NAME,
+];
 
 /// The valid attribute strings related to conditional compilation.
 pub const CFG_ATTRIBUTE_NAME: &str = "cfg";
 pub const CFG_TARGET_ARG_NAME: &str = "target";
 pub const CFG_PROGRAM_TYPE_ARG_NAME: &str = "program_type";
+pub const CFG_ATTR_ATTRIBUTE_NAME: &str = "cfg_attr";
 
 /// The list of valid attributes.
 pub const VALID_ATTRIBUTE_NAMES: &[&str] = &[
@@ -66,6 +104,13 @@ pub const VALID_ATTRIBUTE_NAMES: &[&str] = &[
     TEST_ATTRIBUTE_NAME,
     INLINE_ATTRIBUTE_NAME,
     PAYABLE_ATTRIBUTE_NAME,
+    SELECTOR_ATTRIBUTE_NAME,
+    ABI_NAME_ATTRIBUTE_NAME,
+    ABI_TRANSPARENT_ATTRIBUTE_NAME,
+    REENTRANCY_ATTRIBUTE_NAME,
+    FALLBACK_ATTRIBUTE_NAME,
+    GAS_HINT_ATTRIBUTE_NAME,
     ALLOW_ATTRIBUTE_NAME,
     CFG_ATTRIBUTE_NAME,
+    CFG_ATTR_ATTRIBUTE_NAME,
 ];
@@ -159,9 +159,17 @@ fn format_fn_sig(
     formatted_code: &mut FormattedCode,
     formatter: &mut Formatter,
 ) -> Result<(), FormatterError> {
-    // `pub `
+    // `pub ` or `pub(package) `
     if let Some(visibility_token) = &fn_sig.visibility {
-        write!(formatted_code, "{} ", visibility_token.span().as_str())?;
+        write!(formatted_code, "{}", visibility_token.span().as_str())?;
+        if let Some(package_scope) = &fn_sig.package_scope_opt {
+            write!(formatted_code, "({})", package_scope.get().span().as_str())?;
+        }
+        write!(formatted_code, " ")?;
+    }
+    // `const `
+    if let Some(const_token) = &fn_sig.const_token {
+        write!(formatted_code, "{} ", const_token.span().as_str())?;
     }
     // `fn ` + name
     write!(formatted_code, "{} ", fn_sig.fn_token.span().as_str())?;
@@ -301,6 +309,15 @@ impl Format for FnArg {
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
+        // `#[doc(..)]`, each on a line of its own
+        for attr in &self.attribute_list {
+            attr.format(formatted_code, formatter)?;
+            write!(
+                formatted_code,
+                "{}",
+                &formatter.shape.indent.to_string(&formatter.config)?,
+            )?;
+        }
         self.pattern.format(formatted_code, formatter)?;
         // `: `
         write!(formatted_code, "{} ", self.colon_token.span().as_str())?;
@@ -312,6 +329,11 @@ impl Format for FnArg {
         )?;
         // `Ty`
         self.ty.format(formatted_code, formatter)?;
+        // ` = Expr`
+        if let Some((eq_token, expr)) = &self.default_opt {
+            write!(formatted_code, " {} ", eq_token.ident().as_str())?;
+            expr.format(formatted_code, formatter)?;
+        }
 
         Ok(())
     }
@@ -332,6 +354,12 @@ impl LeafSpans for FnSignature {
         if let Some(visibility) = &self.visibility {
             collected_spans.push(ByteSpan::from(visibility.span()));
         }
+        if let Some(package_scope) = &self.package_scope_opt {
+            collected_spans.push(ByteSpan::from(package_scope.span()));
+        }
+        if let Some(const_token) = &self.const_token {
+            collected_spans.push(ByteSpan::from(const_token.span()));
+        }
         collected_spans.push(ByteSpan::from(self.fn_token.span()));
         collected_spans.push(ByteSpan::from(self.name.span()));
         if let Some(generics) = &self.generics {
@@ -379,10 +407,14 @@ impl LeafSpans for FnArgs {
 
 impl LeafSpans for FnArg {
     fn leaf_spans(&self) -> Vec<ByteSpan> {
-        let mut collected_spans = Vec::new();
+        let mut collected_spans = self.attribute_list.leaf_spans();
         collected_spans.append(&mut self.pattern.leaf_spans());
         collected_spans.push(ByteSpan::from(self.colon_token.span()));
         collected_spans.push(ByteSpan::from(self.ty.span()));
+        if let Some((eq_token, expr)) = &self.default_opt {
+            collected_spans.push(ByteSpan::from(eq_token.span()));
+            collected_spans.append(&mut expr.leaf_spans());
+        }
         collected_spans
     }
 }
@@ -88,3 +88,33 @@ fmt_test_item!(  fn_nested_if_lets
     };
 }"
 );
+
+fmt_test_item!(  fn_with_default_args
+"fn connect(host: b256, port: u64 = 8080, retries: u8 = 3) -> bool {}",
+            intermediate_whitespace
+"fn connect( host : b256 , port :u64=   8080,retries: u8 =3 )  ->  bool { }"
+);
+
+fmt_test_item!(  fn_generic_param_inline_bounds
+"fn foo<T: Eq + Ord, U>(t: T, u: U) {}",
+            intermediate_whitespace
+"fn foo<T   :Eq+Ord ,U  >(t: T, u: U) {}"
+);
+
+fmt_test_item!(  const_fn
+"pub const fn double(x: u64) -> u64 {\n    x * 2\n}",
+            intermediate_whitespace
+"pub   const   fn double( x : u64 )  ->  u64 {\n  x * 2 }"
+);
+
+fmt_test_item!(  pub_package_fn
+"pub(package) fn double(x: u64) -> u64 {\n    x * 2\n}",
+            intermediate_whitespace
+"pub(package)   fn double( x : u64 )  ->  u64 {\n  x * 2 }"
+);
+
+fmt_test_item!(  unparenthesized_tuple_return_type
+"fn split(x: u64) -> u64, bool {}",
+            intermediate_whitespace
+"fn split( x : u64 )  ->  u64 ,bool { }"
+);
@@ -39,12 +39,18 @@ impl Format for AttributeArg {
     fn format(
         &self,
         formatted_code: &mut FormattedCode,
-        _formatter: &mut Formatter,
+        formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
         write!(formatted_code, "{}", self.name.span().as_str())?;
         if let Some(value) = &self.value {
             write!(formatted_code, " = {}", value.span().as_str())?;
         }
+        // nested args e.g. the `(always)` of `inline(always)`
+        if let Some(args) = &self.args {
+            write!(formatted_code, "{}", Delimiter::Parenthesis.as_open_char())?;
+            args.get().format(formatted_code, formatter)?;
+            write!(formatted_code, "{}", Delimiter::Parenthesis.as_close_char())?;
+        }
 
         Ok(())
     }
@@ -56,6 +62,9 @@ impl LeafSpans for AttributeArg {
         if let Some(value) = &self.value {
             collected_spans.push(ByteSpan::from(value.span()));
         }
+        if let Some(args) = &self.args {
+            collected_spans.append(&mut args.leaf_spans());
+        }
         collected_spans
     }
 }
@@ -190,6 +190,12 @@ impl Format for Expr {
                 block.get().format(formatted_code, formatter)?;
                 CodeBlockContents::close_curly_brace(formatted_code, formatter)?;
             }
+            Self::TryBlock { try_token, block } => {
+                write!(formatted_code, "{}", try_token.span().as_str())?;
+                CodeBlockContents::open_curly_brace(formatted_code, formatter)?;
+                block.get().format(formatted_code, formatter)?;
+                CodeBlockContents::close_curly_brace(formatted_code, formatter)?;
+            }
             Self::FuncApp { func, args } => {
                 formatter.with_shape(
                     formatter.shape.with_default_code_line(),
@@ -211,6 +217,19 @@ impl Format for Expr {
                     },
                 )?;
             }
+            Self::NamedArg {
+                name,
+                colon_token,
+                value,
+            } => {
+                write!(
+                    formatted_code,
+                    "{}{} ",
+                    name.as_str(),
+                    colon_token.span().as_str()
+                )?;
+                value.format(formatted_code, formatter)?;
+            }
             Self::Index { target, arg } => {
                 target.format(formatted_code, formatter)?;
                 Self::open_square_bracket(formatted_code, formatter)?;
@@ -298,6 +317,13 @@ impl Format for Expr {
                     field_span.as_str(),
                 )?;
             }
+            Self::Try {
+                expr,
+                question_mark_token,
+            } => {
+                expr.format(formatted_code, formatter)?;
+                write!(formatted_code, "{}", question_mark_token.span().as_str())?;
+            }
             Self::Ref { ref_token, expr } => {
                 write!(formatted_code, "{} ", ref_token.span().as_str())?;
                 expr.format(formatted_code, formatter)?;
@@ -791,12 +817,29 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.append(&mut block.leaf_spans());
             collected_spans
         }
+        Expr::TryBlock { try_token, block } => {
+            let mut collected_spans = vec![ByteSpan::from(try_token.span())];
+            collected_spans.append(&mut block.leaf_spans());
+            collected_spans
+        }
         Expr::FuncApp { func, args } => {
             let mut collected_spans = Vec::new();
             collected_spans.append(&mut func.leaf_spans());
             collected_spans.append(&mut args.leaf_spans());
             collected_spans
         }
+        Expr::NamedArg {
+            name,
+            colon_token,
+            value,
+        } => {
+            let mut collected_spans = vec![
+                ByteSpan::from(name.span()),
+                ByteSpan::from(colon_token.span()),
+            ];
+            collected_spans.append(&mut value.leaf_spans());
+            collected_spans
+        }
         Expr::Index { target, arg } => {
             let mut collected_spans = Vec::new();
             collected_spans.append(&mut target.leaf_spans());
@@ -843,6 +886,14 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.push(ByteSpan::from(field_span.clone()));
             collected_spans
         }
+        Expr::Try {
+            expr,
+            question_mark_token,
+        } => {
+            let mut collected_spans = expr.leaf_spans();
+            collected_spans.push(ByteSpan::from(question_mark_token.span()));
+            collected_spans
+        }
         Expr::Ref { ref_token, expr } => {
             let mut collected_spans = vec![ByteSpan::from(ref_token.span())];
             collected_spans.append(&mut expr.leaf_spans());
@@ -246,3 +246,17 @@ fmt_test_expr!(  nested_array
      ] ,
   ]"
 );
+
+fmt_test_expr!(  try_operator            "foo()?.bar?",
+            intermediate_whitespace "foo ( ) ? . bar ?"
+);
+
+fmt_test_expr!(  try_block
+"try {
+    foo()?;
+    bar()?
+}",
+            intermediate_whitespace
+"try   {  foo ( ) ? ;
+      bar ( ) ?  }"
+);
@@ -1,9 +1,12 @@
 use crate::{
     formatter::{shape::LineStyle, *},
-    utils::map::byte_span::{ByteSpan, LeafSpans},
+    utils::{
+        map::byte_span::{ByteSpan, LeafSpans},
+        CurlyBrace,
+    },
 };
 use std::fmt::Write;
-use sway_ast::{Statement, StatementLet};
+use sway_ast::{IfExpr, Statement, StatementLet};
 use sway_types::Spanned;
 
 impl Format for Statement {
@@ -67,6 +70,13 @@ impl Format for StatementLet {
         write!(formatted_code, " {} ", self.eq_token.span().as_str())?;
         // expr
         self.expr.format(formatted_code, formatter)?;
+        // ` else { .. }`
+        if let Some((else_token, block)) = &self.else_opt {
+            write!(formatted_code, " {}", else_token.span().as_str())?;
+            IfExpr::open_curly_brace(formatted_code, formatter)?;
+            block.get().format(formatted_code, formatter)?;
+            IfExpr::close_curly_brace(formatted_code, formatter)?;
+        }
         if formatter.shape.code_line.line_style == LineStyle::Inline {
             // `;`
             write!(formatted_code, "{}", self.semicolon_token.span().as_str())?;
@@ -113,6 +123,11 @@ impl LeafSpans for StatementLet {
         collected_spans.push(ByteSpan::from(self.eq_token.span()));
         // Add Expr's ByteSpan
         collected_spans.append(&mut self.expr.leaf_spans());
+        // Add else block's ByteSpan if it exists
+        if let Some((else_token, block)) = &self.else_opt {
+            collected_spans.push(ByteSpan::from(else_token.span()));
+            collected_spans.append(&mut block.leaf_spans());
+        }
         collected_spans.push(ByteSpan::from(self.semicolon_token.span()));
         collected_spans
     }
@@ -6,7 +6,7 @@ use std::fmt::Write;
 use sway_ast::{
     brackets::SquareBrackets,
     expr::Expr,
-    keywords::{PtrToken, SliceToken, StrToken, Token, UnderscoreToken},
+    keywords::{BangToken, PtrToken, SliceToken, StrToken, Token, UnderscoreToken},
     token::Delimiter,
     ty::{Ty, TyArrayDescriptor, TyTupleDescriptor},
 };
@@ -42,6 +42,28 @@ impl Format for Ty {
             Self::Slice { slice_token, ty } => {
                 format_slice(formatted_code, slice_token.clone(), ty.clone())
             }
+            Self::Never { bang_token } => format_never(formatted_code, bang_token),
+            Self::ImplTrait {
+                impl_token,
+                trait_bounds,
+            } => {
+                write!(formatted_code, "{} ", impl_token.span().as_str())?;
+                trait_bounds.format(formatted_code, formatter)
+            }
+            Self::UnparenthesizedTuple {
+                head,
+                comma_token,
+                tail,
+            } => formatter.with_shape(
+                formatter.shape.with_default_code_line(),
+                |formatter| -> Result<(), FormatterError> {
+                    head.format(formatted_code, formatter)?;
+                    write!(formatted_code, "{} ", comma_token.ident().as_str())?;
+                    tail.format(formatted_code, formatter)?;
+
+                    Ok(())
+                },
+            ),
         }
     }
 }
@@ -55,6 +77,15 @@ fn format_infer(
     Ok(())
 }
 
+/// Simply inserts a `!` token to the `formatted_code`.
+fn format_never(
+    formatted_code: &mut FormattedCode,
+    bang_token: &BangToken,
+) -> Result<(), FormatterError> {
+    formatted_code.push_str(bang_token.ident().as_str());
+    Ok(())
+}
+
 impl Format for TyArrayDescriptor {
     fn format(
         &self,
@@ -168,6 +199,25 @@ impl LeafSpans for Ty {
                 collected_spans.append(&mut ty.leaf_spans());
                 collected_spans
             }
+            Ty::Never { bang_token } => vec![ByteSpan::from(bang_token.span())],
+            Ty::ImplTrait {
+                impl_token,
+                trait_bounds,
+            } => {
+                let mut collected_spans = vec![ByteSpan::from(impl_token.span())];
+                collected_spans.append(&mut trait_bounds.leaf_spans());
+                collected_spans
+            }
+            Ty::UnparenthesizedTuple {
+                head,
+                comma_token,
+                tail,
+            } => {
+                let mut collected_spans = head.leaf_spans();
+                collected_spans.push(ByteSpan::from(comma_token.span()));
+                collected_spans.append(&mut tail.leaf_spans());
+                collected_spans
+            }
         }
     }
 }
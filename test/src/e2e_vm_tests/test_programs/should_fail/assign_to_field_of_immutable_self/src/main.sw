@@ -0,0 +1,21 @@
+script;
+
+struct Counter {
+    count: u64,
+}
+
+impl Counter {
+    fn increment(self) {
+        self.count = self.count + 1;
+    }
+
+    fn reset(ref mut self) {
+        self.count = 0;
+    }
+}
+
+fn main() {
+    let mut counter = Counter { count: 0 };
+    counter.increment();
+    counter.reset();
+}
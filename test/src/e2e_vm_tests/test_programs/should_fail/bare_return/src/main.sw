@@ -0,0 +1,9 @@
+library;
+
+pub fn unit() {
+    return;
+}
+
+pub fn missing_value() -> u64 {
+    return;
+}
@@ -0,0 +1,7 @@
+library;
+
+trait Foo {
+    fn foo(self) -> u64;
+}
+
+pub fn f(x: Foo) {}
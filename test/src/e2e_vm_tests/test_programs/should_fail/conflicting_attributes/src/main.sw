@@ -0,0 +1,29 @@
+contract;
+
+abi A {
+    fn f();
+}
+
+#[inline(always)]
+#[inline(never)]
+fn helper() {}
+
+#[test]
+#[payable]
+fn t() {}
+
+#[payable]
+#[test]
+fn u() {}
+
+// Attributes which don't conflict can be combined.
+#[inline(never)]
+#[allow(dead_code)]
+fn unused() {}
+
+impl A for Contract {
+    #[fallback, selector("f")]
+    fn f() {
+        helper();
+    }
+}
@@ -0,0 +1,9 @@
+script;
+
+fn zeroed<const N: u64>() -> [u8; N] {
+    [0u8; N]
+}
+
+fn main() -> u64 {
+    0
+}
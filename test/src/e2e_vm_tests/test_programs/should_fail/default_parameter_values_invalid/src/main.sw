@@ -0,0 +1,21 @@
+library;
+
+pub fn required_after_default(a: u64 = 1, b: u64) {}
+
+pub fn non_constant(a: u64, b: u64 = a) {}
+
+pub fn mismatched_type(_a: u64 = true) {}
+
+pub trait Interface {
+    fn interface_fn(a: u64 = 1);
+}
+
+pub fn optional_second(_a: u64, _b: u64 = 2) {}
+
+pub fn call_with_too_few() {
+    optional_second();
+}
+
+pub fn call_with_too_many() {
+    optional_second(1, 2, 3);
+}
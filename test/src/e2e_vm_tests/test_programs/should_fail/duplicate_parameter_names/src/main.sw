@@ -0,0 +1,25 @@
+library;
+
+pub fn f(x: u64, x: u32) -> u64 {
+    0
+}
+
+pub fn g(a: u64, b: u64, a: bool, b: u64) -> u64 {
+    0
+}
+
+pub struct S {}
+
+impl S {
+    pub fn m(self, self: u64) -> u64 {
+        0
+    }
+}
+
+pub fn h(y: UnknownType, y: u64) -> u64 {
+    0
+}
+
+pub trait T {
+    fn t(z: u64, z: u64);
+}
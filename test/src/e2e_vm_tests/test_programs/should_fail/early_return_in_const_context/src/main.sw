@@ -0,0 +1,39 @@
+script;
+
+struct Celsius {
+    degrees: u64,
+}
+
+impl Celsius {
+    fn capped(self, max: u64) -> u64 {
+        if self.degrees > max {
+            return max;
+        }
+        self.degrees
+    }
+}
+
+// Returns at the end of the body and at the end of its branches are fine.
+fn min(a: u64, b: u64) -> u64 {
+    if a < b {
+        return a;
+    } else {
+        return b;
+    }
+}
+
+fn clamp(value: u64, max: u64) -> u64 {
+    if value > max {
+        return max;
+    }
+    value
+}
+
+const SMALL: u64 = min(3, 5);
+const CLAMPED: u64 = clamp(12, 10);
+const CAPPED: u64 = Celsius { degrees: 80 }.capped(100);
+
+fn main() -> u64 {
+    // Outside of constants, returning early is fine.
+    clamp(SMALL, CLAMPED) + Celsius { degrees: 80 }.capped(CAPPED)
+}
@@ -0,0 +1,5 @@
+library;
+
+pub fn __size_of() {}
+
+pub fn size_of() {}
@@ -0,0 +1,11 @@
+contract;
+
+abi MyContract {
+    fn identity<T>(x: T) -> T;
+}
+
+impl MyContract for Contract {
+    fn identity<T>(x: T) -> T {
+        x
+    }
+}
@@ -0,0 +1,41 @@
+library;
+
+trait Ord {
+    fn lt(self, other: Self) -> bool;
+}
+
+trait Clone {
+    fn clone(self) -> Self;
+}
+
+trait Hash {
+    fn hash(self) -> u64;
+}
+
+impl Ord for u64 {
+    fn lt(self, other: Self) -> bool {
+        __lt(self, other)
+    }
+}
+
+impl Clone for u64 {
+    fn clone(self) -> Self {
+        self
+    }
+}
+
+pub fn lt_clone(x: impl Ord + Clone) -> bool {
+    x.lt(x.clone())
+}
+
+pub fn lt_clone_u64() -> bool {
+    lt_clone(1u64)
+}
+
+pub fn hash(x: impl Ord + Clone) -> u64 {
+    x.hash()
+}
+
+pub fn lt_clone_bool() -> bool {
+    lt_clone(true)
+}
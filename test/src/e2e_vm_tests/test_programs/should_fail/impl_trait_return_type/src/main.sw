@@ -0,0 +1,25 @@
+contract;
+
+trait Double {
+    fn double(self) -> u64;
+}
+
+impl Double for u64 {
+    fn double(self) -> u64 {
+        self * 2
+    }
+}
+
+abi MyContract {
+    fn get() -> u64;
+}
+
+impl MyContract for Contract {
+    fn get() -> impl Double {
+        1
+    }
+}
+
+fn not_double() -> impl Double {
+    true
+}
@@ -0,0 +1,12 @@
+script;
+
+fn pick(flag: bool) -> _ {
+    if flag {
+        return true;
+    }
+    5
+}
+
+fn main() {
+    let _ = pick(true);
+}
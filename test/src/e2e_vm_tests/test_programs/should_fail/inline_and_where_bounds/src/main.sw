@@ -0,0 +1,43 @@
+library;
+
+trait Ord {
+    fn lt(self, other: Self) -> bool;
+}
+
+trait Clone {
+    fn clone(self) -> Self;
+}
+
+impl Ord for u64 {
+    fn lt(self, other: Self) -> bool {
+        __lt(self, other)
+    }
+}
+
+impl Clone for u64 {
+    fn clone(self) -> Self {
+        self
+    }
+}
+
+impl Ord for bool {
+    fn lt(self, other: Self) -> bool {
+        if self { false } else { other }
+    }
+}
+
+// Both the inline and the `where` bound must be satisfied at the call site.
+pub fn lt_clone<T: Ord>(x: T) -> bool
+where
+    T: Clone,
+{
+    x.lt(x.clone())
+}
+
+pub fn lt_clone_u64() -> bool {
+    lt_clone(1u64)
+}
+
+pub fn lt_clone_bool() -> bool {
+    lt_clone(true)
+}
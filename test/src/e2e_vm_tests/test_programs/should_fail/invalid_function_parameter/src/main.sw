@@ -0,0 +1,9 @@
+script;
+
+fn transfer(to: u64, amount: Amount, mut fee: u64) -> u64 {
+    to + fee
+}
+
+fn main() -> u64 {
+    transfer(1, 2, 3)
+}
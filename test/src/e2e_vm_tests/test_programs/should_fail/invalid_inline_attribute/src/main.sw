@@ -0,0 +1,34 @@
+script;
+
+trait Shape {
+    #[inline(always)]
+    fn area(self) -> u64;
+}
+
+#[inline(sometimes)]
+fn unknown_argument() -> u64 {
+    1
+}
+
+#[inline(never)]
+#[inline(later)]
+fn unknown_argument_in_second_attribute() -> u64 {
+    2
+}
+
+#[inline]
+fn missing_argument() -> u64 {
+    3
+}
+
+#[inline(always)]
+fn valid() -> u64 {
+    4
+}
+
+fn main() {
+    let _ = unknown_argument();
+    let _ = unknown_argument_in_second_attribute();
+    let _ = missing_argument();
+    let _ = valid();
+}
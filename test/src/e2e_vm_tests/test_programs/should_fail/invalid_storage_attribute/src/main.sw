@@ -0,0 +1,10 @@
+contract;
+
+abi Empty {
+    #[storage()]
+    fn nothing();
+}
+
+impl Empty for Contract {
+    fn nothing() {}
+}
@@ -0,0 +1,23 @@
+script;
+
+fn falls_through(o: Option<u64>) -> u64 {
+    let Some(x) = o else { 0 };
+    x
+}
+
+fn falls_through_with_other_type(o: Option<u64>) -> u64 {
+    let Some(x) = o else { true };
+    x
+}
+
+fn narrowed(o: Option<u64>) -> bool {
+    let Some(x) = o else { return false; };
+    let y: bool = x;
+    y
+}
+
+fn main() {
+    let _ = falls_through(Some(1));
+    let _ = falls_through_with_other_type(Some(1));
+    let _ = narrowed(Some(1));
+}
@@ -0,0 +1,9 @@
+library;
+
+pub fn f(x: u64) -> u64 {
+    match x {
+        0 => 1,
+        1 => true,
+        _ => 2,
+    }
+}
@@ -0,0 +1,9 @@
+script;
+
+fn double(x: u64) -> u64 {
+    x * 2
+}
+
+fn main() {
+    double(21)
+}
@@ -0,0 +1,14 @@
+script;
+
+fn transfer(to: u64, amount: u64, fee: u64 = 1) -> u64 {
+    to + amount + fee
+}
+
+fn main() -> u64 {
+    let a = transfer(to: 1, value: 2);
+    let b = transfer(to: 1, amount: 2, to: 3);
+    let c = transfer(1, 2, to: 3);
+    let d = transfer(amount: 2, 1);
+    let e = transfer(fee: 2);
+    a + b + c + d + e
+}
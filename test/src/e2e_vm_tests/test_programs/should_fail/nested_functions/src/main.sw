@@ -7,13 +7,38 @@ struct Data {
 impl Data {
     fn the_value(self) -> u64 {
         fn double(n: u64) -> u64 {
-            n // + n
+            self.value
         }
 
         double(self.value)
     }
 }
 
+fn with_param(outer_param: u64) -> u64 {
+    fn inner() -> u64 {
+        outer_param
+    }
+    inner()
+}
+
+fn generic<T>(x: T) -> T {
+    fn inner(y: T) -> T {
+        y
+    }
+    x
+}
+
 fn main() {
-    fn bla() { }
+    let outer_local = 42;
+    fn reads_outer() -> u64 {
+        outer_local
+    }
+
+    let mut counter = 0;
+    fn writes_outer() {
+        counter = 1;
+    }
+
+    called_too_early();
+    fn called_too_early() {}
 }
@@ -9,8 +9,7 @@ fn foo() {
     baz();
 }
 
-// Although annotated, with no args is pure.
-#[storage()]
+// Not annotated, so pure.
 fn bar() {
     let z = baz();
 }
@@ -0,0 +1,29 @@
+script;
+
+fn fail() -> ! {
+    __revert(42)
+}
+
+fn fail_either_way(b: bool) -> ! {
+    if b {
+        fail()
+    } else {
+        __revert(0)
+    }
+}
+
+// A value of `!` is produced out of thin air here.
+fn from_register() -> ! {
+    asm(r: 0) { r: ! }
+}
+
+fn not_failing() -> ! {
+    return 5;
+}
+
+fn main() -> u64 {
+    let _ = fail_either_way(true);
+    let _ = from_register();
+    let _ = not_failing();
+    0
+}
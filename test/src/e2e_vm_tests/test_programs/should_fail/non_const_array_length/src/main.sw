@@ -0,0 +1,11 @@
+script;
+
+fn size() -> u64 {
+    3
+}
+
+fn first(values: [u64; size]) -> u64 {
+    values[0]
+}
+
+fn main() {}
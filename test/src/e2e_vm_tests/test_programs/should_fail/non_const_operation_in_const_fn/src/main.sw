@@ -0,0 +1,17 @@
+script;
+
+const fn double(x: u64) -> u64 {
+    __add(x, x)
+}
+
+configurable {
+    X: u64 = double(21),
+}
+
+const fn fail() -> u64 {
+    __revert(0)
+}
+
+fn main() -> u64 {
+    __add(X, fail())
+}
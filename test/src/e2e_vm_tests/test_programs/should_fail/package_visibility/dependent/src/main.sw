@@ -0,0 +1,9 @@
+script;
+
+use package_visibility_lib::Counter;
+
+fn main() -> u64 {
+    let counter = Counter { value: 3 };
+    let _ = counter.get();
+    package_visibility_lib::double(counter.value)
+}
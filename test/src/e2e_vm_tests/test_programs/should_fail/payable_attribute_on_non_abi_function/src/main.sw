@@ -0,0 +1,35 @@
+contract;
+
+abi Wallet {
+    #[payable]
+    fn receive_funds();
+
+    fn send_funds(amount: u64);
+}
+
+trait Deposit {
+    #[payable]
+    fn deposit();
+}
+
+struct Vault {}
+
+impl Vault {
+    #[payable]
+    fn store(self) {}
+}
+
+#[payable]
+fn withdraw() {}
+
+impl Wallet for Contract {
+    #[payable]
+    fn receive_funds() {}
+
+    fn send_funds(amount: u64) {
+        let wallet = abi(Wallet, 0x0000000000000000000000000000000000000000000000000000000000000001);
+        wallet.receive_funds {
+            coins: amount,
+        }();
+    }
+}
@@ -0,0 +1,42 @@
+library;
+
+trait Pick {
+    fn id(self) -> u64;
+} {
+    fn pick(self, other: Self) -> Self {
+        other
+    }
+}
+
+pub struct A {
+    x: u64,
+}
+
+impl Pick for A {
+    fn id(self) -> u64 {
+        self.x
+    }
+}
+
+pub struct B {
+    y: u64,
+}
+
+impl Pick for B {
+    fn id(self) -> u64 {
+        self.y
+    }
+}
+
+// `Self` stands for the implementing type at each use of the provided method.
+pub fn pick_a(a: A, other: A) -> A {
+    a.pick(other)
+}
+
+pub fn pick_b(b: B, other: B) -> B {
+    b.pick(other)
+}
+
+pub fn pick_mixed(a: A, b: B) -> A {
+    a.pick(b)
+}
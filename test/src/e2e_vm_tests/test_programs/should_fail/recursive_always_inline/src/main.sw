@@ -0,0 +1,26 @@
+script;
+
+struct Counter {}
+
+impl Counter {
+    #[inline(always)]
+    fn count_down(self, n: u64) -> u64 {
+        if n == 0 {
+            return 0;
+        }
+        self.count_down(n - 1)
+    }
+
+    #[inline(never)]
+    fn count_up(self, n: u64) -> u64 {
+        if n >= 10 {
+            return n;
+        }
+        self.count_up(n + 1)
+    }
+}
+
+fn main() -> u64 {
+    let counter = Counter {};
+    counter.count_down(3) + counter.count_up(3)
+}
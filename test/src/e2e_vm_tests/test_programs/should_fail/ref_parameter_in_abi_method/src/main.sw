@@ -0,0 +1,21 @@
+contract;
+
+fn bump(ref mut value: u64) {
+    value += 1;
+}
+
+abi Counter {
+    fn count() -> u64;
+} {
+    fn double(ref mut x: u64) {
+        x *= 2;
+    }
+}
+
+impl Counter for Contract {
+    fn count() -> u64 {
+        let mut value = 1;
+        bump(value);
+        value
+    }
+}
@@ -0,0 +1,22 @@
+script;
+
+struct Wrapper {
+    inner: u64,
+}
+
+fn multi_branch(a: bool, b: bool) -> u64 {
+    if a {
+        return true;
+    }
+    if b {
+        return Wrapper { inner: 1 };
+    }
+    if a {
+        return 17u8;
+    }
+    return 42;
+}
+
+fn main() {
+    let _ = multi_branch(true, false);
+}
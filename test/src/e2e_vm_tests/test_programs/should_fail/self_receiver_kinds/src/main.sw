@@ -0,0 +1,17 @@
+script;
+
+struct Counter {
+    value: u64,
+}
+
+trait Reset {
+    fn reset(mut self);
+}
+
+impl Counter {
+    fn increment(mut self) {
+        self.value = self.value + 1;
+    }
+}
+
+fn main() {}
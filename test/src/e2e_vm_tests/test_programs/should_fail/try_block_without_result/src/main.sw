@@ -0,0 +1,6 @@
+library;
+
+pub fn f(x: u64) -> u64 {
+    let _r = try { x };
+    0
+}
@@ -0,0 +1,6 @@
+library;
+
+pub enum Result {
+    Ok: u64,
+    Err: bool,
+}
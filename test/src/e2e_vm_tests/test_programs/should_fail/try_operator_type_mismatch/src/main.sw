@@ -0,0 +1,60 @@
+library;
+
+mod custom;
+
+use custom::Result as CustomResult;
+
+fn parse(x: u64) -> Result<u64, bool> {
+    Result::Ok(x)
+}
+
+fn find(x: u64) -> Option<u64> {
+    Option::Some(x)
+}
+
+pub fn double(x: u64) -> Result<u64, bool> {
+    Result::Ok(__mul(parse(x)?, 2))
+}
+
+pub fn triple(x: u64) -> Option<u64> {
+    let y = find(x)?;
+    Option::Some(__mul(y, 3))
+}
+
+pub fn different_error(x: u64) -> Result<u64, u64> {
+    Result::Ok(parse(x)?)
+}
+
+pub fn different_enum(x: u64) -> Result<u64, bool> {
+    Result::Ok(find(x)?)
+}
+
+pub fn not_a_try_type(x: u64) -> u64 {
+    parse(x)?
+}
+
+// Only the `Result` and `Option` of `std` work with the `?` operator.
+pub fn custom_result(x: u64) -> CustomResult {
+    CustomResult::Ok(parse(x)?)
+}
+
+// The `?` operators short-circuit to the block, so the function needn't return a `Result`.
+pub fn in_try_block(x: u64) -> u64 {
+    let r: Result<u64, bool> = try { __add(parse(x)?, parse(x)?) };
+    let _inferred = try { parse(x)? };
+    let _unit: Result<(), bool> = try { parse(x)?; };
+    match r {
+        Result::Ok(y) => y,
+        Result::Err(_) => 0,
+    }
+}
+
+pub fn different_error_in_try_block(x: u64) -> u64 {
+    let _r: Result<u64, u64> = try { parse(x)? };
+    0
+}
+
+pub fn different_enum_in_try_block(x: u64) -> u64 {
+    let _r = try { find(x)? };
+    0
+}
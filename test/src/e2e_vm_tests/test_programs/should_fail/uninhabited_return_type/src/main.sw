@@ -0,0 +1,42 @@
+script;
+
+enum Void {}
+
+// The body always reverts, so this is fine.
+fn fail() -> Void {
+    __revert(42)
+}
+
+// Calling a function which never returns diverges too.
+fn fail_again() -> Void {
+    fail()
+}
+
+fn fail_either_way(b: bool) -> Void {
+    if b {
+        __revert(0)
+    } else {
+        fail()
+    }
+}
+
+// A value of `Void` is produced out of thin air here.
+fn from_register() -> Void {
+    asm(r: 0) { r: Void }
+}
+
+fn fail_sometimes(b: bool) -> Void {
+    if b {
+        fail()
+    } else {
+        asm(r: 1) { r: Void }
+    }
+}
+
+fn main() -> u64 {
+    let _ = fail_again();
+    let _ = fail_either_way(true);
+    let _ = fail_sometimes(true);
+    let _ = from_register();
+    0
+}
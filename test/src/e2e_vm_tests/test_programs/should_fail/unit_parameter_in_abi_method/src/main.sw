@@ -0,0 +1,13 @@
+contract;
+
+abi A {
+    fn f(x: u64, y: ());
+}
+
+impl A for Contract {
+    fn f(x: u64, y: ()) {}
+}
+
+// Unit parameters are only rejected in contract calls.
+#[allow(dead_code, unused_variables)]
+fn g(x: u64, y: ()) {}
@@ -0,0 +1,20 @@
+script;
+
+trait Double {
+    fn double(self) -> u64;
+}
+
+impl Double for u64 {
+    fn double(self) -> u64 {
+        self * 2
+    }
+}
+
+fn one() -> impl Doubel {
+    1
+}
+
+fn main() -> u64 {
+    one();
+    0
+}
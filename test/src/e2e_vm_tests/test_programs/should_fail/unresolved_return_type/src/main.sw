@@ -0,0 +1,19 @@
+script;
+
+struct Balance {
+    amount: u64,
+}
+
+// Only the unknown return type is reported, not the values returned from the body.
+fn balance(b: bool) -> Amount {
+    if b {
+        return Balance { amount: 1 };
+    }
+    let amount = 2;
+    Balance { amount }
+}
+
+fn main() -> u64 {
+    let _ = balance(true);
+    0
+}
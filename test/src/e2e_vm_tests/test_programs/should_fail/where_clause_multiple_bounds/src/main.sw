@@ -0,0 +1,45 @@
+script;
+
+trait Double {
+    fn double(self) -> Self;
+}
+
+trait Halve {
+    fn halve(self) -> Self;
+}
+
+impl Double for u64 {
+    fn double(self) -> Self {
+        self * 2
+    }
+}
+
+impl Double for u32 {
+    fn double(self) -> Self {
+        self * 2
+    }
+}
+
+impl Halve for u32 {
+    fn halve(self) -> Self {
+        self / 2
+    }
+}
+
+fn round_trip<T>(x: T) -> T where T: Double + Halve {
+    x.double().halve()
+}
+
+fn unsupported<T>(x: T) -> T where T: core::ops::Add + core::ops::Subtract {
+    x
+}
+
+fn main() -> bool {
+    // Both bounds are satisfied.
+    let _ = round_trip(3u32);
+    // Only `Double` is implemented for `u64`.
+    let _ = round_trip(3u64);
+    // Neither bound is implemented for `bool`.
+    let _ = round_trip(true);
+    true
+}
@@ -6,11 +6,29 @@ fn f1() {}
 #[allow]
 fn f2() {}
 
-#[allow(dead_code, dead_code)]
-fn f3() {}
+#[allow(dead_code, unused_variables)]
+fn f3(x: u64) {}
+
+#[allow(non_snake_case)]
+fn F4() {}
+
+#[allow(bar)]
+struct S {}
+
+#[allow(baz)]
+enum E {
+    A: (),
+}
+
+#[allow(qux)]
+const C: u64 = 0;
 
 fn main() {
     f1();
     f2();
-    f3();
+    f3(0);
+    F4();
+    let _ = S {};
+    let _ = E::A;
+    let _ = C;
 }
@@ -0,0 +1,16 @@
+script;
+
+const SIZE: u64 = 3;
+const DOUBLE_SIZE: u64 = 2 * SIZE;
+
+fn sum(values: [u64; SIZE]) -> u64 {
+    values[0] + values[1] + values[2]
+}
+
+fn last(values: [u64; DOUBLE_SIZE]) -> u64 {
+    values[5]
+}
+
+fn main() -> u64 {
+    sum([1, 2, 3]) + last([0, 0, 0, 0, 0, 4])
+}
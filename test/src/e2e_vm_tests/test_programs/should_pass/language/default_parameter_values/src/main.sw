@@ -0,0 +1,43 @@
+script;
+
+const DEFAULT_RETRIES: u64 = 3;
+
+struct Config {
+    port: u64,
+    secure: bool,
+}
+
+fn connect(port: u64, retries: u64 = DEFAULT_RETRIES, timeout: u64 = 10 * 3) -> u64 {
+    port + retries + timeout
+}
+
+fn configure(config: Config = Config { port: 8080, secure: true }) -> u64 {
+    if config.secure { config.port } else { 0 }
+}
+
+struct Counter {
+    value: u64,
+}
+
+impl Counter {
+    fn add(self, amount: u64 = 1) -> u64 {
+        self.value + amount
+    }
+}
+
+fn main() -> u64 {
+    let a = connect(1);
+    assert(a == 34);
+    let b = connect(1, 2);
+    assert(b == 33);
+    let c = connect(1, 2, 3);
+    assert(c == 6);
+    let d = configure();
+    assert(d == 8080);
+    let counter = Counter { value: 10 };
+    let e = counter.add();
+    assert(e == 11);
+    let f = counter.add(5);
+    assert(f == 15);
+    a + b + c + d + e + f
+}
@@ -0,0 +1,11 @@
+library;
+
+pub trait Perimeter {
+    fn perimeter(self) -> u64;
+}
+
+impl Perimeter for u64 {
+    fn perimeter(self) -> u64 {
+        self * 4
+    }
+}
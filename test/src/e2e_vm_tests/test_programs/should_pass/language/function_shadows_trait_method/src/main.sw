@@ -0,0 +1,27 @@
+script;
+
+mod shapes;
+mod lengths;
+
+use shapes::{Area, Square};
+use lengths::*;
+
+// Both of these are named like a method of the explicitly imported `Area`.
+fn area(width: u64, height: u64) -> u64 {
+    width * height
+}
+
+fn double_area(width: u64, height: u64) -> u64 {
+    area(width, height) * 2
+}
+
+// `Perimeter` is only glob imported, so this is not reported.
+fn perimeter(width: u64, height: u64) -> u64 {
+    (width + height) * 2
+}
+
+fn main() -> u64 {
+    let square = Square::new(3);
+    // The free functions and the methods don't interfere with each other.
+    square.area() + square.double_area() + area(2, 3) + double_area(1, 2) + perimeter(1, 2) + 2.perimeter()
+}
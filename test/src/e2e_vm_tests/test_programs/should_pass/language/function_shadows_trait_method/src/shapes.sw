@@ -0,0 +1,25 @@
+library;
+
+pub trait Area {
+    fn area(self) -> u64;
+} {
+    fn double_area(self) -> u64 {
+        self.area() * 2
+    }
+}
+
+pub struct Square {
+    side: u64,
+}
+
+impl Square {
+    pub fn new(side: u64) -> Self {
+        Self { side }
+    }
+}
+
+impl Area for Square {
+    fn area(self) -> u64 {
+        self.side * self.side
+    }
+}
@@ -0,0 +1,70 @@
+script;
+
+struct Counter {
+    count: u64,
+}
+
+impl Counter {
+    fn new() -> Self {
+        Counter { count: 0 }
+    }
+
+    fn with(x: u64) -> Self {
+        Self { count: x }
+    }
+
+    fn incremented(self) -> Self {
+        Self {
+            count: self.count + 1,
+        }
+    }
+}
+
+enum Level {
+    Low: (),
+    High: u64,
+}
+
+impl Level {
+    fn new() -> Self {
+        Level::Low
+    }
+
+    fn with(x: u64) -> Self {
+        Self::High(x)
+    }
+
+    fn value(self) -> u64 {
+        match self {
+            Level::Low => 0,
+            Level::High(x) => x,
+        }
+    }
+}
+
+struct Wrapper<T> {
+    inner: T,
+}
+
+impl<T> Wrapper<T> {
+    fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl Wrapper<u64> {
+    fn with(x: u64) -> Self {
+        Wrapper { inner: x * 2 }
+    }
+}
+
+fn main() -> u64 {
+    let counter = Counter::new();
+    let other = Counter::with(10);
+    let low = Level::new();
+    let high = Level::with(20);
+    let wrapper = Wrapper::new(3);
+    let doubled = Wrapper::<u64>::with(4);
+
+    counter.incremented().count + other.count + low.value() + high.value() + wrapper.inner + doubled.inner
+}
@@ -0,0 +1,10 @@
+script;
+
+mod shapes;
+
+use shapes::{Area, square};
+
+fn main() -> u64 {
+    let shape = square(3);
+    shape.area()
+}
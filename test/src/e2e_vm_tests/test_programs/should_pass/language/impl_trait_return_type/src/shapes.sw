@@ -0,0 +1,19 @@
+library;
+
+pub trait Area {
+    fn area(self) -> u64;
+}
+
+struct Square {
+    side: u64,
+}
+
+impl Area for Square {
+    fn area(self) -> u64 {
+        self.side * self.side
+    }
+}
+
+pub fn square(side: u64) -> impl Area {
+    Square { side }
+}
@@ -0,0 +1,13 @@
+script;
+
+fn increment(x: u64) -> _ {
+    x + 1
+}
+
+fn first<T>(pair: (T, T)) -> _ {
+    pair.0
+}
+
+fn main() -> u64 {
+    increment(first((40, 0))) + 1
+}
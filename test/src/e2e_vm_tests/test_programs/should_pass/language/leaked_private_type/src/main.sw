@@ -0,0 +1,33 @@
+library;
+
+struct Secret {
+    x: u64,
+}
+
+pub struct Wrapper<T> {
+    inner: T,
+}
+
+pub fn unwrap_secret(s: Secret) -> u64 {
+    s.x
+}
+
+// The type arguments aren't checked, e.g. for a `Result` of a private error.
+pub fn unwrap_wrapped_secret(w: Wrapper<Secret>) -> u64 {
+    w.inner.x
+}
+
+// Neither private functions nor the methods of a private type expose anything.
+fn reveal(s: Secret) -> u64 {
+    s.get()
+}
+
+impl Secret {
+    pub fn get(self) -> u64 {
+        self.x
+    }
+}
+
+pub fn reveal_wrapped(w: Wrapper<u64>) -> u64 {
+    reveal(Secret { x: w.inner })
+}
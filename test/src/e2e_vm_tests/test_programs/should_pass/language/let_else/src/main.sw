@@ -0,0 +1,29 @@
+script;
+
+fn increment(o: Option<u64>) -> Option<u64> {
+    // The code after the guard sees `x` as a `u64`.
+    let Some(x) = o else {
+        return None;
+    };
+    let y: u64 = x + 1;
+    Some(y)
+}
+
+fn unwrap_or_revert(r: Result<u64, u64>) -> u64 {
+    let Ok(x) = r else {
+        revert(0)
+    };
+    x
+}
+
+fn main() -> u64 {
+    let a = match increment(Some(41)) {
+        Some(value) => value,
+        None => 0,
+    };
+    let b = match increment(None) {
+        Some(_) => 0,
+        None => 1,
+    };
+    a + b + unwrap_or_revert(Ok(0))
+}
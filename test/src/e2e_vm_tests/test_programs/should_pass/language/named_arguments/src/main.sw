@@ -0,0 +1,19 @@
+script;
+
+fn transfer(to: u64, amount: u64, fee: u64 = 1) -> u64 {
+    to * 100 + amount * 10 + fee
+}
+
+fn connect(port: u64, retries: u64 = 3, timeout: u64 = 30) -> u64 {
+    port + retries + timeout
+}
+
+fn main() -> u64 {
+    let a = transfer(amount: 2, to: 3);
+    assert(a == 321);
+    let b = transfer(3, fee: 5, amount: 2);
+    assert(b == 325);
+    let c = connect(1, timeout: 5);
+    assert(c == 9);
+    a + b + c
+}
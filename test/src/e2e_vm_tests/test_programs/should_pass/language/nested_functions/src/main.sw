@@ -0,0 +1,55 @@
+script;
+
+const OFFSET: u64 = 100;
+
+fn top_level(x: u64) -> u64 {
+    x * 2
+}
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+impl Point {
+    fn sum(self) -> u64 {
+        fn add(a: u64, b: u64) -> u64 {
+            a + b
+        }
+        add(self.x, self.y)
+    }
+}
+
+fn generic<T>(value: T) -> T {
+    fn identity(n: u64) -> u64 {
+        n
+    }
+    let _ = identity(1);
+    value
+}
+
+fn main() -> u64 {
+    let x = 5;
+
+    // A nested function can call top-level functions and use top-level constants.
+    fn helper(n: u64) -> u64 {
+        top_level(n) + OFFSET
+    }
+
+    // Nested functions can themselves contain nested functions and call earlier ones.
+    fn outer_helper(n: u64) -> u64 {
+        fn inner(n: u64) -> u64 {
+            n + 1
+        }
+        inner(n) + helper(n)
+    }
+
+    // A `let` binding inside a nested function may reuse a name of the enclosing function.
+    fn shadowing() -> u64 {
+        let x = 1000;
+        x
+    }
+
+    let p = Point { x: 3, y: 4 };
+    helper(x) + outer_helper(x) + shadowing() + p.sum() + generic(10)
+}
@@ -0,0 +1,28 @@
+script;
+
+fn fail(code: u64) -> ! {
+    __revert(code)
+}
+
+// Calling a function which never returns diverges as well.
+fn fail_again() -> ! {
+    fail(7)
+}
+
+fn checked_sub(a: u64, b: u64) -> u64 {
+    if a < b {
+        fail(1)
+    } else {
+        a - b
+    }
+}
+
+fn main() -> u64 {
+    let x: u64 = checked_sub(10, 3);
+    if x != 7 {
+        let _: b256 = fail_again();
+    }
+    let y: u64 = if x == 7 { 35 } else { fail(2) };
+    let z: u64 = fail(x + y);
+    z
+}
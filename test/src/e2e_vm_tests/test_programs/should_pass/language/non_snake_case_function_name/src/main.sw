@@ -0,0 +1,7 @@
+library;
+
+pub fn fooBar() {}
+
+pub fn BazQux() {}
+
+pub fn snake_case() {}
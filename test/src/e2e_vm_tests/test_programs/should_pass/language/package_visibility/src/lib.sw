@@ -0,0 +1,15 @@
+library;
+
+pub struct Counter {
+    value: u64,
+}
+
+impl Counter {
+    pub(package) fn get(self) -> u64 {
+        self.value
+    }
+}
+
+pub(package) fn double(x: u64) -> u64 {
+    x * 2
+}
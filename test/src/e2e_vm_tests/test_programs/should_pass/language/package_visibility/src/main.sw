@@ -0,0 +1,10 @@
+script;
+
+mod lib;
+
+use lib::{Counter, double};
+
+fn main() -> u64 {
+    let counter = Counter { value: 3 };
+    double(counter.get()) + lib::double(1)
+}
@@ -0,0 +1,20 @@
+library;
+
+pub fn id(x: u64) -> u64 {
+    x
+}
+
+pub fn early(x: u64, b: bool) -> u64 {
+    if b {
+        return x;
+    }
+    x
+}
+
+// A parameter which is used before being returned is not merely passed through.
+pub fn check(x: bool) -> bool {
+    if x {
+        return x;
+    }
+    x
+}
@@ -0,0 +1,9 @@
+script;
+
+fn scale<T>(_value: T, T: u64) -> u64 {
+    T * 2
+}
+
+fn main() -> u64 {
+    scale(true, 21)
+}
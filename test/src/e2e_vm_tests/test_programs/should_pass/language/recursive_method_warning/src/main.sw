@@ -0,0 +1,34 @@
+script;
+
+trait Countdown {
+    fn countdown(self, n: u64) -> u64;
+}
+
+struct Counter {}
+struct Other {}
+
+impl Countdown for Other {
+    fn countdown(self, n: u64) -> u64 {
+        n
+    }
+}
+
+impl Countdown for Counter {
+    fn countdown(self, n: u64) -> u64 {
+        if __eq(n, 0) {
+            return 0;
+        }
+        if __eq(n, 1) {
+            return self.countdown(__sub(n, 1));
+        }
+        let other = Other {};
+        // Not recursive, this is a different implementation.
+        let _ = other.countdown(n);
+        Self::countdown(self, __sub(n, 1))
+    }
+}
+
+fn main() {
+    let c = Counter {};
+    let _ = c.countdown(3);
+}
@@ -0,0 +1,24 @@
+library;
+
+pub trait Ord {
+    fn lt(self, other: Self) -> bool;
+}
+
+pub trait Clone {
+    fn clone(self) -> Self;
+}
+
+// The inline and the `where` bounds of a type parameter are merged.
+pub fn lt_clone<T: Ord>(x: T) -> bool
+where
+    T: Clone,
+{
+    x.lt(x.clone())
+}
+
+pub fn lt_self<T: Ord>(x: T) -> bool
+where
+    T: Ord,
+{
+    x.lt(x)
+}
@@ -0,0 +1,5 @@
+library;
+
+pub fn pass_through(key: StorageKey<u64>) -> StorageKey<u64> {
+    return key;
+}
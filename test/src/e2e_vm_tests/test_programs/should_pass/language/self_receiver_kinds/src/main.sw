@@ -0,0 +1,45 @@
+script;
+
+struct Counter {
+    value: u64,
+}
+
+trait Step {
+    fn step(ref mut self);
+    fn current(self) -> u64;
+}
+
+impl Counter {
+    // No receiver.
+    fn new() -> Self {
+        Counter { value: 0 }
+    }
+
+    // By value.
+    fn get(self) -> u64 {
+        self.value
+    }
+
+    // By mutable reference.
+    fn add(ref mut self, amount: u64) {
+        self.value = self.value + amount;
+    }
+}
+
+impl Step for Counter {
+    fn step(ref mut self) {
+        self.add(1);
+    }
+
+    fn current(self) -> u64 {
+        self.get()
+    }
+}
+
+fn main() -> u64 {
+    let mut counter = Counter::new();
+    counter.add(40);
+    counter.step();
+    counter.step();
+    counter.current()
+}
@@ -0,0 +1,13 @@
+script;
+
+fn my_func() -> u64 {
+    1
+}
+
+fn myFunc() -> u64 {
+    2
+}
+
+fn main() -> u64 {
+    my_func() + myFunc()
+}
@@ -0,0 +1,26 @@
+contract;
+
+storage {
+    counter: u64 = 0,
+}
+
+abi Counter {
+    #[storage(read, read)]
+    fn get() -> u64;
+
+    #[storage(read, write)]
+    #[storage(write)]
+    fn increment();
+}
+
+impl Counter for Contract {
+    #[storage(read)]
+    fn get() -> u64 {
+        storage.counter.read()
+    }
+
+    #[storage(read, write)]
+    fn increment() {
+        storage.counter.write(storage.counter.read() + 1);
+    }
+}
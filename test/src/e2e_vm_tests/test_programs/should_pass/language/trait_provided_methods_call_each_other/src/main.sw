@@ -0,0 +1,32 @@
+script;
+
+trait Scaled {
+    fn value(self) -> u64;
+} {
+    // Calls a provided method declared after it.
+    fn described(self) -> u64 {
+        self.doubled() + 1
+    }
+
+    fn doubled(self) -> u64 {
+        self.value() * 2
+    }
+
+    // Calls a provided method declared before it.
+    fn quadrupled(self) -> u64 {
+        self.doubled() * 2
+    }
+}
+
+struct Five {}
+
+impl Scaled for Five {
+    fn value(self) -> u64 {
+        5
+    }
+}
+
+fn main() -> u64 {
+    let five = Five {};
+    five.described() + five.quadrupled()
+}
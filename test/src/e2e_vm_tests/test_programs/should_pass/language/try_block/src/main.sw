@@ -0,0 +1,26 @@
+script;
+
+fn parse(x: u64) -> Result<u64, u64> {
+    if x < 10 { Ok(x) } else { Err(x) }
+}
+
+fn main() -> u64 {
+    // Every `?` succeeds, so the block evaluates to `Ok` of its value.
+    let succeeded: Result<u64, u64> = try {
+        parse(1)? + parse(2)?
+    };
+    // The second `?` short-circuits to the end of the block, skipping the third one.
+    let short_circuited: Result<u64, u64> = try {
+        parse(3)? + parse(40)? + parse(50)?
+    };
+
+    let succeeded = match succeeded {
+        Ok(value) => value,
+        Err(_) => 0,
+    };
+    let short_circuited = match short_circuited {
+        Ok(_) => 0,
+        Err(error) => error,
+    };
+    succeeded + short_circuited
+}
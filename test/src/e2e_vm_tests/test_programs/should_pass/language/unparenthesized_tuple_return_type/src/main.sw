@@ -0,0 +1,44 @@
+script;
+
+// The same as `-> (u64, bool)`.
+fn div_rem(a: u64, b: u64) -> u64, u64 {
+    (a / b, a % b)
+}
+
+fn checked_div(a: u64, b: u64) -> u64, bool {
+    if b == 0 {
+        return (0, false);
+    }
+    (a / b, true)
+}
+
+trait Split {
+    fn split(self) -> u64, u64;
+}
+
+impl Split for u64 {
+    fn split(self) -> u64, u64 {
+        (self >> 32, self & 0xffffffff)
+    }
+}
+
+// A single type is still not a tuple.
+fn double(a: u64) -> u64 {
+    a * 2
+}
+
+fn main() -> u64 {
+    let (q, r) = div_rem(17, 5);
+    assert(q == 3 && r == 2);
+
+    let pair: (u64, bool) = checked_div(10, 0);
+    assert(!pair.1);
+    let (q, ok) = checked_div(10, 2);
+    assert(ok && q == 5);
+
+    let x: u64 = 0x0000000100000002;
+    let (hi, lo) = x.split();
+    assert(hi == 1 && lo == 2);
+
+    double(q + r)
+}
@@ -0,0 +1,46 @@
+library;
+
+pub fn after_return() -> u64 {
+    return 1;
+    1002
+}
+
+pub fn both_branches_return(b: bool) -> u64 {
+    if b {
+        return 1;
+    } else {
+        return 2;
+    }
+    1003
+}
+
+pub fn one_branch_returns(b: bool) -> u64 {
+    if b {
+        return 1;
+    }
+    1004
+}
+
+pub fn all_arms_return(x: u64) -> u64 {
+    match x {
+        0 => return 1,
+        _ => return 2,
+    }
+    1005
+}
+
+pub fn in_loop_body(ref mut i: u64) -> u64 {
+    while i < 10 {
+        return i;
+        i = 1006;
+    }
+    1007
+}
+
+pub fn unreachable_block() -> u64 {
+    return 1;
+    {
+        return 2;
+        1008
+    }
+}
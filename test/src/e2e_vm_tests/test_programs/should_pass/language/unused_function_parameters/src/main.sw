@@ -0,0 +1,36 @@
+library;
+
+pub fn unused_param(a: u64, b: u64) -> u64 {
+    a
+}
+
+pub fn silenced(_c: u64) {}
+
+pub fn generic<T>(t: T, d: u64) -> T {
+    t
+}
+
+pub trait Tr {
+    fn tr_method(self, e: u64) -> u64;
+} {
+    fn tr_default(self, f: u64) -> u64 {
+        self.tr_method(1)
+    }
+}
+
+pub struct S {}
+
+impl Tr for S {
+    fn tr_method(self, g: u64) -> u64 {
+        42
+    }
+}
+
+pub fn reassigned(ref mut h: u64) {
+    h = 1;
+}
+
+pub fn shadowed(i: u64) -> u64 {
+    let i = 1;
+    i
+}
@@ -0,0 +1,9 @@
+script;
+
+fn first<T, U>(value: T) -> T {
+    value
+}
+
+fn main() -> u64 {
+    first::<u64, bool>(42)
+}
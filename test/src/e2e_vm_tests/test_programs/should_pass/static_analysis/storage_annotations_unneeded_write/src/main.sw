@@ -0,0 +1,34 @@
+contract;
+
+storage {
+    x: u64 = 0,
+}
+
+abi MyContract {
+    #[storage(read, write)]
+    fn increment();
+}
+
+impl MyContract for Contract {
+    #[storage(read, write)]
+    fn increment() {
+        let counter = Counter { offset: 1 };
+        storage.x.write(read_x() + counter.read_x());
+    }
+}
+
+struct Counter {
+    offset: u64,
+}
+
+impl Counter {
+    #[storage(read, write)]
+    fn read_x(self) -> u64 {
+        storage.x.read() + self.offset
+    }
+}
+
+#[storage(read, write)]
+fn read_x() -> u64 {
+    storage.x.read()
+}
@@ -36,10 +36,14 @@ fn main() -> [M2; 6] {
    b.0
 }
 
-#[test]
-fn test() -> [M2; 6] {
+fn checked() -> [M2; 6] {
    let mut b = bar();
    assert(b.0[0].a.a == U256::new());
    assert(b.0[0].a.b == U256::max());
    b.0
 }
+
+#[test]
+fn test() {
+   let _ = checked();
+}